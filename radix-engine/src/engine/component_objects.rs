@@ -11,6 +11,7 @@ pub struct UnclaimedLazyMap {
     /// All descendents (not just direct children) of the unclaimed lazy map
     pub descendent_lazy_maps: HashMap<LazyMapId, HashMap<Vec<u8>, Vec<u8>>>,
     pub descendent_vaults: HashMap<VaultId, Vault>,
+    pub descendent_components: HashSet<ComponentAddress>,
 }
 
 impl UnclaimedLazyMap {
@@ -19,6 +20,7 @@ impl UnclaimedLazyMap {
             lazy_map: HashMap::new(),
             descendent_lazy_maps: HashMap::new(),
             descendent_vaults: HashMap::new(),
+            descendent_components: HashSet::new(),
         }
     }
 
@@ -30,6 +32,12 @@ impl UnclaimedLazyMap {
         self.descendent_vaults.insert(vault_id, vault);
     }
 
+    fn insert_component(&mut self, component_address: ComponentAddress) {
+        if !self.descendent_components.insert(component_address) {
+            panic!("duplicate component insertion: {:?}", component_address);
+        }
+    }
+
     fn insert_lazy_map(&mut self, lazy_map_id: LazyMapId, lazy_map: HashMap<Vec<u8>, Vec<u8>>) {
         if self.descendent_lazy_maps.contains_key(&lazy_map_id) {
             panic!("duplicate map insertion: {:?}", lazy_map_id);
@@ -51,6 +59,9 @@ impl UnclaimedLazyMap {
         for (vault_id, vault) in unclaimed_lazy_map.descendent_vaults {
             self.insert_vault(vault_id, vault);
         }
+        for component_address in unclaimed_lazy_map.descendent_components {
+            self.insert_component(component_address);
+        }
     }
 
     pub fn insert_descendents(&mut self, new_descendents: ComponentObjects) {
@@ -61,6 +72,10 @@ impl UnclaimedLazyMap {
         for (lazy_map_id, child_lazy_map) in new_descendents.lazy_maps {
             self.insert_map_descendent(child_lazy_map, lazy_map_id);
         }
+
+        for component_address in new_descendents.components {
+            self.insert_component(component_address);
+        }
     }
 }
 
@@ -68,6 +83,7 @@ impl UnclaimedLazyMap {
 pub struct ComponentObjectRefs {
     pub lazy_map_ids: HashSet<LazyMapId>,
     pub vault_ids: HashSet<VaultId>,
+    pub component_addresses: HashSet<ComponentAddress>,
 }
 
 impl ComponentObjectRefs {
@@ -75,12 +91,14 @@ impl ComponentObjectRefs {
         ComponentObjectRefs {
             lazy_map_ids: HashSet::new(),
             vault_ids: HashSet::new(),
+            component_addresses: HashSet::new(),
         }
     }
 
     pub fn extend(&mut self, other: ComponentObjectRefs) {
         self.lazy_map_ids.extend(other.lazy_map_ids);
         self.vault_ids.extend(other.vault_ids);
+        self.component_addresses.extend(other.component_addresses);
     }
 
     pub fn remove(&mut self, other: &ComponentObjectRefs) -> Result<(), RuntimeError> {
@@ -97,6 +115,12 @@ impl ComponentObjectRefs {
             }
         }
 
+        for component_address in &other.component_addresses {
+            if !self.component_addresses.remove(&component_address) {
+                return Err(RuntimeError::ComponentRemoved(*component_address));
+            }
+        }
+
         Ok(())
     }
 }
@@ -109,6 +133,8 @@ pub struct ComponentObjects {
     pub lazy_maps: HashMap<LazyMapId, UnclaimedLazyMap>,
     /// Vaults which haven't been assigned to a component or lazy map yet.
     pub vaults: HashMap<VaultId, Vault>,
+    /// Owned components which haven't been assigned to a component or lazy map yet.
+    pub components: HashSet<ComponentAddress>,
     borrowed_vault: Option<(VaultId, Option<LazyMapId>)>,
 }
 
@@ -117,6 +143,7 @@ impl ComponentObjects {
         ComponentObjects {
             lazy_maps: HashMap::new(),
             vaults: HashMap::new(),
+            components: HashSet::new(),
             borrowed_vault: None,
         }
     }
@@ -128,6 +155,7 @@ impl ComponentObjects {
 
         let mut vaults = HashMap::new();
         let mut lazy_maps = HashMap::new();
+        let mut components = HashSet::new();
 
         for vault_id in other.vault_ids {
             let vault = self
@@ -145,7 +173,19 @@ impl ComponentObjects {
             lazy_maps.insert(lazy_map_id, lazy_map);
         }
 
-        Ok(ComponentObjects { vaults, lazy_maps, borrowed_vault: None })
+        for component_address in other.component_addresses {
+            if !self.components.remove(&component_address) {
+                return Err(RuntimeError::ComponentNotFound(component_address));
+            }
+            components.insert(component_address);
+        }
+
+        Ok(ComponentObjects {
+            vaults,
+            lazy_maps,
+            components,
+            borrowed_vault: None,
+        })
     }
 
     pub fn insert_objects_into_map(
@@ -170,6 +210,41 @@ impl ComponentObjects {
         lazy_map.insert(key, value);
     }
 
+    pub fn remove_lazy_map_entry(&mut self, lazy_map_id: &LazyMapId, key: &[u8]) {
+        if self.borrowed_vault.is_some() {
+            panic!("Should not be taking while value is being borrowed");
+        }
+
+        let (_, lazy_map) = self.get_lazy_map_mut(lazy_map_id).unwrap();
+        lazy_map.remove(key);
+    }
+
+    /// Takes a vault out of an unclaimed lazy map, e.g. when the entry holding it is removed.
+    pub fn take_lazy_map_vault(&mut self, root: &LazyMapId, vault_id: &VaultId) -> Option<Vault> {
+        if self.borrowed_vault.is_some() {
+            panic!("Should not be taking while value is being borrowed");
+        }
+
+        self.lazy_maps
+            .get_mut(root)
+            .and_then(|unclaimed| unclaimed.descendent_vaults.remove(vault_id))
+    }
+
+    /// Returns all entries of an unclaimed lazy map, in the order of the encoded keys.
+    pub fn get_lazy_map_entries(
+        &mut self,
+        lazy_map_id: &LazyMapId,
+    ) -> Option<Vec<(Vec<u8>, Vec<u8>)>> {
+        self.get_lazy_map_mut(lazy_map_id).map(|(_, lazy_map)| {
+            let mut entries: Vec<(Vec<u8>, Vec<u8>)> = lazy_map
+                .iter()
+                .map(|(k, v)| (k.clone(), v.clone()))
+                .collect();
+            entries.sort_by(|a, b| a.0.cmp(&b.0));
+            entries
+        })
+    }
+
     pub fn get_lazy_map_entry(
         &mut self,
         lazy_map_id: &LazyMapId,
@@ -229,7 +304,11 @@ impl ComponentObjects {
     pub fn return_borrowed_vault_mut(&mut self, vault: Vault) {
         if let Some((vault_id, maybe_ancestor)) = self.borrowed_vault.take() {
             if let Some(ancestor_id) = maybe_ancestor {
-                self.lazy_maps.get_mut(&ancestor_id).unwrap().descendent_vaults.insert(vault_id, vault);
+                self.lazy_maps
+                    .get_mut(&ancestor_id)
+                    .unwrap()
+                    .descendent_vaults
+                    .insert(vault_id, vault);
             } else {
                 self.vaults.insert(vault_id, vault);
             }
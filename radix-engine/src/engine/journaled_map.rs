@@ -0,0 +1,151 @@
+use indexmap::IndexMap;
+use sbor::rust::hash::Hash;
+use scrypto::rust::collections::*;
+use scrypto::rust::vec::Vec;
+
+/// A value which a journaled map can save before updating it, and restore on rollback.
+pub trait Restore {
+    type Saved;
+
+    fn save(&self) -> Self::Saved;
+
+    fn restore(saved: Self::Saved) -> Self;
+}
+
+impl<T: Clone> Restore for T {
+    type Saved = T;
+
+    fn save(&self) -> T {
+        self.clone()
+    }
+
+    fn restore(saved: T) -> T {
+        saved
+    }
+}
+
+/// A map which can roll back its updates to earlier savepoints.
+///
+/// Instead of copying the map, each savepoint journals the entries as they were before
+/// their first update since it was taken, so that only updated entries are ever saved.
+pub struct JournaledMap<K, V: Restore> {
+    entries: IndexMap<K, V>,
+    /// The entries updated since each open savepoint, with their values before the update.
+    journals: Vec<HashMap<K, Option<V::Saved>>>,
+}
+
+impl<K: Hash + Eq + Clone, V: Restore> JournaledMap<K, V> {
+    pub fn new() -> Self {
+        Self {
+            entries: IndexMap::new(),
+            journals: Vec::new(),
+        }
+    }
+
+    pub fn contains_key(&self, key: &K) -> bool {
+        self.entries.contains_key(key)
+    }
+
+    pub fn get(&self, key: &K) -> Option<&V> {
+        self.entries.get(key)
+    }
+
+    pub fn get_mut(&mut self, key: &K) -> Option<&mut V> {
+        self.journal(key);
+        self.entries.get_mut(key)
+    }
+
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        self.journal(&key);
+        self.entries.insert(key, value)
+    }
+
+    pub fn remove(&mut self, key: &K) -> Option<V> {
+        self.journal(key);
+        self.entries.remove(key)
+    }
+
+    pub fn keys(&self) -> impl Iterator<Item = &K> {
+        self.entries.keys()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&K, &V)> {
+        self.entries.iter()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Opens a savepoint, which must be closed by either a rollback or a release.
+    pub fn savepoint(&mut self) {
+        self.journals.push(HashMap::new());
+    }
+
+    /// Rolls back all updates made since the last open savepoint, and closes it.
+    pub fn rollback(&mut self) {
+        let journal = self.journals.pop().expect("No open savepoint");
+        for (key, saved) in journal {
+            match saved {
+                Some(saved) => {
+                    self.entries.insert(key, V::restore(saved));
+                }
+                None => {
+                    self.entries.remove(&key);
+                }
+            }
+        }
+    }
+
+    /// Keeps all updates made since the last open savepoint, and closes it.
+    pub fn release(&mut self) {
+        let journal = self.journals.pop().expect("No open savepoint");
+        if let Some(parent) = self.journals.last_mut() {
+            // Entries the enclosing savepoint has journaled already hold older values
+            for (key, saved) in journal {
+                parent.entry(key).or_insert(saved);
+            }
+        }
+    }
+
+    fn journal(&mut self, key: &K) {
+        if let Some(journal) = self.journals.last_mut() {
+            if !journal.contains_key(key) {
+                let saved = self.entries.get(key).map(|value| value.save());
+                journal.insert(key.clone(), saved);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rollback_nested_savepoints() {
+        let mut map: JournaledMap<u32, u32> = JournaledMap::new();
+        map.insert(1, 1);
+
+        map.savepoint();
+        map.insert(2, 2);
+        *map.get_mut(&1).unwrap() = 10;
+        map.savepoint();
+        map.remove(&1);
+        map.insert(3, 3);
+        map.release();
+        map.savepoint();
+        map.insert(2, 20);
+        map.rollback();
+
+        assert_eq!(map.get(&1), None);
+        assert_eq!(map.get(&2), Some(&2));
+        assert_eq!(map.get(&3), Some(&3));
+
+        map.rollback();
+
+        assert_eq!(map.get(&1), Some(&1));
+        assert_eq!(map.get(&2), None);
+        assert_eq!(map.get(&3), None);
+    }
+}
@@ -1,6 +1,7 @@
 mod component_objects;
 mod id_allocator;
 mod id_validator;
+mod journaled_map;
 mod process;
 mod track;
 mod wasm_env;
@@ -9,5 +10,5 @@ pub use component_objects::*;
 pub use id_allocator::*;
 pub use id_validator::*;
 pub use process::{Process, SNodeState, SystemApi};
-pub use track::{CommitReceipt, Track};
+pub use track::{CommitReceipt, Track, TrackSavepoint};
 pub use wasm_env::{EnvModuleResolver, ENGINE_FUNCTION_INDEX, ENGINE_FUNCTION_NAME};
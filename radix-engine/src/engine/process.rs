@@ -1,11 +1,14 @@
 use colored::*;
 
-use sbor::*;
 use sbor::path::SborPath;
+use sbor::*;
+use scrypto::abi::Mutability;
 use scrypto::buffer::*;
-use scrypto::core::{SNodeRef, ScryptoActor};
+use scrypto::constants::{BLUEPRINT_TOKEN, COMPONENT_TOKEN, PACKAGE_TOKEN};
+use scrypto::core::{CallError, SNodeRef, ScryptoActor};
 use scrypto::engine::api::*;
 use scrypto::engine::types::*;
+use scrypto::resource::{blueprint_badge, component_badge, package_badge, AccessRules};
 use scrypto::rust::borrow::ToOwned;
 use scrypto::rust::collections::*;
 use scrypto::rust::fmt;
@@ -95,27 +98,37 @@ pub trait SystemApi {
 
     fn create_resource(&mut self, resource_manager: ResourceManager) -> ResourceAddress;
 
-    fn create_package(&mut self, package: Package) -> PackageAddress;
+    fn create_package(&mut self, package: Package, version: PackageVersion) -> PackageAddress;
+
+    fn create_package_version(
+        &mut self,
+        package_address: PackageAddress,
+        version: u32,
+        package_version: PackageVersion,
+    );
 }
 
 pub enum SNodeState {
     Transaction(TransactionProcess),
     PackageStatic,
+    PackageRef(PackageAddress, Package),
     AuthZone(AuthZone),
     Worktop(Worktop),
-    Scrypto(ScryptoActorInfo, Option<Component>),
+    Scrypto(ScryptoActorInfo, Option<(Component, Mutability)>),
     ResourceStatic,
     ResourceRef(ResourceAddress, ResourceManager),
     BucketRef(BucketId, Bucket),
-    Bucket(Bucket),
+    Bucket(BucketId, Bucket),
     ProofRef(ProofId, Proof),
-    Proof(Proof),
+    Proof(ProofId, Proof),
     VaultRef(VaultId, Option<ComponentAddress>, Vault),
 }
 
 /// Represents an interpreter instance.
 pub struct Interpreter {
     actor: ScryptoActorInfo,
+    /// The version of the package being run
+    package_version: u32,
     function: String,
     args: Vec<ScryptoValue>,
     module: ModuleRef,
@@ -129,6 +142,8 @@ enum InterpreterState<'a> {
     Component {
         component_address: ComponentAddress,
         component: &'a mut Component,
+        /// Whether the method being run may write component state
+        mutability: Mutability,
         initial_loaded_object_refs: ComponentObjectRefs,
     },
 }
@@ -204,6 +219,18 @@ pub struct Process<'r, 'l, L: SubstateStore> {
     /// The caller's auth zone
     caller_auth_zone: Option<&'r AuthZone>,
 
+    /// The calling component or blueprint, empty if called by the transaction
+    caller: Option<ScryptoActor>,
+
+    /// Whether the signature proofs visible to a blueprint authorize the methods it calls.
+    ///
+    /// They're only presented to the method check of the callee, and never added to its auth
+    /// zone, so they don't pass any further unless explicitly passed on.
+    pass_signature_proofs: bool,
+
+    /// Whether this process and the processes it starts may only call read-only methods.
+    read_only: bool,
+
     /// State for the given wasm process, empty only on the root process
     /// (root process cannot create components nor is a component itself)
     wasm_process_state: Option<WasmProcess<'r>>,
@@ -231,10 +258,23 @@ impl<'r, 'l, L: SubstateStore> Process<'r, 'l, L> {
             auth_zone,
             snode_refs: ComponentObjectRefs::new(),
             caller_auth_zone: None,
+            caller: None,
+            pass_signature_proofs: false,
+            read_only: false,
             wasm_process_state: None,
         }
     }
 
+    /// Sets whether signature proofs authorize the methods called by blueprints.
+    pub fn set_pass_signature_proofs(&mut self, pass_signature_proofs: bool) {
+        self.pass_signature_proofs = pass_signature_proofs;
+    }
+
+    /// Sets whether calls to component methods which may write component state are rejected.
+    pub fn set_read_only(&mut self, read_only: bool) {
+        self.read_only = read_only;
+    }
+
     fn new_bucket_id(&mut self) -> Result<BucketId, RuntimeError> {
         Ok(self.track.new_bucket_id())
     }
@@ -263,46 +303,65 @@ impl<'r, 'l, L: SubstateStore> Process<'r, 'l, L> {
 
         // Execution
         let output = match snode {
-            SNodeState::Transaction(transaction_process) => {
-                transaction_process.main(self)
-            }
+            SNodeState::Transaction(transaction_process) => transaction_process.main(self),
             SNodeState::PackageStatic => {
                 Package::static_main(&function, args, self).map_err(RuntimeError::PackageError)
             }
-            SNodeState::AuthZone(auth_zone) => {
-                auth_zone
-                    .main(function.as_str(), args, self)
-                    .map_err(RuntimeError::AuthZoneError)
-            }
-            SNodeState::Worktop(worktop) => {
-                worktop
-                    .main(function.as_str(), args, self)
-                    .map_err(RuntimeError::WorktopError)
-            }
+            SNodeState::PackageRef(package_address, package) => package
+                .main(*package_address, &function, args, self)
+                .map_err(RuntimeError::PackageError),
+            SNodeState::AuthZone(auth_zone) => auth_zone
+                .main(function.as_str(), args, self)
+                .map_err(RuntimeError::AuthZoneError),
+            SNodeState::Worktop(worktop) => worktop
+                .main(function.as_str(), args, self)
+                .map_err(RuntimeError::WorktopError),
             SNodeState::Scrypto(actor, component_state) => {
-                let package = self.track.get_package(actor.package_address()).ok_or(
-                    RuntimeError::PackageNotFound(actor.package_address().clone()),
-                )?;
+                // Components run the version they were created with or migrated to
+                let package_version = match component_state.as_ref() {
+                    Some((component, _)) => component.package_version(),
+                    None => self
+                        .track
+                        .get_package(actor.package_address())
+                        .ok_or(RuntimeError::PackageNotFound(
+                            actor.package_address().clone(),
+                        ))?
+                        .latest_version(),
+                };
+                let package_code = self
+                    .track
+                    .get_package_version(actor.package_address().clone(), package_version)
+                    .ok_or(RuntimeError::PackageError(PackageError::VersionNotFound(
+                        package_version,
+                    )))?;
 
-                if !package.contains_blueprint(actor.blueprint_name()) {
+                if !package_code.contains_blueprint(actor.blueprint_name()) {
                     return Err(RuntimeError::BlueprintNotFound(
                         actor.package_address().clone(),
                         actor.blueprint_name().to_string(),
                     ));
                 }
 
-                let (module, memory) = package.load_module().unwrap();
+                let (module, memory) = package_code.load_module().unwrap();
+                let frame = package_code
+                    .symbols()
+                    .export_function_name(actor.export_name())
+                    .to_string();
 
-                let (interpreter_state, args) = if let Some(component) = component_state {
+                let (interpreter_state, args) = if let Some((component, mutability)) =
+                    component_state
+                {
                     let component_address = actor.component_address().unwrap().clone();
                     let data = ScryptoValue::from_slice(component.state()).unwrap();
                     let initial_loaded_object_refs = ComponentObjectRefs {
                         vault_ids: data.vault_ids.into_iter().collect(),
                         lazy_map_ids: data.lazy_map_ids.into_iter().collect(),
+                        component_addresses: data.owned_component_addresses.into_iter().collect(),
                     };
                     let istate = InterpreterState::Component {
                         component_address,
                         component,
+                        mutability: mutability.clone(),
                         initial_loaded_object_refs,
                     };
                     let mut args_with_self = vec![ScryptoValue::from_value(&component_address)];
@@ -320,6 +379,7 @@ impl<'r, 'l, L: SubstateStore> Process<'r, 'l, L> {
                         function,
                         args,
                         actor: actor.clone(),
+                        package_version,
                         module: module.clone(),
                         memory,
                     },
@@ -331,15 +391,51 @@ impl<'r, 'l, L: SubstateStore> Process<'r, 'l, L> {
 
                 // Return value
                 re_debug!(self, "Invoke result: {:?}", result);
-                let rtn = result
-                    .map_err(|e| {
-                        match e.into_host_error() {
+                let rtn = match result {
+                    Ok(rtn) => rtn,
+                    Err(Error::Trap(trap)) => {
+                        let error = match trap.into_kind() {
+                            // Pass-through runtime errors, with this frame added to trap call frames
+                            TrapKind::Host(host_error) => {
+                                match *host_error.downcast::<RuntimeError>().unwrap() {
+                                    RuntimeError::WasmTrap {
+                                        actor,
+                                        kind,
+                                        mut call_frames,
+                                    } => {
+                                        call_frames.push(frame);
+                                        RuntimeError::WasmTrap {
+                                            actor,
+                                            kind,
+                                            call_frames,
+                                        }
+                                    }
+                                    error => error,
+                                }
+                            }
+                            kind => RuntimeError::WasmTrap {
+                                actor: actor.actor(),
+                                kind: Self::wasm_trap_kind(kind),
+                                call_frames: vec![frame],
+                            },
+                        };
+                        if let RuntimeError::WasmTrap {
+                            kind, call_frames, ..
+                        } = &error
+                        {
+                            re_warn!(self, "Trap: {:?}, call frames: {:?}", kind, call_frames);
+                        }
+                        return Err(error);
+                    }
+                    Err(e) => {
+                        return Err(match e.into_host_error() {
                             // Pass-through runtime errors
                             Some(host_error) => *host_error.downcast::<RuntimeError>().unwrap(),
                             None => RuntimeError::InvokeError,
-                        }
-                    })?
-                    .ok_or(RuntimeError::NoReturnData)?;
+                        });
+                    }
+                }
+                .ok_or(RuntimeError::NoReturnData)?;
                 match rtn {
                     RuntimeValue::I32(ptr) => self.read_return_value(ptr as u32),
                     _ => Err(RuntimeError::InvalidReturnType),
@@ -362,10 +458,9 @@ impl<'r, 'l, L: SubstateStore> Process<'r, 'l, L> {
             SNodeState::ProofRef(_, proof) => proof
                 .main(function.as_str(), args, self)
                 .map_err(RuntimeError::ProofError),
-            SNodeState::VaultRef(vault_id, _, vault) =>
-                vault
-                    .main(*vault_id, function.as_str(), args, self)
-                    .map_err(RuntimeError::VaultError),
+            SNodeState::VaultRef(vault_id, _, vault) => vault
+                .main(*vault_id, function.as_str(), args, self)
+                .map_err(RuntimeError::VaultError),
             _ => Err(RuntimeError::IllegalSystemCall),
         }?;
 
@@ -397,6 +492,57 @@ impl<'r, 'l, L: SubstateStore> Process<'r, 'l, L> {
         Ok((output, moving_buckets, moving_proofs))
     }
 
+    /// Calls a function/method, rolling back all of its state updates and resource moves
+    /// if it fails.
+    pub fn try_invoke_snode(
+        &mut self,
+        snode_ref: SNodeRef,
+        function: String,
+        args: Vec<ScryptoValue>,
+    ) -> Result<ScryptoValue, RuntimeError> {
+        let savepoint = self.track.savepoint();
+        let mut bucket_snapshots = HashMap::new();
+        let mut proof_clones = HashMap::new();
+        for arg in &args {
+            for (bucket_id, _) in &arg.bucket_ids {
+                if let Some(bucket) = self.buckets.get(bucket_id) {
+                    bucket_snapshots.insert(*bucket_id, bucket.detached_snapshot());
+                }
+            }
+            for (proof_id, _) in &arg.proof_ids {
+                if let Some(proof) = self.proofs.get(proof_id) {
+                    proof_clones.insert(*proof_id, proof.clone());
+                }
+            }
+        }
+
+        match self.invoke_snode(snode_ref, function, args) {
+            Ok(result) => {
+                self.track.release(savepoint);
+                for (_, proof) in proof_clones {
+                    proof.drop();
+                }
+                Ok(result)
+            }
+            Err(error) => {
+                re_warn!(self, "Rolling back failed call: {:?}", error);
+                self.track.rollback(savepoint);
+                for (bucket_id, snapshot) in bucket_snapshots {
+                    self.buckets.insert(bucket_id, snapshot.restore());
+                }
+                // Proofs the callee never received are still held here
+                for (proof_id, proof) in proof_clones {
+                    if self.proofs.contains_key(&proof_id) {
+                        proof.drop();
+                    } else {
+                        self.proofs.insert(proof_id, proof);
+                    }
+                }
+                Err(error)
+            }
+        }
+    }
+
     /// Calls a function/method.
     pub fn invoke_snode(
         &mut self,
@@ -404,9 +550,36 @@ impl<'r, 'l, L: SubstateStore> Process<'r, 'l, L> {
         function: String,
         args: Vec<ScryptoValue>,
     ) -> Result<ScryptoValue, RuntimeError> {
+        // Nested read-only calls see the component state this frame has put so far
+        if let SNodeRef::Scrypto(_) = &snode_ref {
+            if let Some(WasmProcess {
+                interpreter_state:
+                    InterpreterState::Component {
+                        component_address,
+                        component,
+                        ..
+                    },
+                ..
+            }) = &self.wasm_process_state
+            {
+                self.track
+                    .update_borrowed_component_view(*component_address, component);
+            }
+        }
+
         // Authorization and state load
         let (mut snode, method_auths) = match &snode_ref {
             SNodeRef::PackageStatic => Ok((SNodeState::PackageStatic, vec![])),
+            SNodeRef::PackageRef(package_address) => {
+                let package = self
+                    .track
+                    .borrow_global_mut_package(package_address.clone())?;
+                let method_auth = package.get_auth(&function);
+                Ok((
+                    SNodeState::PackageRef(package_address.clone(), package),
+                    vec![method_auth],
+                ))
+            }
             SNodeRef::AuthZoneRef => {
                 if let Some(auth_zone) = self.auth_zone.take() {
                     Ok((SNodeState::AuthZone(auth_zone), vec![]))
@@ -438,22 +611,93 @@ impl<'r, 'l, L: SubstateStore> Process<'r, 'l, L> {
                         ))
                     }
                     ScryptoActor::Component(component_address) => {
-                        let component = self
+                        // Components that are already borrowed can still be read
+                        let (component, shared) = match self
                             .track
-                            .borrow_global_mut_component(component_address.clone())?;
+                            .borrow_global_mut_component(component_address.clone())
+                        {
+                            Err(RuntimeError::ComponentReentrancy(_)) => (
+                                self.track
+                                    .borrow_shared_global_component(component_address.clone())?,
+                                true,
+                            ),
+                            result => (result?, false),
+                        };
+                        // Owned components can only be invoked by their owner
+                        if component.is_owned()
+                            && !self.owned_snodes.components.contains(component_address)
+                            && !self
+                                .snode_refs
+                                .component_addresses
+                                .contains(component_address)
+                        {
+                            self.track.return_borrowed_global_component(
+                                component_address.clone(),
+                                component,
+                                false,
+                            );
+                            return Err(RuntimeError::OwnedComponentNotAccessible(
+                                component_address.clone(),
+                            ));
+                        }
                         let package_address = component.package_address();
                         let blueprint_name = component.blueprint_name().to_string();
                         let export_name = format!("{}_main", blueprint_name);
 
-                        let package = self
+                        let abi = match self
                             .track
-                            .get_package(&package_address)
-                            .ok_or(RuntimeError::PackageNotFound(package_address))?;
+                            .get_package_version(package_address, component.package_version())
+                            .ok_or(PackageError::VersionNotFound(component.package_version()))
+                            .and_then(|code| code.load_blueprint_abi(&blueprint_name))
+                        {
+                            Ok(abi) => abi,
+                            Err(error) => {
+                                self.track.return_borrowed_global_component(
+                                    component_address.clone(),
+                                    component,
+                                    false,
+                                );
+                                return Err(RuntimeError::PackageError(error));
+                            }
+                        };
+                        let mutability =
+                            match abi.methods.iter().find(|method| method.name == function) {
+                                Some(method) => method.mutability.clone(),
+                                None => {
+                                    self.track.return_borrowed_global_component(
+                                        component_address.clone(),
+                                        component,
+                                        false,
+                                    );
+                                    return Err(RuntimeError::MethodNotFound(
+                                        component_address.clone(),
+                                        function,
+                                    ));
+                                }
+                            };
+                        if self.read_only && matches!(mutability, Mutability::Mutable) {
+                            self.track.return_borrowed_global_component(
+                                component_address.clone(),
+                                component,
+                                false,
+                            );
+                            return Err(RuntimeError::MethodNotReadOnly(
+                                component_address.clone(),
+                                function,
+                            ));
+                        }
                         // TODO: Remove clone
-                        let schema = package
-                            .load_blueprint_schema(&blueprint_name)
-                            .unwrap()
-                            .clone();
+                        let schema = abi.schema.clone();
+                        if shared && matches!(mutability, Mutability::Mutable) {
+                            self.track.return_borrowed_global_component(
+                                component_address.clone(),
+                                component,
+                                false,
+                            );
+                            return Err(RuntimeError::ComponentReentrancy(
+                                component_address.clone(),
+                            ));
+                        }
 
                         let (_, method_auths) = component.method_authorization(&schema, &function);
                         Ok((
@@ -464,7 +708,7 @@ impl<'r, 'l, L: SubstateStore> Process<'r, 'l, L> {
                                     export_name,
                                     component_address.clone(),
                                 ),
-                                Some(component),
+                                Some((component, mutability)),
                             ),
                             method_auths,
                         ))
@@ -493,7 +737,10 @@ impl<'r, 'l, L: SubstateStore> Process<'r, 'l, L> {
                     .get_resource_manager(&resource_address)
                     .unwrap()
                     .get_auth(&function, &args);
-                Ok((SNodeState::Bucket(bucket), vec![method_auth.clone()]))
+                Ok((
+                    SNodeState::Bucket(bucket_id.clone(), bucket),
+                    vec![method_auth.clone()],
+                ))
             }
             SNodeRef::BucketRef(bucket_id) => {
                 let bucket = self
@@ -512,24 +759,38 @@ impl<'r, 'l, L: SubstateStore> Process<'r, 'l, L> {
                 ))
             }
             SNodeRef::ProofRef(proof_id) => {
-                let proof = self.proofs.remove(&proof_id).ok_or(RuntimeError::ProofNotFound(proof_id.clone()))?;
+                let proof = self
+                    .proofs
+                    .remove(&proof_id)
+                    .ok_or(RuntimeError::ProofNotFound(proof_id.clone()))?;
                 Ok((SNodeState::ProofRef(proof_id.clone(), proof), vec![]))
             }
             SNodeRef::Proof(proof_id) => {
-                let proof = self.proofs.remove(&proof_id).ok_or(RuntimeError::ProofNotFound(proof_id.clone()))?;
-                Ok((SNodeState::Proof(proof), vec![]))
+                let proof = self
+                    .proofs
+                    .remove(&proof_id)
+                    .ok_or(RuntimeError::ProofNotFound(proof_id.clone()))?;
+                Ok((SNodeState::Proof(proof_id.clone(), proof), vec![]))
             }
             SNodeRef::VaultRef(vault_id) => {
-                let (component, vault) = if let Some(vault) = self.owned_snodes.borrow_vault_mut(vault_id) {
-                    (None, vault)
-                } else if !self.snode_refs.vault_ids.contains(vault_id) {
-                    return Err(RuntimeError::VaultNotFound(*vault_id));
-                } else if let Some(WasmProcess { interpreter_state: InterpreterState::Component { component_address, .. }, .. }) = &self.wasm_process_state {
-                    let vault = self.track.borrow_vault_mut(component_address, vault_id);
-                    (Some(*component_address), vault)
-                } else {
-                    panic!("Should never get here");
-                };
+                let (component, vault) =
+                    if let Some(vault) = self.owned_snodes.borrow_vault_mut(vault_id) {
+                        (None, vault)
+                    } else if !self.snode_refs.vault_ids.contains(vault_id) {
+                        return Err(RuntimeError::VaultNotFound(*vault_id));
+                    } else if let Some(WasmProcess {
+                        interpreter_state:
+                            InterpreterState::Component {
+                                component_address, ..
+                            },
+                        ..
+                    }) = &self.wasm_process_state
+                    {
+                        let vault = self.track.borrow_vault_mut(component_address, vault_id);
+                        (Some(*component_address), vault)
+                    } else {
+                        panic!("Should never get here");
+                    };
 
                 let resource_address = vault.resource_address();
                 let method_auth = self
@@ -545,56 +806,33 @@ impl<'r, 'l, L: SubstateStore> Process<'r, 'l, L> {
         }?;
 
         // Authorization check
-        if !method_auths.is_empty() {
-            let mut auth_zones = Vec::new();
-            if let Some(self_auth_zone) = &self.auth_zone {
-                auth_zones.push(self_auth_zone);
-            }
-
-            match &snode {
-                // Resource auth check includes caller
-                SNodeState::ResourceRef(_, _) | SNodeState::VaultRef(_, _, _) | SNodeState::BucketRef(_, _) | SNodeState::Bucket(_) => {
-                    if let Some(auth_zone) = self.caller_auth_zone {
-                        auth_zones.push(auth_zone);
-                    }
-                }
-                // Extern call auth check
-                _ => { }
-            };
-
-            for method_auth in method_auths {
-                method_auth
-                    .check(&auth_zones)
-                    .map_err(|error| RuntimeError::AuthorizationError {
-                        function: function.clone(),
-                        authorization: method_auth,
-                        error
-                    })?;
-            }
+        if let Err(error) = self.check_method_auths(&snode, method_auths, &function) {
+            self.return_borrowed_snode(snode, false);
+            return Err(error);
         }
 
         // Execution
         let result = match snode {
-            SNodeState::Proof(proof) => {
-                proof.main_consume(function.as_str())
-                    .map_err(RuntimeError::ProofError)
-            },
-            SNodeState::Bucket(bucket) => match function.as_str() {
+            SNodeState::Proof(_, proof) => proof
+                .main_consume(function.as_str())
+                .map_err(RuntimeError::ProofError),
+            SNodeState::Bucket(_, bucket) => match function.as_str() {
                 "burn" => bucket.drop(self).map_err(RuntimeError::BucketError),
                 _ => Err(RuntimeError::IllegalSystemCall),
             },
             _ => {
                 // Figure out what buckets and proofs to move from this process
-                let mut moving_buckets = HashMap::new();
-                let mut moving_proofs = HashMap::new();
-                for arg in &args {
-                    self.process_call_data(arg)?;
-                    moving_buckets.extend(self.send_buckets(&arg.bucket_ids)?);
-                    moving_proofs.extend(self.send_proofs(&arg.proof_ids, MoveMethod::AsArgument)?);
-                }
+                let (moving_buckets, moving_proofs) = match self.send_call_data(&args) {
+                    Ok(moving) => moving,
+                    Err(error) => {
+                        self.return_borrowed_snode(snode, false);
+                        return Err(error);
+                    }
+                };
 
                 // start a new process
                 let process_auth_zone = if matches!(snode, SNodeState::Scrypto(_, _)) {
+                    // Caller badges and signature proofs only authorize this invocation
                     Some(AuthZone::new())
                 } else {
                     None
@@ -612,52 +850,32 @@ impl<'r, 'l, L: SubstateStore> Process<'r, 'l, L> {
                 if let Some(auth_zone) = &self.auth_zone {
                     process.caller_auth_zone = Option::Some(auth_zone);
                 }
+                process.caller = self
+                    .wasm_process_state
+                    .as_ref()
+                    .map(|wasm_process| wasm_process.vm.actor.actor());
+                process.pass_signature_proofs = self.pass_signature_proofs;
+                process.read_only = self.read_only;
 
                 // invoke the main function
-                let (result, received_buckets, received_proofs) =
-                    process.run(&mut snode, function, args)?;
+                let output = process.run(&mut snode, function, args);
+                if output.is_err() {
+                    // Failed callees still release the resources they lock
+                    process.drop_proofs();
+                }
+                let (result, received_buckets, received_proofs) = match output {
+                    Ok(output) => output,
+                    Err(error) => {
+                        self.return_borrowed_snode(snode, false);
+                        return Err(error);
+                    }
+                };
 
                 // move buckets and proofs to this process.
                 self.buckets.extend(received_buckets);
                 self.proofs.extend(received_proofs);
 
-                // Return borrowed snodes
-                match snode {
-                    SNodeState::AuthZone(auth_zone) => {
-                        self.auth_zone = Some(auth_zone);
-                    }
-                    SNodeState::Worktop(worktop) => {
-                        self.worktop = Some(worktop);
-                    }
-                    SNodeState::Scrypto(actor, component_state) => {
-                        if let Some(component_address) = actor.component_address() {
-                            self.track.return_borrowed_global_component(
-                                component_address,
-                                component_state.unwrap(),
-                            );
-                        }
-                    }
-                    SNodeState::ResourceRef(resource_address, resource_manager) => {
-                        self.track.return_borrowed_global_resource_manager(
-                            resource_address,
-                            resource_manager,
-                        );
-                    }
-                    SNodeState::BucketRef(bucket_id, bucket) => {
-                        self.buckets.insert(bucket_id, bucket);
-                    }
-                    SNodeState::ProofRef(proof_id, proof) => {
-                        self.proofs.insert(proof_id, proof);
-                    }
-                    SNodeState::VaultRef(vault_id, maybe_component_address, vault) => {
-                        if let Some(component_address) = maybe_component_address {
-                            self.track.return_borrowed_vault(&component_address, &vault_id, vault);
-                        } else {
-                            self.owned_snodes.return_borrowed_vault_mut(vault);
-                        }
-                    }
-                    _ => {}
-                }
+                self.return_borrowed_snode(snode, true);
 
                 Ok(result)
             }
@@ -666,31 +884,164 @@ impl<'r, 'l, L: SubstateStore> Process<'r, 'l, L> {
         Ok(result)
     }
 
-    /// Calls the ABI generator of a blueprint.
-    // TODO: Remove
-    pub fn call_abi(
+    /// Checks the authorization of a call against the auth zones visible to it.
+    fn check_method_auths(
         &mut self,
-        package_address: PackageAddress,
-        blueprint_name: &str,
-    ) -> Result<ScryptoValue, RuntimeError> {
-        re_debug!(self, "Call abi started");
+        snode: &SNodeState,
+        method_auths: Vec<MethodAuthorization>,
+        function: &str,
+    ) -> Result<(), RuntimeError> {
+        if method_auths.is_empty() {
+            return Ok(());
+        }
 
-        let mut snode = SNodeState::Scrypto(
-            ScryptoActorInfo::blueprint(
-                package_address,
-                blueprint_name.to_string(),
-                format!("{}_abi", blueprint_name),
-            ),
-            None,
-        );
+        // Blueprints present virtual badges of their identity to the blueprints they call,
+        // which are only created when the method auths require any of them
+        let caller_badges = if matches!(snode, SNodeState::Scrypto(_, _))
+            && self.wasm_process_state.is_some()
+            && method_auths.iter().any(|method_auth| {
+                [PACKAGE_TOKEN, BLUEPRINT_TOKEN, COMPONENT_TOKEN]
+                    .iter()
+                    .any(|token| method_auth.references(*token))
+            }) {
+            Some(self.create_caller_badges()?)
+        } else {
+            None
+        };
+        let signature_proofs = if self.pass_signature_proofs
+            && matches!(snode, SNodeState::Scrypto(_, _))
+            && self.wasm_process_state.is_some()
+        {
+            Some(AuthZone::new_with_proofs(self.clone_signature_proofs()))
+        } else {
+            None
+        };
+
+        let mut auth_zones = Vec::new();
+        if let Some(self_auth_zone) = &self.auth_zone {
+            auth_zones.push(self_auth_zone);
+        }
+        if let Some(caller_badges) = &caller_badges {
+            auth_zones.push(caller_badges);
+        }
+        if let Some(signature_proofs) = &signature_proofs {
+            auth_zones.push(signature_proofs);
+        }
+
+        match snode {
+            // Resource auth check includes caller
+            SNodeState::ResourceRef(_, _)
+            | SNodeState::VaultRef(_, _, _)
+            | SNodeState::BucketRef(_, _)
+            | SNodeState::Bucket(_, _) => {
+                if let Some(auth_zone) = self.caller_auth_zone {
+                    auth_zones.push(auth_zone);
+                }
+            }
+            // Extern call auth check
+            _ => {}
+        };
+
+        for method_auth in method_auths {
+            method_auth
+                .check(&auth_zones)
+                .map_err(|error| RuntimeError::AuthorizationError {
+                    function: function.to_string(),
+                    authorization: method_auth,
+                    error,
+                })?;
+        }
+        Ok(())
+    }
+
+    /// Moves the buckets and proofs passed as call arguments out of this process, or none
+    /// of them if any can't be moved.
+    fn send_call_data(
+        &mut self,
+        args: &[ScryptoValue],
+    ) -> Result<(HashMap<BucketId, Bucket>, HashMap<ProofId, Proof>), RuntimeError> {
+        let mut bucket_ids = HashMap::new();
+        let mut proof_ids = HashMap::new();
+        for arg in args {
+            self.process_call_data(arg)?;
+            for (bucket_id, path) in &arg.bucket_ids {
+                if bucket_ids.insert(*bucket_id, path.clone()).is_some() {
+                    return Err(RuntimeError::BucketNotFound(*bucket_id));
+                }
+            }
+            for (proof_id, path) in &arg.proof_ids {
+                if proof_ids.insert(*proof_id, path.clone()).is_some() {
+                    return Err(RuntimeError::ProofNotFound(*proof_id));
+                }
+            }
+        }
+
+        let buckets = self.send_buckets(&bucket_ids)?;
+        match self.send_proofs(&proof_ids, MoveMethod::AsArgument) {
+            Ok(proofs) => Ok((buckets, proofs)),
+            Err(error) => {
+                self.buckets.extend(buckets);
+                Err(error)
+            }
+        }
+    }
 
-        let mut process = Process::new(self.depth + 1, self.trace, self.track, None, None, HashMap::new(), HashMap::new());
-        let result = process
-            .run(&mut snode, String::new(), Vec::new())
-            .map(|(r, _, _)| r);
+    /// Returns an snode borrowed for a call, keeping its state updates only if the call
+    /// succeeded.
+    fn return_borrowed_snode(&mut self, snode: SNodeState, succeeded: bool) {
+        match snode {
+            SNodeState::AuthZone(auth_zone) => {
+                self.auth_zone = Some(auth_zone);
+            }
+            SNodeState::Worktop(worktop) => {
+                self.worktop = Some(worktop);
+            }
+            SNodeState::PackageRef(package_address, package) => {
+                self.track
+                    .return_borrowed_global_package(package_address, package);
+            }
+            SNodeState::Scrypto(actor, component_state) => {
+                if let Some(component_address) = actor.component_address() {
+                    let (component, mutability) = component_state.unwrap();
+                    self.track.return_borrowed_global_component(
+                        component_address,
+                        component,
+                        succeeded && matches!(mutability, Mutability::Mutable),
+                    );
+                }
+            }
+            SNodeState::ResourceRef(resource_address, resource_manager) => {
+                self.track
+                    .return_borrowed_global_resource_manager(resource_address, resource_manager);
+            }
+            SNodeState::BucketRef(bucket_id, bucket) | SNodeState::Bucket(bucket_id, bucket) => {
+                self.buckets.insert(bucket_id, bucket);
+            }
+            SNodeState::ProofRef(proof_id, proof) | SNodeState::Proof(proof_id, proof) => {
+                self.proofs.insert(proof_id, proof);
+            }
+            SNodeState::VaultRef(vault_id, maybe_component_address, vault) => {
+                if let Some(component_address) = maybe_component_address {
+                    self.track
+                        .return_borrowed_vault(&component_address, &vault_id, vault);
+                } else {
+                    self.owned_snodes.return_borrowed_vault_mut(vault);
+                }
+            }
+            _ => {}
+        }
+    }
 
-        re_debug!(self, "Call abi ended");
-        result
+    /// Drops all proofs held by this process, including those in its auth zone.
+    fn drop_proofs(&mut self) {
+        for (_, proof) in self.proofs.drain() {
+            proof.drop();
+        }
+        if let Some(auth_zone) = &mut self.auth_zone {
+            for proof in auth_zone.proofs.drain(..) {
+                proof.drop();
+            }
+        }
     }
 
     /// Checks resource leak.
@@ -714,6 +1065,11 @@ impl<'r, 'l, L: SubstateStore> Process<'r, 'l, L> {
             resource = ResourceFailure::UnclaimedLazyMap;
             success = false;
         }
+        for component_address in &self.owned_snodes.components {
+            re_warn!(self, "Dangling owned component: {:?}", component_address);
+            resource = ResourceFailure::UnclaimedComponent;
+            success = false;
+        }
 
         if let Some(worktop) = &self.worktop {
             if !worktop.is_empty() {
@@ -753,6 +1109,9 @@ impl<'r, 'l, L: SubstateStore> Process<'r, 'l, L> {
         if !validated.vault_ids.is_empty() {
             return Err(RuntimeError::VaultNotAllowed);
         }
+        if !validated.owned_component_addresses.is_empty() {
+            return Err(RuntimeError::OwnedComponentNotAllowed);
+        }
         Ok(())
     }
 
@@ -763,6 +1122,9 @@ impl<'r, 'l, L: SubstateStore> Process<'r, 'l, L> {
         if !validated.vault_ids.is_empty() {
             return Err(RuntimeError::VaultNotAllowed);
         }
+        if !validated.owned_component_addresses.is_empty() {
+            return Err(RuntimeError::OwnedComponentNotAllowed);
+        }
         Ok(())
     }
 
@@ -793,29 +1155,154 @@ impl<'r, 'l, L: SubstateStore> Process<'r, 'l, L> {
             vault_ids.insert(vault_id);
         }
 
+        let mut component_addresses = HashSet::new();
+        for component_address in validated.owned_component_addresses {
+            if component_addresses.contains(&component_address) {
+                return Err(RuntimeError::DuplicateComponent(component_address));
+            }
+            component_addresses.insert(component_address);
+        }
+
         // lazy map allowed
         // vaults allowed
+        // owned components allowed
         Ok(ComponentObjectRefs {
             lazy_map_ids,
             vault_ids,
+            component_addresses,
         })
     }
 
+    /// Converts the kind of a trap.
+    fn wasm_trap_kind(kind: TrapKind) -> WasmTrapKind {
+        match kind {
+            TrapKind::Unreachable => WasmTrapKind::Unreachable,
+            TrapKind::MemoryAccessOutOfBounds => WasmTrapKind::MemoryAccessOutOfBounds,
+            TrapKind::TableAccessOutOfBounds => WasmTrapKind::TableAccessOutOfBounds,
+            TrapKind::ElemUninitialized => WasmTrapKind::ElemUninitialized,
+            TrapKind::DivisionByZero => WasmTrapKind::DivisionByZero,
+            TrapKind::InvalidConversionToInt => WasmTrapKind::InvalidConversionToInt,
+            TrapKind::StackOverflow => WasmTrapKind::StackOverflow,
+            TrapKind::UnexpectedSignature => WasmTrapKind::UnexpectedSignature,
+            TrapKind::Host(_) => WasmTrapKind::Host,
+        }
+    }
+
+    /// Converts the error of a failed try-call into one the caller can decode.
+    fn call_error(error: RuntimeError) -> CallError {
+        match error {
+            RuntimeError::ApplicationError { actor, error } => CallError::ApplicationError {
+                actor,
+                error: error.raw,
+            },
+            RuntimeError::Panic {
+                actor,
+                message,
+                location,
+            } => CallError::Panic {
+                actor,
+                message,
+                location,
+            },
+            RuntimeError::WasmTrap { actor, .. } => CallError::WasmTrap { actor },
+            RuntimeError::AuthorizationError { function, .. } => {
+                CallError::NotAuthorized { function }
+            }
+            RuntimeError::PackageNotFound(package_address) => {
+                CallError::PackageNotFound(package_address)
+            }
+            RuntimeError::BlueprintNotFound(package_address, blueprint_name) => {
+                CallError::BlueprintNotFound(package_address, blueprint_name)
+            }
+            RuntimeError::ComponentNotFound(component_address) => {
+                CallError::ComponentNotFound(component_address)
+            }
+            RuntimeError::MethodNotFound(component_address, method_name) => {
+                CallError::MethodNotFound(component_address, method_name)
+            }
+            RuntimeError::ComponentReentrancy(component_address) => {
+                CallError::ComponentReentrancy(component_address)
+            }
+            RuntimeError::OwnedComponentNotAccessible(component_address) => {
+                CallError::OwnedComponentNotAccessible(component_address)
+            }
+            RuntimeError::ImmutableMethodWrite(component_address, method) => {
+                CallError::ImmutableMethodWrite(component_address, method)
+            }
+            RuntimeError::BucketNotFound(bucket_id) => CallError::BucketNotFound(bucket_id),
+            RuntimeError::ProofNotFound(proof_id) => CallError::ProofNotFound(proof_id),
+            RuntimeError::CantMoveLockedBucket => CallError::CantMoveLockedBucket,
+            RuntimeError::CantMoveRestrictedProof(proof_id) => {
+                CallError::CantMoveRestrictedProof(proof_id)
+            }
+            RuntimeError::ResourceCheckFailure(_) => CallError::ResourceCheckFailure,
+            error => CallError::Other(format!("{:?}", error)),
+        }
+    }
+
+    /// Clones the signature proofs visible to this process.
+    fn clone_signature_proofs(&self) -> Vec<Proof> {
+        self.auth_zone
+            .iter()
+            .chain(self.caller_auth_zone)
+            .flat_map(|auth_zone| auth_zone.proofs.iter())
+            .filter(|proof| proof.resource_address() == ECDSA_TOKEN)
+            .map(|proof| proof.clone())
+            .collect()
+    }
+
+    /// Creates an auth zone with virtual proofs of the package, blueprint and component
+    /// of the running blueprint.
+    fn create_caller_badges(&mut self) -> Result<AuthZone, RuntimeError> {
+        let actor = &self
+            .wasm_process_state
+            .as_ref()
+            .ok_or(RuntimeError::InterpreterNotStarted)?
+            .vm
+            .actor;
+        let mut badges = vec![
+            package_badge(actor.package_address().clone()),
+            blueprint_badge(actor.package_address().clone(), actor.blueprint_name()),
+        ];
+        if let Some(component_address) = actor.component_address() {
+            badges.push(component_badge(component_address));
+        }
+
+        let mut proofs = Vec::new();
+        for badge in badges {
+            let mut ids = BTreeSet::new();
+            ids.insert(badge.non_fungible_id());
+            let mut bucket = Bucket::new(ResourceContainer::new_non_fungible(
+                badge.resource_address(),
+                ids,
+            ));
+            let proof = bucket
+                .create_proof(self.new_bucket_id()?)
+                .map_err(RuntimeError::ProofError)?;
+            proofs.push(proof);
+        }
+        Ok(AuthZone::new_with_proofs(proofs))
+    }
+
     /// Sends buckets to another component/blueprint, either as argument or return
     fn send_buckets(
         &mut self,
         bucket_ids: &HashMap<BucketId, SborPath>,
     ) -> Result<HashMap<BucketId, Bucket>, RuntimeError> {
-        let mut buckets = HashMap::new();
         for (bucket_id, _) in bucket_ids {
             let bucket = self
                 .buckets
-                .remove(bucket_id)
+                .get(bucket_id)
                 .ok_or(RuntimeError::BucketNotFound(*bucket_id))?;
-            re_debug!(self, "Moving bucket: {}, {:?}", bucket_id, bucket);
             if bucket.is_locked() {
                 return Err(RuntimeError::CantMoveLockedBucket);
             }
+        }
+
+        let mut buckets = HashMap::new();
+        for (bucket_id, _) in bucket_ids {
+            let bucket = self.buckets.remove(bucket_id).unwrap();
+            re_debug!(self, "Moving bucket: {}, {:?}", bucket_id, bucket);
             buckets.insert(*bucket_id, bucket);
         }
         Ok(buckets)
@@ -827,16 +1314,20 @@ impl<'r, 'l, L: SubstateStore> Process<'r, 'l, L> {
         proof_ids: &HashMap<ProofId, SborPath>,
         method: MoveMethod,
     ) -> Result<HashMap<ProofId, Proof>, RuntimeError> {
-        let mut proofs = HashMap::new();
         for (proof_id, _) in proof_ids {
-            let mut proof = self
+            let proof = self
                 .proofs
-                .remove(proof_id)
+                .get(proof_id)
                 .ok_or(RuntimeError::ProofNotFound(*proof_id))?;
-            re_debug!(self, "Moving proof: {}, {:?}", proof_id, proof);
             if proof.is_restricted() {
                 return Err(RuntimeError::CantMoveRestrictedProof(*proof_id));
             }
+        }
+
+        let mut proofs = HashMap::new();
+        for (proof_id, _) in proof_ids {
+            let mut proof = self.proofs.remove(proof_id).unwrap();
+            re_debug!(self, "Moving proof: {}, {:?}", proof_id, proof);
             if matches!(method, MoveMethod::AsArgument) {
                 proof.change_to_restricted();
             }
@@ -933,35 +1424,112 @@ impl<'r, 'l, L: SubstateStore> Process<'r, 'l, L> {
     // SYSTEM CALL HANDLERS START
     //============================
 
+    fn validate_component_state(
+        &mut self,
+        package_address: PackageAddress,
+        package_version: u32,
+        blueprint_name: &str,
+        state: &[u8],
+    ) -> Result<(), RuntimeError> {
+        let schema = self
+            .track
+            .get_package_version(package_address, package_version)
+            .ok_or(RuntimeError::PackageError(PackageError::VersionNotFound(
+                package_version,
+            )))?
+            .load_blueprint_schema(blueprint_name)
+            .map_err(|_| {
+                RuntimeError::BlueprintNotFound(package_address, blueprint_name.to_owned())
+            })?;
+        let valid = ScryptoValue::from_slice(state)
+            .map(|value| value.matches_schema(schema))
+            .unwrap_or(false);
+        if !valid {
+            return Err(RuntimeError::InvalidComponentState(
+                package_address,
+                blueprint_name.to_owned(),
+            ));
+        }
+        Ok(())
+    }
+
+    fn create_component(
+        &mut self,
+        blueprint_name: String,
+        access_rules_list: Vec<AccessRules>,
+        state: Vec<u8>,
+        owned: bool,
+    ) -> Result<ComponentAddress, RuntimeError> {
+        let vm = &self
+            .wasm_process_state
+            .as_ref()
+            .ok_or(RuntimeError::IllegalSystemCall)?
+            .vm;
+        let package_address = vm.actor.package_address().clone();
+        let package_version = vm.package_version;
+        self.validate_component_state(package_address, package_version, &blueprint_name, &state)?;
+
+        let data = Self::process_entry_data(&state)?;
+        let new_objects = self.owned_snodes.take(data)?;
+
+        let component = if owned {
+            Component::new_owned(
+                package_address,
+                package_version,
+                blueprint_name,
+                access_rules_list,
+                state,
+            )
+        } else {
+            Component::new(
+                package_address,
+                package_version,
+                blueprint_name,
+                access_rules_list,
+                state,
+            )
+        };
+        let component_address = self.track.create_component(component);
+        self.track
+            .insert_objects_into_component(new_objects, component_address);
+
+        Ok(component_address)
+    }
+
     fn handle_create_component(
         &mut self,
         input: CreateComponentInput,
     ) -> Result<CreateComponentOutput, RuntimeError> {
-        let data = Self::process_entry_data(&input.state)?;
-        let new_objects = self.owned_snodes.take(data)?;
-
-        let wasm_process = self
-            .wasm_process_state
-            .as_mut()
-            .ok_or(RuntimeError::IllegalSystemCall)?;
-        let package_address = wasm_process.vm.actor.package_address().clone();
-        let component = Component::new(
-            package_address,
+        let component_address = self.create_component(
             input.blueprint_name,
             input.access_rules_list,
             input.state,
-        );
-        let component_address = self.track.create_component(component);
-        self.track
-            .insert_objects_into_component(new_objects, component_address);
+            false,
+        )?;
 
         Ok(CreateComponentOutput { component_address })
     }
 
-    fn handle_get_component_info(
+    fn handle_create_owned_component(
         &mut self,
-        input: GetComponentInfoInput,
-    ) -> Result<GetComponentInfoOutput, RuntimeError> {
+        input: CreateOwnedComponentInput,
+    ) -> Result<CreateOwnedComponentOutput, RuntimeError> {
+        let component_address = self.create_component(
+            input.blueprint_name,
+            input.access_rules_list,
+            input.state,
+            true,
+        )?;
+        // Owned by this process until stored in a component or lazy map
+        self.owned_snodes.components.insert(component_address);
+
+        Ok(CreateOwnedComponentOutput { component_address })
+    }
+
+    fn handle_get_component_info(
+        &mut self,
+        input: GetComponentInfoInput,
+    ) -> Result<GetComponentInfoOutput, RuntimeError> {
         let component = self
             .track
             .get_component(input.component_address)
@@ -982,10 +1550,14 @@ impl<'r, 'l, L: SubstateStore> Process<'r, 'l, L> {
             .as_mut()
             .ok_or(RuntimeError::IllegalSystemCall)?;
         let component_state = match &wasm_process.interpreter_state {
-            InterpreterState::Component { component, initial_loaded_object_refs, .. } => {
+            InterpreterState::Component {
+                component,
+                initial_loaded_object_refs,
+                ..
+            } => {
                 self.snode_refs.extend(initial_loaded_object_refs.clone());
                 Ok(component.state())
-            },
+            }
             _ => Err(RuntimeError::IllegalSystemCall),
         }?;
         let state = component_state.to_vec();
@@ -996,6 +1568,43 @@ impl<'r, 'l, L: SubstateStore> Process<'r, 'l, L> {
         &mut self,
         input: PutComponentStateInput,
     ) -> Result<PutComponentStateOutput, RuntimeError> {
+        let wasm_process = self
+            .wasm_process_state
+            .as_ref()
+            .ok_or(RuntimeError::IllegalSystemCall)?;
+        let (package_address, package_version, blueprint_name) =
+            match &wasm_process.interpreter_state {
+                InterpreterState::Component {
+                    component,
+                    component_address,
+                    mutability,
+                    ..
+                } => {
+                    if let Mutability::Immutable = mutability {
+                        return Err(RuntimeError::ImmutableMethodWrite(
+                            *component_address,
+                            wasm_process.vm.function.clone(),
+                        ));
+                    }
+                    // State of the old version can't overwrite a migrated component
+                    if component.package_version() != wasm_process.vm.package_version {
+                        return Err(RuntimeError::ComponentMigrated(*component_address));
+                    }
+                    Ok((
+                        component.package_address(),
+                        component.package_version(),
+                        component.blueprint_name().to_owned(),
+                    ))
+                }
+                _ => Err(RuntimeError::IllegalSystemCall),
+            }?;
+        self.validate_component_state(
+            package_address,
+            package_version,
+            &blueprint_name,
+            &input.state,
+        )?;
+
         let wasm_process = self
             .wasm_process_state
             .as_mut()
@@ -1015,7 +1624,11 @@ impl<'r, 'l, L: SubstateStore> Process<'r, 'l, L> {
         }?;
 
         let new_objects = self.owned_snodes.take(new_set)?;
-        self.track.insert_objects_into_component(new_objects, *component_address);
+        self.snode_refs
+            .component_addresses
+            .extend(new_objects.components.iter().cloned());
+        self.track
+            .insert_objects_into_component(new_objects, *component_address);
 
         // TODO: Verify that process_owned_objects is empty
 
@@ -1024,13 +1637,305 @@ impl<'r, 'l, L: SubstateStore> Process<'r, 'l, L> {
         Ok(PutComponentStateOutput {})
     }
 
+    /// Updates the method auth of the current component, once the caller is authorized to do so
+    fn update_component_method_auth<F>(
+        &mut self,
+        component_address: ComponentAddress,
+        access_rules_index: usize,
+        method_name: &str,
+        function: &str,
+        update: F,
+    ) -> Result<(), RuntimeError>
+    where
+        F: FnOnce(&mut Component) -> Result<(), ComponentError>,
+    {
+        match &mut self.wasm_process_state {
+            // Access rules can only be updated from within the component
+            Some(WasmProcess {
+                vm,
+                interpreter_state:
+                    InterpreterState::Component {
+                        component_address: current_address,
+                        component,
+                        mutability,
+                        ..
+                    },
+                ..
+            }) if *current_address == component_address => {
+                if let Mutability::Immutable = mutability {
+                    return Err(RuntimeError::ImmutableMethodWrite(
+                        component_address,
+                        vm.function.clone(),
+                    ));
+                }
+
+                let package_address = component.package_address();
+                let schema = self
+                    .track
+                    .get_package_version(package_address, component.package_version())
+                    .ok_or(PackageError::VersionNotFound(component.package_version()))
+                    .and_then(|code| code.load_blueprint_schema(component.blueprint_name()))
+                    .map_err(RuntimeError::PackageError)?;
+                let authorization = component
+                    .method_mutability_authorization(schema, access_rules_index, method_name)
+                    .map_err(RuntimeError::ComponentError)?;
+
+                let mut auth_zones = Vec::new();
+                if let Some(self_auth_zone) = &self.auth_zone {
+                    auth_zones.push(self_auth_zone);
+                }
+                if let Some(auth_zone) = self.caller_auth_zone {
+                    auth_zones.push(auth_zone);
+                }
+                authorization.check(&auth_zones).map_err(|error| {
+                    RuntimeError::AuthorizationError {
+                        function: function.to_owned(),
+                        authorization,
+                        error,
+                    }
+                })?;
+
+                update(component).map_err(RuntimeError::ComponentError)
+            }
+            _ => Err(RuntimeError::IllegalSystemCall),
+        }
+    }
+
+    fn handle_set_component_method_auth(
+        &mut self,
+        input: SetComponentMethodAuthInput,
+    ) -> Result<SetComponentMethodAuthOutput, RuntimeError> {
+        let access_rules_index = input.access_rules_index as usize;
+        let method_name = input.method_name;
+        let access_rule = input.access_rule;
+        self.update_component_method_auth(
+            input.component_address,
+            access_rules_index,
+            &method_name,
+            "set_method_auth",
+            |component| component.set_method_auth(access_rules_index, &method_name, access_rule),
+        )?;
+
+        Ok(SetComponentMethodAuthOutput {})
+    }
+
+    fn handle_lock_component_method_auth(
+        &mut self,
+        input: LockComponentMethodAuthInput,
+    ) -> Result<LockComponentMethodAuthOutput, RuntimeError> {
+        let access_rules_index = input.access_rules_index as usize;
+        self.update_component_method_auth(
+            input.component_address,
+            access_rules_index,
+            &input.method_name,
+            "lock_method_auth",
+            |component| component.lock_method_auth(access_rules_index, &input.method_name),
+        )?;
+
+        Ok(LockComponentMethodAuthOutput {})
+    }
+
+    /// Checks that a committed vault holds no resources, and no proofs have been created from it.
+    fn check_committed_vault_empty(
+        &mut self,
+        component_address: ComponentAddress,
+        vault_id: VaultId,
+    ) -> Result<(), RuntimeError> {
+        let vault = self.track.borrow_vault_mut(&component_address, &vault_id);
+        let is_empty = vault.is_empty() && !vault.is_locked();
+        self.track
+            .return_borrowed_vault(&component_address, &vault_id, vault);
+
+        if is_empty {
+            Ok(())
+        } else {
+            Err(RuntimeError::VaultNotEmpty(vault_id))
+        }
+    }
+
+    /// Deletes all vaults, lazy map entries and owned components of a component, given its state.
+    fn delete_component_substates(
+        &mut self,
+        component_address: ComponentAddress,
+        state: &[u8],
+    ) -> Result<(), RuntimeError> {
+        let refs = Self::process_entry_data(state)?;
+        let mut vault_ids = refs.vault_ids;
+        let mut component_addresses = refs.component_addresses;
+        let mut lazy_map_ids: Vec<LazyMapId> = refs.lazy_map_ids.into_iter().collect();
+        let mut entries = Vec::new();
+        while let Some(lazy_map_id) = lazy_map_ids.pop() {
+            for (key, value) in self
+                .track
+                .get_lazy_map_entries(component_address, &lazy_map_id)
+            {
+                let entry_refs = Self::process_entry_data(&value)?;
+                vault_ids.extend(entry_refs.vault_ids);
+                component_addresses.extend(entry_refs.component_addresses);
+                lazy_map_ids.extend(entry_refs.lazy_map_ids);
+                entries.push((lazy_map_id, key));
+            }
+        }
+
+        // No resources may be destroyed
+        for vault_id in &vault_ids {
+            self.check_committed_vault_empty(component_address, *vault_id)?;
+        }
+
+        for child_address in component_addresses {
+            let child = self.track.borrow_global_mut_component(child_address)?;
+            let child_state = child.state().to_vec();
+            self.track
+                .return_borrowed_global_component(child_address, child, false);
+            self.delete_component_substates(child_address, &child_state)?;
+            self.track.delete_component(child_address);
+        }
+        for vault_id in vault_ids {
+            self.track.delete_vault(component_address, vault_id);
+        }
+        for (lazy_map_id, key) in entries {
+            self.track
+                .remove_lazy_map_entry(component_address, lazy_map_id, key);
+        }
+
+        Ok(())
+    }
+
+    fn handle_delete_component(
+        &mut self,
+        input: DeleteComponentInput,
+    ) -> Result<DeleteComponentOutput, RuntimeError> {
+        let wasm_process = self
+            .wasm_process_state
+            .as_ref()
+            .ok_or(RuntimeError::IllegalSystemCall)?;
+        let state = match &wasm_process.interpreter_state {
+            // Components can only be deleted from within, and owned ones along with their owner
+            InterpreterState::Component {
+                component_address,
+                component,
+                mutability,
+                ..
+            } if *component_address == input.component_address => {
+                if let Mutability::Immutable = mutability {
+                    return Err(RuntimeError::ImmutableMethodWrite(
+                        *component_address,
+                        wasm_process.vm.function.clone(),
+                    ));
+                }
+                if component.is_owned() {
+                    return Err(RuntimeError::OwnedComponentNotDeletable(
+                        input.component_address,
+                    ));
+                }
+                Ok(component.state().to_vec())
+            }
+            _ => Err(RuntimeError::IllegalSystemCall),
+        }?;
+        // Nested read-only calls can't pull the component from under their callers
+        if self
+            .track
+            .is_shared_borrowed_component(input.component_address)
+        {
+            return Err(RuntimeError::ComponentReentrancy(input.component_address));
+        }
+
+        self.delete_component_substates(input.component_address, &state)?;
+        self.track.delete_component(input.component_address);
+
+        Ok(DeleteComponentOutput {})
+    }
+
+    fn handle_migrate_component(
+        &mut self,
+        input: MigrateComponentInput,
+    ) -> Result<MigrateComponentOutput, RuntimeError> {
+        let wasm_process = self
+            .wasm_process_state
+            .as_ref()
+            .ok_or(RuntimeError::IllegalSystemCall)?;
+        let (package_address, package_version, blueprint_name, old_state) =
+            match &wasm_process.interpreter_state {
+                // Components can only be migrated from within, by a method which may write state
+                InterpreterState::Component {
+                    component_address,
+                    component,
+                    mutability,
+                    ..
+                } if *component_address == input.component_address => {
+                    if let Mutability::Immutable = mutability {
+                        return Err(RuntimeError::ImmutableMethodWrite(
+                            *component_address,
+                            wasm_process.vm.function.clone(),
+                        ));
+                    }
+                    Ok((
+                        component.package_address(),
+                        component.package_version(),
+                        component.blueprint_name().to_owned(),
+                        component.state().to_vec(),
+                    ))
+                }
+                _ => Err(RuntimeError::IllegalSystemCall),
+            }?;
+        let latest_version = self
+            .track
+            .get_package(&package_address)
+            .ok_or(RuntimeError::PackageNotFound(package_address))?
+            .latest_version();
+        if package_version == latest_version {
+            return Err(RuntimeError::ComponentAlreadyMigrated(
+                input.component_address,
+            ));
+        }
+
+        // Blueprint functions run the latest version
+        let result = self.invoke_snode(
+            SNodeRef::Scrypto(ScryptoActor::Blueprint(
+                package_address,
+                blueprint_name.clone(),
+            )),
+            "migrate".to_string(),
+            vec![ScryptoValue::from_value(&old_state)],
+        )?;
+        let new_state: Vec<u8> = scrypto_decode(&result.raw)
+            .map_err(|_| RuntimeError::InvalidMigratedState(input.component_address))?;
+        self.validate_component_state(
+            package_address,
+            latest_version,
+            &blueprint_name,
+            &new_state,
+        )?;
+
+        // Vaults, lazy maps and owned components are carried over as they are
+        let old_refs = Self::process_entry_data(&old_state)?;
+        let new_refs = Self::process_entry_data(&new_state)?;
+        if new_refs.vault_ids != old_refs.vault_ids
+            || new_refs.lazy_map_ids != old_refs.lazy_map_ids
+            || new_refs.component_addresses != old_refs.component_addresses
+        {
+            return Err(RuntimeError::InvalidMigratedState(input.component_address));
+        }
+
+        match &mut self.wasm_process_state {
+            Some(WasmProcess {
+                interpreter_state: InterpreterState::Component { component, .. },
+                ..
+            }) => component.migrate(latest_version, new_state),
+            _ => panic!("Should not get here."),
+        }
+
+        Ok(MigrateComponentOutput {
+            package_version: latest_version,
+        })
+    }
+
     fn handle_create_lazy_map(
         &mut self,
         _input: CreateLazyMapInput,
     ) -> Result<CreateLazyMapOutput, RuntimeError> {
         let lazy_map_id = self.track.new_lazy_map_id();
-        self
-            .owned_snodes
+        self.owned_snodes
             .lazy_maps
             .insert(lazy_map_id, UnclaimedLazyMap::new());
         Ok(CreateLazyMapOutput { lazy_map_id })
@@ -1042,7 +1947,8 @@ impl<'r, 'l, L: SubstateStore> Process<'r, 'l, L> {
     ) -> Result<GetLazyMapEntryOutput, RuntimeError> {
         if let Some((_, value)) = self
             .owned_snodes
-            .get_lazy_map_entry(&input.lazy_map_id, &input.key) {
+            .get_lazy_map_entry(&input.lazy_map_id, &input.key)
+        {
             return Ok(GetLazyMapEntryOutput { value });
         }
 
@@ -1050,15 +1956,19 @@ impl<'r, 'l, L: SubstateStore> Process<'r, 'l, L> {
             return Err(RuntimeError::LazyMapNotFound(input.lazy_map_id));
         }
 
-        if let Some(WasmProcess { interpreter_state: InterpreterState::Component { component_address, .. }, .. }) = &self.wasm_process_state {
-            let value = self.track.get_lazy_map_entry(
-                *component_address,
-                &input.lazy_map_id,
-                &input.key,
-            );
+        if let Some(WasmProcess {
+            interpreter_state:
+                InterpreterState::Component {
+                    component_address, ..
+                },
+            ..
+        }) = &self.wasm_process_state
+        {
+            let value =
+                self.track
+                    .get_lazy_map_entry(*component_address, &input.lazy_map_id, &input.key);
             if value.is_some() {
-                let map_entry_objects =
-                    Self::process_entry_data(&value.as_ref().unwrap()).unwrap();
+                let map_entry_objects = Self::process_entry_data(&value.as_ref().unwrap()).unwrap();
                 self.snode_refs.extend(map_entry_objects);
             }
 
@@ -1083,12 +1993,16 @@ impl<'r, 'l, L: SubstateStore> Process<'r, 'l, L> {
             None => match &wasm_process.interpreter_state {
                 InterpreterState::Component {
                     component_address,
+                    mutability,
                     ..
                 } => {
-                    if !self.snode_refs
-                            .lazy_map_ids
-                            .contains(&input.lazy_map_id)
-                    {
+                    if let Mutability::Immutable = mutability {
+                        return Err(RuntimeError::ImmutableMethodWrite(
+                            *component_address,
+                            wasm_process.vm.function.clone(),
+                        ));
+                    }
+                    if !self.snode_refs.lazy_map_ids.contains(&input.lazy_map_id) {
                         return Err(RuntimeError::LazyMapNotFound(input.lazy_map_id));
                     }
                     let old_value = self.track.get_lazy_map_entry(
@@ -1121,19 +2035,13 @@ impl<'r, 'l, L: SubstateStore> Process<'r, 'l, L> {
             }
         }
 
-        let new_objects = self
-            .owned_snodes
-            .take(new_entry_object_refs)?;
+        let new_objects = self.owned_snodes.take(new_entry_object_refs)?;
 
         match lazy_map_state {
             Uncommitted { root } => {
-                self.owned_snodes.insert_lazy_map_entry(
-                    &input.lazy_map_id,
-                    input.key,
-                    input.value,
-                );
-                self
-                    .owned_snodes
+                self.owned_snodes
+                    .insert_lazy_map_entry(&input.lazy_map_id, input.key, input.value);
+                self.owned_snodes
                     .insert_objects_into_map(new_objects, &root);
             }
             Committed { component_address } => {
@@ -1151,6 +2059,147 @@ impl<'r, 'l, L: SubstateStore> Process<'r, 'l, L> {
         Ok(PutLazyMapEntryOutput {})
     }
 
+    fn handle_remove_lazy_map_entry(
+        &mut self,
+        input: RemoveLazyMapEntryInput,
+    ) -> Result<RemoveLazyMapEntryOutput, RuntimeError> {
+        let wasm_process = self
+            .wasm_process_state
+            .as_mut()
+            .ok_or(RuntimeError::IllegalSystemCall)?;
+        let (old_value, lazy_map_state) = match self
+            .owned_snodes
+            .get_lazy_map_entry(&input.lazy_map_id, &input.key)
+        {
+            None => match &wasm_process.interpreter_state {
+                InterpreterState::Component {
+                    component_address,
+                    mutability,
+                    ..
+                } => {
+                    if let Mutability::Immutable = mutability {
+                        return Err(RuntimeError::ImmutableMethodWrite(
+                            *component_address,
+                            wasm_process.vm.function.clone(),
+                        ));
+                    }
+                    if !self.snode_refs.lazy_map_ids.contains(&input.lazy_map_id) {
+                        return Err(RuntimeError::LazyMapNotFound(input.lazy_map_id));
+                    }
+                    let old_value = self.track.get_lazy_map_entry(
+                        *component_address,
+                        &input.lazy_map_id,
+                        &input.key,
+                    );
+                    Ok((
+                        old_value,
+                        Committed {
+                            component_address: *component_address,
+                        },
+                    ))
+                }
+                _ => Err(RuntimeError::LazyMapNotFound(input.lazy_map_id)),
+            },
+            Some((root, value)) => Ok((value, Uncommitted { root })),
+        }?;
+
+        // Vaults in the removed entry move to this process, to be stored elsewhere or dropped,
+        // whereas lazy maps and components would be orphaned
+        if let Some(old_value) = &old_value {
+            let mut old_entry_object_refs = Self::process_entry_data(old_value)?;
+            let vault_ids: Vec<VaultId> = old_entry_object_refs.vault_ids.drain().collect();
+            ComponentObjectRefs::new().remove(&old_entry_object_refs)?;
+
+            for vault_id in vault_ids {
+                let vault = match &lazy_map_state {
+                    Uncommitted { root } => self
+                        .owned_snodes
+                        .take_lazy_map_vault(root, &vault_id)
+                        .ok_or(RuntimeError::VaultNotFound(vault_id))?,
+                    Committed { component_address } => {
+                        self.snode_refs.vault_ids.remove(&vault_id);
+                        self.track.take_vault(*component_address, vault_id)
+                    }
+                };
+                self.owned_snodes.vaults.insert(vault_id, vault);
+            }
+        }
+
+        match lazy_map_state {
+            Uncommitted { .. } => {
+                self.owned_snodes
+                    .remove_lazy_map_entry(&input.lazy_map_id, &input.key);
+            }
+            Committed { component_address } => {
+                if old_value.is_some() {
+                    self.track.remove_lazy_map_entry(
+                        component_address,
+                        input.lazy_map_id,
+                        input.key,
+                    );
+                }
+            }
+        }
+
+        Ok(RemoveLazyMapEntryOutput { value: old_value })
+    }
+
+    /// Returns all entries of a lazy map, in the order of the encoded keys, along with whether
+    /// the lazy map is stored in the current component rather than owned by this process.
+    fn read_lazy_map_entries(
+        &mut self,
+        lazy_map_id: LazyMapId,
+    ) -> Result<(Vec<(Vec<u8>, Vec<u8>)>, bool), RuntimeError> {
+        if let Some(entries) = self.owned_snodes.get_lazy_map_entries(&lazy_map_id) {
+            return Ok((entries, false));
+        }
+
+        if !self.snode_refs.lazy_map_ids.contains(&lazy_map_id) {
+            return Err(RuntimeError::LazyMapNotFound(lazy_map_id));
+        }
+
+        match &self.wasm_process_state {
+            Some(WasmProcess {
+                interpreter_state:
+                    InterpreterState::Component {
+                        component_address, ..
+                    },
+                ..
+            }) => {
+                let entries = self
+                    .track
+                    .get_lazy_map_entries(*component_address, &lazy_map_id);
+                Ok((entries, true))
+            }
+            _ => Err(RuntimeError::IllegalSystemCall),
+        }
+    }
+
+    fn handle_get_lazy_map_entries(
+        &mut self,
+        input: GetLazyMapEntriesInput,
+    ) -> Result<GetLazyMapEntriesOutput, RuntimeError> {
+        let (entries, committed) = self.read_lazy_map_entries(input.lazy_map_id)?;
+        if committed {
+            for (_, value) in &entries {
+                let map_entry_objects = Self::process_entry_data(value)?;
+                self.snode_refs.extend(map_entry_objects);
+            }
+        }
+
+        Ok(GetLazyMapEntriesOutput { entries })
+    }
+
+    fn handle_get_lazy_map_keys(
+        &mut self,
+        input: GetLazyMapKeysInput,
+    ) -> Result<GetLazyMapKeysOutput, RuntimeError> {
+        let (entries, _) = self.read_lazy_map_entries(input.lazy_map_id)?;
+        let keys = entries.into_iter().map(|(key, _)| key).collect();
+
+        Ok(GetLazyMapKeysOutput { keys })
+    }
+
     fn handle_create_vault(
         &mut self,
         input: CreateEmptyVaultInput,
@@ -1167,14 +2216,69 @@ impl<'r, 'l, L: SubstateStore> Process<'r, 'l, L> {
             definition.resource_type(),
         ));
         let vault_id = self.track.new_vault_id();
-        self
-            .owned_snodes
-            .vaults
-            .insert(vault_id, new_vault);
+        self.owned_snodes.vaults.insert(vault_id, new_vault);
 
         Ok(CreateEmptyVaultOutput { vault_id })
     }
 
+    fn handle_drop_empty_vault(
+        &mut self,
+        input: DropEmptyVaultInput,
+    ) -> Result<DropEmptyVaultOutput, RuntimeError> {
+        if let Some(vault) = self.owned_snodes.vaults.get(&input.vault_id) {
+            if !vault.is_empty() || vault.is_locked() {
+                return Err(RuntimeError::VaultNotEmpty(input.vault_id));
+            }
+            self.owned_snodes.vaults.remove(&input.vault_id);
+            return Ok(DropEmptyVaultOutput {});
+        }
+
+        // Otherwise, only vaults in the state of the current component can be dropped
+        let component_address = match &self.wasm_process_state {
+            Some(WasmProcess {
+                interpreter_state:
+                    InterpreterState::Component {
+                        component_address,
+                        initial_loaded_object_refs,
+                        mutability,
+                        ..
+                    },
+                vm,
+                ..
+            }) if initial_loaded_object_refs
+                .vault_ids
+                .contains(&input.vault_id) =>
+            {
+                if let Mutability::Immutable = mutability {
+                    return Err(RuntimeError::ImmutableMethodWrite(
+                        *component_address,
+                        vm.function.clone(),
+                    ));
+                }
+                Ok(*component_address)
+            }
+            _ => Err(RuntimeError::VaultNotFound(input.vault_id)),
+        }?;
+        self.check_committed_vault_empty(component_address, input.vault_id)?;
+        self.track.delete_vault(component_address, input.vault_id);
+
+        // The vault must no longer be referenced by the component state
+        if let Some(WasmProcess {
+            interpreter_state:
+                InterpreterState::Component {
+                    initial_loaded_object_refs,
+                    ..
+                },
+            ..
+        }) = &mut self.wasm_process_state
+        {
+            initial_loaded_object_refs.vault_ids.remove(&input.vault_id);
+        }
+        self.snode_refs.vault_ids.remove(&input.vault_id);
+
+        Ok(DropEmptyVaultOutput {})
+    }
+
     fn handle_invoke_snode(
         &mut self,
         input: InvokeSNodeInput,
@@ -1190,6 +2294,24 @@ impl<'r, 'l, L: SubstateStore> Process<'r, 'l, L> {
         Ok(InvokeSNodeOutput { rtn: result.raw })
     }
 
+    fn handle_try_invoke_snode(
+        &mut self,
+        input: TryInvokeSNodeInput,
+    ) -> Result<TryInvokeSNodeOutput, RuntimeError> {
+        let mut validated_args = Vec::new();
+        for arg in input.args {
+            validated_args.push(
+                ScryptoValue::from_slice(&arg).map_err(RuntimeError::ParseScryptoValueError)?,
+            );
+        }
+
+        let result = self
+            .try_invoke_snode(input.snode_ref, input.function, validated_args)
+            .map(|result| result.raw)
+            .map_err(Self::call_error);
+        Ok(TryInvokeSNodeOutput { rtn: result })
+    }
+
     fn handle_emit_log(&mut self, input: EmitLogInput) -> Result<EmitLogOutput, RuntimeError> {
         self.track.add_log(input.level, input.message);
 
@@ -1225,6 +2347,15 @@ impl<'r, 'l, L: SubstateStore> Process<'r, 'l, L> {
         })
     }
 
+    fn handle_get_transaction_signers(
+        &mut self,
+        _input: GetTransactionSignersInput,
+    ) -> Result<GetTransactionSignersOutput, RuntimeError> {
+        Ok(GetTransactionSignersOutput {
+            transaction_signers: self.track.transaction_signers(),
+        })
+    }
+
     fn handle_get_current_epoch(
         &mut self,
         _input: GetCurrentEpochInput,
@@ -1253,21 +2384,67 @@ impl<'r, 'l, L: SubstateStore> Process<'r, 'l, L> {
             actor: wasm_process.vm.actor.clone(),
         });
     }
-    
-    fn handle_check_access_rule(&mut self, input: CheckAccessRuleInput) -> Result<CheckAccessRuleOutput, RuntimeError> {
-        let proofs = input.proof_ids
+
+    fn handle_get_caller(
+        &mut self,
+        _input: GetCallerInput,
+    ) -> Result<GetCallerOutput, RuntimeError> {
+        Ok(GetCallerOutput {
+            caller: self.caller.clone(),
+        })
+    }
+
+    fn handle_check_access_rule(
+        &mut self,
+        input: CheckAccessRuleInput,
+    ) -> Result<CheckAccessRuleOutput, RuntimeError> {
+        let proofs = input
+            .proof_ids
             .iter()
-            .map(|proof_id| self.proofs.get(&proof_id).ok_or(RuntimeError::ProofNotFound(proof_id.clone())).unwrap().clone())
+            .map(|proof_id| {
+                self.proofs
+                    .get(&proof_id)
+                    .ok_or(RuntimeError::ProofNotFound(proof_id.clone()))
+                    .unwrap()
+                    .clone()
+            })
             .collect::<Vec<Proof>>();
         let mut simulated_auth_zone = AuthZone::new_with_proofs(proofs);
 
         let method_authorization = convert(&Type::Unit, &Value::Unit, &input.access_rule);
         let is_authorized = method_authorization.check(&[&simulated_auth_zone]).is_ok();
-        simulated_auth_zone.main("clear", Vec::new(), self).map_err(RuntimeError::AuthZoneError)?;
+        simulated_auth_zone
+            .main("clear", Vec::new(), self)
+            .map_err(RuntimeError::AuthZoneError)?;
 
-        return Ok(CheckAccessRuleOutput{
-            is_authorized
-        });
+        return Ok(CheckAccessRuleOutput { is_authorized });
+    }
+
+    fn handle_panic(&mut self, input: PanicInput) -> Result<PanicOutput, RuntimeError> {
+        let wasm_process = self
+            .wasm_process_state
+            .as_ref()
+            .ok_or(RuntimeError::InterpreterNotStarted)?;
+
+        Err(RuntimeError::Panic {
+            actor: wasm_process.vm.actor.actor(),
+            message: input.message,
+            location: input.location,
+        })
+    }
+
+    fn handle_abort(&mut self, input: AbortInput) -> Result<AbortOutput, RuntimeError> {
+        let wasm_process = self
+            .wasm_process_state
+            .as_ref()
+            .ok_or(RuntimeError::InterpreterNotStarted)?;
+        let error =
+            ScryptoValue::from_slice(&input.error).map_err(RuntimeError::ParseScryptoValueError)?;
+
+        Err(RuntimeError::ApplicationError {
+            actor: wasm_process.vm.actor.actor(),
+            error,
+        })
     }
 
     //============================
@@ -1325,7 +2502,8 @@ impl<'r, 'l, L: SubstateStore> SystemApi for Process<'r, 'l, L> {
     }
 
     fn take_proof(&mut self, proof_id: ProofId) -> Result<Proof, RuntimeError> {
-        let proof = self.proofs
+        let proof = self
+            .proofs
             .remove(&proof_id)
             .ok_or(RuntimeError::ProofNotFound(proof_id))?;
 
@@ -1348,8 +2526,18 @@ impl<'r, 'l, L: SubstateStore> SystemApi for Process<'r, 'l, L> {
         self.track.create_resource_manager(resource_manager)
     }
 
-    fn create_package(&mut self, package: Package) -> PackageAddress {
-        self.track.create_package(package)
+    fn create_package(&mut self, package: Package, version: PackageVersion) -> PackageAddress {
+        self.track.create_package(package, version)
+    }
+
+    fn create_package_version(
+        &mut self,
+        package_address: PackageAddress,
+        version: u32,
+        package_version: PackageVersion,
+    ) {
+        self.track
+            .create_package_version(package_address, version, package_version)
     }
 }
 
@@ -1367,24 +2555,47 @@ impl<'r, 'l, L: SubstateStore> Externals for Process<'r, 'l, L> {
                     GET_COMPONENT_INFO => self.handle(args, Self::handle_get_component_info),
                     GET_COMPONENT_STATE => self.handle(args, Self::handle_get_component_state),
                     PUT_COMPONENT_STATE => self.handle(args, Self::handle_put_component_state),
+                    SET_COMPONENT_METHOD_AUTH => {
+                        self.handle(args, Self::handle_set_component_method_auth)
+                    }
+                    LOCK_COMPONENT_METHOD_AUTH => {
+                        self.handle(args, Self::handle_lock_component_method_auth)
+                    }
+                    CREATE_OWNED_COMPONENT => {
+                        self.handle(args, Self::handle_create_owned_component)
+                    }
+                    DELETE_COMPONENT => self.handle(args, Self::handle_delete_component),
+                    MIGRATE_COMPONENT => self.handle(args, Self::handle_migrate_component),
 
                     CREATE_LAZY_MAP => self.handle(args, Self::handle_create_lazy_map),
                     GET_LAZY_MAP_ENTRY => self.handle(args, Self::handle_get_lazy_map_entry),
                     PUT_LAZY_MAP_ENTRY => self.handle(args, Self::handle_put_lazy_map_entry),
+                    REMOVE_LAZY_MAP_ENTRY => self.handle(args, Self::handle_remove_lazy_map_entry),
+                    GET_LAZY_MAP_ENTRIES => self.handle(args, Self::handle_get_lazy_map_entries),
+                    GET_LAZY_MAP_KEYS => self.handle(args, Self::handle_get_lazy_map_keys),
 
                     CREATE_EMPTY_VAULT => self.handle(args, Self::handle_create_vault),
+                    DROP_EMPTY_VAULT => self.handle(args, Self::handle_drop_empty_vault),
 
                     INVOKE_SNODE => self.handle(args, Self::handle_invoke_snode),
+                    TRY_INVOKE_SNODE => self.handle(args, Self::handle_try_invoke_snode),
 
                     EMIT_LOG => self.handle(args, Self::handle_emit_log),
                     GET_CALL_DATA => self.handle(args, Self::handle_get_call_data),
                     GET_TRANSACTION_HASH => self.handle(args, Self::handle_get_transaction_hash),
+                    GET_TRANSACTION_SIGNERS => {
+                        self.handle(args, Self::handle_get_transaction_signers)
+                    }
                     GET_CURRENT_EPOCH => self.handle(args, Self::handle_get_current_epoch),
                     GENERATE_UUID => self.handle(args, Self::handle_generate_uuid),
                     GET_ACTOR => self.handle(args, Self::handle_get_actor),
+                    GET_CALLER => self.handle(args, Self::handle_get_caller),
 
                     CHECK_ACCESS_RULE => self.handle(args, Self::handle_check_access_rule),
 
+                    PANIC => self.handle(args, Self::handle_panic),
+                    ABORT => self.handle(args, Self::handle_abort),
+
                     _ => Err(RuntimeError::InvalidRequestCode(operation).into()),
                 }
             }
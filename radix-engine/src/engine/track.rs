@@ -1,10 +1,11 @@
-use indexmap::IndexMap;
+use scrypto::buffer::scrypto_encode;
 use scrypto::constants::*;
 use scrypto::engine::types::*;
 use scrypto::rust::collections::*;
 use scrypto::rust::string::String;
 use scrypto::rust::vec::Vec;
 
+use crate::engine::journaled_map::{JournaledMap, Restore};
 use crate::engine::*;
 use crate::errors::RuntimeError;
 use crate::ledger::*;
@@ -32,11 +33,38 @@ impl CommitReceipt {
     }
 }
 
+#[derive(Clone)]
 struct SubstateUpdate<T> {
     prev_id: Option<(Hash, u32)>,
     value: T,
 }
 
+impl Restore for SubstateUpdate<Vault> {
+    type Saved = SubstateUpdate<VaultSnapshot>;
+
+    fn save(&self) -> Self::Saved {
+        SubstateUpdate {
+            prev_id: self.prev_id.clone(),
+            value: self.value.snapshot(),
+        }
+    }
+
+    fn restore(saved: Self::Saved) -> Self {
+        SubstateUpdate {
+            prev_id: saved.prev_id,
+            value: saved.value.restore(),
+        }
+    }
+}
+
+/// A savepoint of the state updates of a track, which it can be rolled back to.
+///
+/// Savepoints are closed in the reverse order they are taken, by either a rollback or
+/// a release.
+pub struct TrackSavepoint {
+    depth: usize,
+}
+
 /// An abstraction of transaction execution state.
 ///
 /// It acts as the facade of ledger state and keeps track of all temporary state updates,
@@ -50,21 +78,37 @@ pub struct Track<'s, S: SubstateStore> {
     transaction_signers: Vec<EcdsaPublicKey>,
     id_allocator: IdAllocator,
     logs: Vec<(Level, String)>,
-
-    packages: IndexMap<PackageAddress, SubstateUpdate<Package>>,
-
-    components: IndexMap<ComponentAddress, SubstateUpdate<Component>>,
-    borrowed_components: HashMap<ComponentAddress, Option<(Hash, u32)>>,
-
-    resource_managers: IndexMap<ResourceAddress, SubstateUpdate<ResourceManager>>,
-    borrowed_resource_managers: HashMap<ResourceAddress, Option<(Hash, u32)>>,
-
-    vaults: HashMap<(ComponentAddress, VaultId), SubstateUpdate<Vault>>,
-    borrowed_vaults: HashMap<(ComponentAddress, VaultId), Option<(Hash, u32)>>,
-
-    non_fungibles: HashMap<NonFungibleAddress, SubstateUpdate<Option<NonFungible>>>,
-
-    lazy_map_entries: HashMap<(ComponentAddress, LazyMapId, Vec<u8>), SubstateUpdate<Vec<u8>>>,
+    /// Number of open savepoints.
+    savepoints: usize,
+
+    packages: JournaledMap<PackageAddress, SubstateUpdate<Package>>,
+    borrowed_packages: JournaledMap<PackageAddress, Option<(Hash, u32)>>,
+    /// Loaded and new package versions, which are never updated once published.
+    package_versions: JournaledMap<(PackageAddress, u32), SubstateUpdate<PackageVersion>>,
+
+    components: JournaledMap<ComponentAddress, SubstateUpdate<Component>>,
+    /// Borrowed components, with the ids of their substates and whether they had been updated.
+    borrowed_components: JournaledMap<ComponentAddress, (Option<(Hash, u32)>, bool)>,
+    /// Views of borrowed components as last seen by their frames, for nested read-only calls.
+    borrowed_component_views: JournaledMap<ComponentAddress, Component>,
+    /// Number of nested read-only borrows of borrowed components.
+    shared_borrowed_components: JournaledMap<ComponentAddress, usize>,
+    /// Deleted components, with the ids of their substates to be downed.
+    deleted_components: JournaledMap<ComponentAddress, Option<(Hash, u32)>>,
+
+    resource_managers: JournaledMap<ResourceAddress, SubstateUpdate<ResourceManager>>,
+    borrowed_resource_managers: JournaledMap<ResourceAddress, Option<(Hash, u32)>>,
+
+    vaults: JournaledMap<(ComponentAddress, VaultId), SubstateUpdate<Vault>>,
+    borrowed_vaults: JournaledMap<(ComponentAddress, VaultId), Option<(Hash, u32)>>,
+    /// Deleted vaults, with the ids of their substates to be downed.
+    deleted_vaults: JournaledMap<(ComponentAddress, VaultId), Option<(Hash, u32)>>,
+
+    non_fungibles: JournaledMap<NonFungibleAddress, SubstateUpdate<Option<NonFungible>>>,
+
+    /// Lazy map entries, where `None` is a tombstone of a removed entry.
+    lazy_map_entries:
+        JournaledMap<(ComponentAddress, LazyMapId, Vec<u8>), SubstateUpdate<Option<Vec<u8>>>>,
 }
 
 impl<'s, S: SubstateStore> Track<'s, S> {
@@ -79,18 +123,97 @@ impl<'s, S: SubstateStore> Track<'s, S> {
             transaction_signers,
             id_allocator: IdAllocator::new(IdSpace::Application),
             logs: Vec::new(),
-            packages: IndexMap::new(),
-            components: IndexMap::new(),
-            borrowed_components: HashMap::new(),
-            resource_managers: IndexMap::new(),
-            borrowed_resource_managers: HashMap::new(),
-            lazy_map_entries: HashMap::new(),
-            vaults: HashMap::new(),
-            borrowed_vaults: HashMap::new(),
-            non_fungibles: HashMap::new(),
+            savepoints: 0,
+            packages: JournaledMap::new(),
+            borrowed_packages: JournaledMap::new(),
+            package_versions: JournaledMap::new(),
+            components: JournaledMap::new(),
+            borrowed_components: JournaledMap::new(),
+            borrowed_component_views: JournaledMap::new(),
+            shared_borrowed_components: JournaledMap::new(),
+            deleted_components: JournaledMap::new(),
+            resource_managers: JournaledMap::new(),
+            borrowed_resource_managers: JournaledMap::new(),
+            lazy_map_entries: JournaledMap::new(),
+            vaults: JournaledMap::new(),
+            borrowed_vaults: JournaledMap::new(),
+            deleted_vaults: JournaledMap::new(),
+            non_fungibles: JournaledMap::new(),
         }
     }
 
+    /// Takes a savepoint of the state updates so far.
+    ///
+    /// Logs and allocated ids are not part of a savepoint.
+    pub fn savepoint(&mut self) -> TrackSavepoint {
+        self.packages.savepoint();
+        self.borrowed_packages.savepoint();
+        self.package_versions.savepoint();
+        self.components.savepoint();
+        self.borrowed_components.savepoint();
+        self.borrowed_component_views.savepoint();
+        self.shared_borrowed_components.savepoint();
+        self.deleted_components.savepoint();
+        self.resource_managers.savepoint();
+        self.borrowed_resource_managers.savepoint();
+        self.vaults.savepoint();
+        self.borrowed_vaults.savepoint();
+        self.deleted_vaults.savepoint();
+        self.non_fungibles.savepoint();
+        self.lazy_map_entries.savepoint();
+        self.savepoints += 1;
+        TrackSavepoint {
+            depth: self.savepoints,
+        }
+    }
+
+    /// Rolls back all state updates made since the savepoint was taken.
+    pub fn rollback(&mut self, savepoint: TrackSavepoint) {
+        self.close_savepoint(savepoint);
+        self.packages.rollback();
+        self.borrowed_packages.rollback();
+        self.package_versions.rollback();
+        self.components.rollback();
+        self.borrowed_components.rollback();
+        self.borrowed_component_views.rollback();
+        self.shared_borrowed_components.rollback();
+        self.deleted_components.rollback();
+        self.resource_managers.rollback();
+        self.borrowed_resource_managers.rollback();
+        self.vaults.rollback();
+        self.borrowed_vaults.rollback();
+        self.deleted_vaults.rollback();
+        self.non_fungibles.rollback();
+        self.lazy_map_entries.rollback();
+    }
+
+    /// Keeps all state updates made since the savepoint was taken.
+    pub fn release(&mut self, savepoint: TrackSavepoint) {
+        self.close_savepoint(savepoint);
+        self.packages.release();
+        self.borrowed_packages.release();
+        self.package_versions.release();
+        self.components.release();
+        self.borrowed_components.release();
+        self.borrowed_component_views.release();
+        self.shared_borrowed_components.release();
+        self.deleted_components.release();
+        self.resource_managers.release();
+        self.borrowed_resource_managers.release();
+        self.vaults.release();
+        self.borrowed_vaults.release();
+        self.deleted_vaults.release();
+        self.non_fungibles.release();
+        self.lazy_map_entries.release();
+    }
+
+    fn close_savepoint(&mut self, savepoint: TrackSavepoint) {
+        if savepoint.depth != self.savepoints {
+            panic!("Savepoints must be closed in reverse order");
+        }
+        self.savepoints -= 1;
+    }
+
     /// Start a process.
     pub fn start_process<'r>(&'r mut self, verbose: bool) -> Process<'r, 's, S> {
         let signers: BTreeSet<NonFungibleId> = self
@@ -132,6 +255,11 @@ impl<'s, S: SubstateStore> Track<'s, S> {
         self.transaction_hash
     }
 
+    /// Returns the public keys of the transaction signers.
+    pub fn transaction_signers(&self) -> Vec<EcdsaPublicKey> {
+        self.transaction_signers.clone()
+    }
+
     /// Returns the current epoch.
     pub fn current_epoch(&self) -> u64 {
         self.substate_store.get_epoch()
@@ -153,11 +281,12 @@ impl<'s, S: SubstateStore> Track<'s, S> {
         package_addresses
     }
 
-    /// Returns new components created so far.
+    /// Returns new global components created so far.
     pub fn new_component_addresses(&self) -> Vec<ComponentAddress> {
         let mut component_addresses = Vec::new();
         for (component_address, update) in self.components.iter() {
-            if let None = update.prev_id {
+            // Owned components are only reachable through their owner
+            if update.prev_id.is_none() && !update.value.is_owned() {
                 component_addresses.push(component_address.clone());
             }
         }
@@ -201,8 +330,8 @@ impl<'s, S: SubstateStore> Track<'s, S> {
         }
     }
 
-    /// Inserts a new package.
-    pub fn create_package(&mut self, package: Package) -> PackageAddress {
+    /// Inserts a new package, along with its first version.
+    pub fn create_package(&mut self, package: Package, version: PackageVersion) -> PackageAddress {
         let package_address = self.new_package_address();
         self.packages.insert(
             package_address,
@@ -211,16 +340,105 @@ impl<'s, S: SubstateStore> Track<'s, S> {
                 value: package,
             },
         );
+        self.create_package_version(package_address, 1, version);
         package_address
     }
 
+    /// Returns an immutable reference to a package version, if exists.
+    pub fn get_package_version(
+        &mut self,
+        package_address: PackageAddress,
+        version: u32,
+    ) -> Option<&PackageVersion> {
+        let canonical_id = (package_address, version);
+        if self.package_versions.contains_key(&canonical_id) {
+            return self.package_versions.get(&canonical_id).map(|v| &v.value);
+        }
+
+        if let Some((package_version, phys_id)) = self
+            .substate_store
+            .get_decoded_child_substate(&package_address, &version)
+        {
+            self.package_versions.insert(
+                canonical_id,
+                SubstateUpdate {
+                    prev_id: Some(phys_id),
+                    value: package_version,
+                },
+            );
+            self.package_versions.get(&canonical_id).map(|v| &v.value)
+        } else {
+            None
+        }
+    }
+
+    /// Inserts a new package version.
+    pub fn create_package_version(
+        &mut self,
+        package_address: PackageAddress,
+        version: u32,
+        package_version: PackageVersion,
+    ) {
+        self.package_versions.insert(
+            (package_address, version),
+            SubstateUpdate {
+                prev_id: None,
+                value: package_version,
+            },
+        );
+    }
+
+    pub fn borrow_global_mut_package(
+        &mut self,
+        package_address: PackageAddress,
+    ) -> Result<Package, RuntimeError> {
+        let maybe_package = self.packages.remove(&package_address);
+        if self.borrowed_packages.contains_key(&package_address) {
+            panic!("Invalid package reentrancy");
+        } else if let Some(SubstateUpdate { value, prev_id }) = maybe_package {
+            self.borrowed_packages.insert(package_address, prev_id);
+            Ok(value)
+        } else if let Some((package, phys_id)) =
+            self.substate_store.get_decoded_substate(&package_address)
+        {
+            self.borrowed_packages
+                .insert(package_address, Some(phys_id));
+            Ok(package)
+        } else {
+            Err(RuntimeError::PackageNotFound(package_address))
+        }
+    }
+
+    pub fn return_borrowed_global_package(
+        &mut self,
+        package_address: PackageAddress,
+        package: Package,
+    ) {
+        if let Some(prev_id) = self.borrowed_packages.remove(&package_address) {
+            self.packages.insert(
+                package_address,
+                SubstateUpdate {
+                    prev_id,
+                    value: package,
+                },
+            );
+        } else {
+            panic!("Package was never borrowed");
+        }
+    }
+
     pub fn borrow_global_mut_component(
         &mut self,
         component_address: ComponentAddress,
     ) -> Result<Component, RuntimeError> {
+        if self.deleted_components.contains_key(&component_address) {
+            return Err(RuntimeError::ComponentNotFound(component_address));
+        }
+
         let maybe_component = self.components.remove(&component_address);
         if let Some(SubstateUpdate { value, prev_id }) = maybe_component {
-            self.borrowed_components.insert(component_address, prev_id);
+            self.borrowed_components
+                .insert(component_address, (prev_id, true));
             Ok(value)
         } else if self.borrowed_components.contains_key(&component_address) {
             Err(RuntimeError::ComponentReentrancy(component_address))
@@ -228,19 +446,84 @@ impl<'s, S: SubstateStore> Track<'s, S> {
             self.substate_store.get_decoded_substate(&component_address)
         {
             self.borrowed_components
-                .insert(component_address, Some(phys_id));
+                .insert(component_address, (Some(phys_id), false));
             Ok(component)
         } else {
             Err(RuntimeError::ComponentNotFound(component_address))
         }
     }
 
+    /// Borrows a component that's already borrowed, for a nested read-only call.
+    ///
+    /// The component is seen as it was last updated by the frame borrowing it.
+    pub fn borrow_shared_global_component(
+        &mut self,
+        component_address: ComponentAddress,
+    ) -> Result<Component, RuntimeError> {
+        if self.deleted_components.contains_key(&component_address) {
+            return Err(RuntimeError::ComponentNotFound(component_address));
+        }
+
+        if let Some(component) = self.borrowed_component_views.get(&component_address) {
+            *self
+                .shared_borrowed_components
+                .entry(component_address)
+                .or_default() += 1;
+            Ok(component.clone())
+        } else {
+            Err(RuntimeError::ComponentReentrancy(component_address))
+        }
+    }
+
+    /// Updates the view of a borrowed component, unless the frame running it is a nested
+    /// read-only one.
+    pub fn update_borrowed_component_view(
+        &mut self,
+        component_address: ComponentAddress,
+        component: &Component,
+    ) {
+        if self.borrowed_components.contains_key(&component_address)
+            && !self.is_shared_borrowed_component(component_address)
+        {
+            self.borrowed_component_views
+                .insert(component_address, component.clone());
+        }
+    }
+
+    /// Returns whether a component is borrowed by a nested read-only call.
+    pub fn is_shared_borrowed_component(&self, component_address: ComponentAddress) -> bool {
+        self.shared_borrowed_components
+            .contains_key(&component_address)
+    }
+
+    /// Returns a borrowed component, which is only written back if it's been modified
+    /// now or before.
+    ///
+    /// Nested read-only borrows are always returned before the borrow they're nested in,
+    /// and leave the component untouched.
     pub fn return_borrowed_global_component(
         &mut self,
         component_address: ComponentAddress,
         component: Component,
+        modified: bool,
     ) {
-        if let Some(prev_id) = self.borrowed_components.remove(&component_address) {
+        if let Some(count) = self.shared_borrowed_components.get_mut(&component_address) {
+            *count -= 1;
+            if *count == 0 {
+                self.shared_borrowed_components.remove(&component_address);
+            }
+            return;
+        }
+
+        if let Some((prev_id, updated)) = self.borrowed_components.remove(&component_address) {
+            self.borrowed_component_views.remove(&component_address);
+            // A component deleted while borrowed is dropped
+            if self.deleted_components.contains_key(&component_address) {
+                return;
+            }
+            if !modified && !updated {
+                return;
+            }
             self.components.insert(
                 component_address,
                 SubstateUpdate {
@@ -255,6 +538,10 @@ impl<'s, S: SubstateStore> Track<'s, S> {
 
     /// Returns an immutable reference to a component, if exists.
     pub fn get_component(&mut self, component_address: ComponentAddress) -> Option<&Component> {
+        if self.deleted_components.contains_key(&component_address) {
+            return None;
+        }
+
         if self.components.contains_key(&component_address) {
             return self.components.get(&component_address).map(|c| &c.value);
         }
@@ -275,6 +562,22 @@ impl<'s, S: SubstateStore> Track<'s, S> {
         }
     }
 
+    /// Deletes a component, which is dropped when returned if it's currently borrowed.
+    ///
+    /// Its child substates must be deleted separately.
+    pub fn delete_component(&mut self, component_address: ComponentAddress) {
+        let prev_id = if let Some(component) = self.components.remove(&component_address) {
+            component.prev_id
+        } else if let Some((prev_id, _)) = self.borrowed_components.get(&component_address) {
+            prev_id.clone()
+        } else {
+            self.substate_store
+                .get_substate(&component_address)
+                .map(|s| s.phys_id)
+        };
+        self.deleted_components.insert(component_address, prev_id);
+    }
+
     /// Inserts a new component.
     pub fn create_component(&mut self, component: Component) -> ComponentAddress {
         let component_address = self.new_component_address();
@@ -351,13 +654,8 @@ impl<'s, S: SubstateStore> Track<'s, S> {
     ) -> Option<Vec<u8>> {
         let canonical_id = (component_address.clone(), lazy_map_id.clone(), key.to_vec());
 
-        if self.lazy_map_entries.contains_key(&canonical_id) {
-            return Some(
-                self.lazy_map_entries
-                    .get(&canonical_id)
-                    .map(|r| r.value.clone())
-                    .unwrap(),
-            );
+        if let Some(entry) = self.lazy_map_entries.get(&canonical_id) {
+            return entry.value.clone();
         }
 
         let grand_child_key = key.to_vec();
@@ -371,19 +669,64 @@ impl<'s, S: SubstateStore> Track<'s, S> {
                 canonical_id,
                 SubstateUpdate {
                     prev_id: Some(phys_id),
-                    value: entry_bytes.clone(),
+                    value: Some(entry_bytes.clone()),
                 },
             );
         }
         value.map(|r| r.0)
     }
 
+    /// Returns all entries of a lazy map, in the order of the encoded keys.
+    pub fn get_lazy_map_entries(
+        &mut self,
+        component_address: ComponentAddress,
+        lazy_map_id: &LazyMapId,
+    ) -> Vec<(Vec<u8>, Vec<u8>)> {
+        let mut entries: BTreeMap<Vec<u8>, Vec<u8>> = self
+            .substate_store
+            .get_grand_child_substates(&component_address, lazy_map_id)
+            .into_iter()
+            .map(|(key, substate)| (key, substate.value))
+            .collect();
+
+        for ((address, id, key), entry) in self.lazy_map_entries.iter() {
+            if *address == component_address && id == lazy_map_id {
+                match &entry.value {
+                    Some(value) => entries.insert(key.clone(), value.clone()),
+                    None => entries.remove(key),
+                };
+            }
+        }
+
+        entries.into_iter().collect()
+    }
+
     pub fn put_lazy_map_entry(
         &mut self,
         component_address: ComponentAddress,
         lazy_map_id: LazyMapId,
         key: Vec<u8>,
         value: Vec<u8>,
+    ) {
+        self.update_lazy_map_entry(component_address, lazy_map_id, key, Some(value));
+    }
+
+    /// Removes a lazy map entry, leaving a tombstone which is dropped from the ledger on commit.
+    pub fn remove_lazy_map_entry(
+        &mut self,
+        component_address: ComponentAddress,
+        lazy_map_id: LazyMapId,
+        key: Vec<u8>,
+    ) {
+        self.update_lazy_map_entry(component_address, lazy_map_id, key, None);
+    }
+
+    fn update_lazy_map_entry(
+        &mut self,
+        component_address: ComponentAddress,
+        lazy_map_id: LazyMapId,
+        key: Vec<u8>,
+        value: Option<Vec<u8>>,
     ) {
         let canonical_id = (component_address.clone(), lazy_map_id.clone(), key.clone());
 
@@ -508,7 +851,11 @@ impl<'s, S: SubstateStore> Track<'s, S> {
         resource_address
     }
 
-    pub fn borrow_vault_mut(&mut self, component_address: &ComponentAddress, vid: &VaultId) -> Vault {
+    pub fn borrow_vault_mut(
+        &mut self,
+        component_address: &ComponentAddress,
+        vid: &VaultId,
+    ) -> Vault {
         let canonical_id = (component_address.clone(), vid.clone());
         if self.borrowed_vaults.contains_key(&canonical_id) {
             panic!("Invalid vault reentrancy");
@@ -519,9 +866,11 @@ impl<'s, S: SubstateStore> Track<'s, S> {
             return value;
         }
 
-        if let Some((vault, phys_id)) = self.substate_store.get_decoded_child_substate(component_address, vid) {
-            self.borrowed_vaults
-                .insert(canonical_id, Some(phys_id));
+        if let Some((vault, phys_id)) = self
+            .substate_store
+            .get_decoded_child_substate(component_address, vid)
+        {
+            self.borrowed_vaults.insert(canonical_id, Some(phys_id));
             return vault;
         }
 
@@ -548,6 +897,32 @@ impl<'s, S: SubstateStore> Track<'s, S> {
         }
     }
 
+    /// Deletes a vault, which must not be currently borrowed.
+    pub fn delete_vault(&mut self, component_address: ComponentAddress, vault_id: VaultId) {
+        let canonical_id = (component_address, vault_id);
+        if self.borrowed_vaults.contains_key(&canonical_id) {
+            panic!("Borrowed vault should not be deleted");
+        }
+
+        let prev_id = if let Some(vault) = self.vaults.remove(&canonical_id) {
+            vault.prev_id
+        } else {
+            self.substate_store
+                .get_child_substate(&component_address, &scrypto_encode(&vault_id))
+                .map(|s| s.phys_id)
+        };
+        self.deleted_vaults.insert(canonical_id, prev_id);
+    }
+
+    /// Removes a vault, which must not be currently borrowed, and returns it.
+    pub fn take_vault(&mut self, component_address: ComponentAddress, vault_id: VaultId) -> Vault {
+        let vault = self.borrow_vault_mut(&component_address, &vault_id);
+        let canonical_id = (component_address, vault_id);
+        let prev_id = self.borrowed_vaults.remove(&canonical_id).unwrap();
+        self.deleted_vaults.insert(canonical_id, prev_id);
+        vault
+    }
+
     /// Inserts a new vault.
     pub fn put_vault(
         &mut self,
@@ -556,10 +931,12 @@ impl<'s, S: SubstateStore> Track<'s, S> {
         vault: Vault,
     ) {
         let canonical_id = (component_address, vault_id);
+        // A vault taken out earlier may be put back, replacing its old substate
+        let prev_id = self.deleted_vaults.remove(&canonical_id).flatten();
         self.vaults.insert(
             canonical_id,
             SubstateUpdate {
-                prev_id: None,
+                prev_id,
                 value: vault,
             },
         );
@@ -623,10 +1000,12 @@ impl<'s, S: SubstateStore> Track<'s, S> {
     }
 
     /// Commits changes to the underlying ledger.
-    /// Currently none of these objects are deleted so all commits are puts
     pub fn commit(&mut self) -> CommitReceipt {
         // Sanity check
-        if !self.borrowed_components.is_empty() {
+        if !self.borrowed_packages.is_empty() {
+            panic!("Borrowed packages should be empty by end of transaction.");
+        }
+        if !self.borrowed_components.is_empty() || !self.shared_borrowed_components.is_empty() {
             panic!("Borrowed components should be empty by end of transaction.");
         }
         if !self.borrowed_resource_managers.is_empty() {
@@ -653,6 +1032,28 @@ impl<'s, S: SubstateStore> Track<'s, S> {
                 .put_encoded_substate(&package_address, &package.value, phys_id);
         }
 
+        // Package versions are immutable, so only new ones are written
+        let mut package_version_ids: Vec<(PackageAddress, u32)> = self
+            .package_versions
+            .iter()
+            .filter(|(_, update)| update.prev_id.is_none())
+            .map(|(id, _)| id.clone())
+            .collect();
+        package_version_ids.sort_by_cached_key(scrypto_encode);
+        for package_version_id in package_version_ids {
+            let package_version = self.package_versions.remove(&package_version_id).unwrap();
+            let phys_id = id_gen.next();
+            receipt.up(phys_id);
+
+            let (package_address, version) = package_version_id;
+            self.substate_store.put_encoded_child_substate(
+                &package_address,
+                &version,
+                &package_version.value,
+                phys_id,
+            );
+        }
+
         let component_addresses: Vec<ComponentAddress> = self.components.keys().cloned().collect();
         for component_address in component_addresses {
             let component = self.components.remove(&component_address).unwrap();
@@ -667,51 +1068,74 @@ impl<'s, S: SubstateStore> Track<'s, S> {
                 .put_encoded_substate(&component_address, &component.value, phys_id);
         }
 
+        let mut deleted_component_addresses: Vec<ComponentAddress> =
+            self.deleted_components.keys().cloned().collect();
+        deleted_component_addresses.sort_by_cached_key(scrypto_encode);
+        for component_address in deleted_component_addresses {
+            if let Some(prev_id) = self.deleted_components.remove(&component_address).unwrap() {
+                receipt.down(prev_id);
+                self.substate_store.delete_substate(&component_address);
+            }
+        }
+
         let resource_addresses: Vec<ResourceAddress> =
             self.resource_managers.keys().cloned().collect();
         for resource_address in resource_addresses {
             let resource_manager = self.resource_managers.remove(&resource_address).unwrap();
-            
+
             // Unshrouds shrouded resources
             let mut resource_info = resource_manager.value;
             if resource_info.is_shrouded() {
                 resource_info.unshroud();
             }
-        
+
             if let Some(prev_id) = resource_manager.prev_id {
                 receipt.down(prev_id);
             }
             let phys_id = id_gen.next();
             receipt.up(phys_id);
 
-            self.substate_store.put_encoded_substate(
-                &resource_address,
-                &resource_info,
-                phys_id,
-            );
+            self.substate_store
+                .put_encoded_substate(&resource_address, &resource_info, phys_id);
         }
 
-        let entry_ids: Vec<(ComponentAddress, LazyMapId, Vec<u8>)> =
+        // Hash-based updates are committed in the order of their encoded ids, so that
+        // physical ids are deterministic.
+        let mut entry_ids: Vec<(ComponentAddress, LazyMapId, Vec<u8>)> =
             self.lazy_map_entries.keys().cloned().collect();
+        entry_ids.sort_by_cached_key(scrypto_encode);
         for entry_id in entry_ids {
             let entry = self.lazy_map_entries.remove(&entry_id).unwrap();
             if let Some(prev_id) = entry.prev_id {
                 receipt.down(prev_id);
             }
-            let phys_id = id_gen.next();
-            receipt.up(phys_id);
 
             let (component_address, lazy_map_id, key) = entry_id;
-            self.substate_store.put_encoded_grand_child_substate(
-                &component_address,
-                &lazy_map_id,
-                &key,
-                &entry.value,
-                phys_id,
-            );
+            match entry.value {
+                Some(value) => {
+                    let phys_id = id_gen.next();
+                    receipt.up(phys_id);
+
+                    self.substate_store.put_encoded_grand_child_substate(
+                        &component_address,
+                        &lazy_map_id,
+                        &key,
+                        &value,
+                        phys_id,
+                    );
+                }
+                None => {
+                    self.substate_store.delete_grand_child_substate(
+                        &component_address,
+                        &lazy_map_id,
+                        &key,
+                    );
+                }
+            }
         }
 
-        let vault_ids: Vec<(ComponentAddress, VaultId)> = self.vaults.keys().cloned().collect();
+        let mut vault_ids: Vec<(ComponentAddress, VaultId)> = self.vaults.keys().cloned().collect();
+        vault_ids.sort_by_cached_key(scrypto_encode);
         for vault_id in vault_ids {
             let vault = self.vaults.remove(&vault_id).unwrap();
             if let Some(prev_id) = vault.prev_id {
@@ -729,8 +1153,21 @@ impl<'s, S: SubstateStore> Track<'s, S> {
             );
         }
 
-        let non_fungible_addresses: Vec<NonFungibleAddress> =
+        let mut deleted_vault_ids: Vec<(ComponentAddress, VaultId)> =
+            self.deleted_vaults.keys().cloned().collect();
+        deleted_vault_ids.sort_by_cached_key(scrypto_encode);
+        for vault_id in deleted_vault_ids {
+            if let Some(prev_id) = self.deleted_vaults.remove(&vault_id).unwrap() {
+                receipt.down(prev_id);
+                let (component_address, vault_id) = vault_id;
+                self.substate_store
+                    .delete_child_substate(&component_address, &scrypto_encode(&vault_id));
+            }
+        }
+
+        let mut non_fungible_addresses: Vec<NonFungibleAddress> =
             self.non_fungibles.keys().cloned().collect();
+        non_fungible_addresses.sort_by_cached_key(scrypto_encode);
         for non_fungible_address in non_fungible_addresses {
             let non_fungible = self.non_fungibles.remove(&non_fungible_address).unwrap();
             if let Some(prev_id) = non_fungible.prev_id {
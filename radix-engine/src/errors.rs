@@ -1,4 +1,5 @@
 use sbor::*;
+use scrypto::core::ScryptoActor;
 use scrypto::engine::types::*;
 use scrypto::rust::fmt;
 use scrypto::rust::string::String;
@@ -49,6 +50,21 @@ impl From<wasmi::Error> for WasmiError {
     }
 }
 
+/// The kind of trap which stopped WASM execution.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum WasmTrapKind {
+    Unreachable,
+    MemoryAccessOutOfBounds,
+    TableAccessOutOfBounds,
+    ElemUninitialized,
+    DivisionByZero,
+    InvalidConversionToInt,
+    StackOverflow,
+    UnexpectedSignature,
+    /// A host function failed.
+    Host,
+}
+
 /// Represents an error when validating a WASM file.
 #[derive(Debug, PartialEq, Clone)]
 pub enum WasmValidationError {
@@ -73,6 +89,7 @@ pub enum TransactionValidationError {
     IdValidatorError(IdValidatorError),
     VaultNotAllowed(VaultId),
     LazyMapNotAllowed(LazyMapId),
+    OwnedComponentNotAllowed(ComponentAddress),
     InvalidSignature,
 }
 
@@ -81,7 +98,8 @@ pub enum ResourceFailure {
     Resource(ResourceAddress),
     Resources(Vec<ResourceAddress>),
     UnclaimedLazyMap,
-    Unknown
+    UnclaimedComponent,
+    Unknown,
 }
 
 /// Represents an error when executing a transaction.
@@ -105,6 +123,30 @@ pub enum RuntimeError {
     /// Error when invoking an export.
     InvokeError,
 
+    /// A blueprint aborted the transaction with an error of its own.
+    ApplicationError {
+        actor: ScryptoActor,
+        error: ScryptoValue,
+    },
+
+    /// The blueprint code trapped.
+    ///
+    /// The call frames are the blueprint calls that were running, innermost first, each named
+    /// by the symbol of its exported function. Frames within a call are not recorded, as the
+    /// interpreter does not expose them.
+    WasmTrap {
+        actor: ScryptoActor,
+        kind: WasmTrapKind,
+        call_frames: Vec<String>,
+    },
+
+    /// A blueprint panicked.
+    Panic {
+        actor: ScryptoActor,
+        message: String,
+        location: String,
+    },
+
     /// Error when accessing the program memory.
     MemoryAccessError,
 
@@ -142,9 +184,42 @@ pub enum RuntimeError {
     /// Component does not exist.
     ComponentNotFound(ComponentAddress),
 
+    /// Method does not exist in the blueprint ABI of the component.
+    MethodNotFound(ComponentAddress, String),
+
     /// Component is already loaded
     ComponentAlreadyLoaded(ComponentAddress),
 
+    /// Component state does not match the blueprint schema.
+    InvalidComponentState(PackageAddress, String),
+
+    /// Owned component removed.
+    ComponentRemoved(ComponentAddress),
+
+    /// Duplicate owned component added
+    DuplicateComponent(ComponentAddress),
+
+    /// Owned component invoked by a component which does not own it.
+    OwnedComponentNotAccessible(ComponentAddress),
+
+    /// Owned component can only be deleted along with its owner.
+    OwnedComponentNotDeletable(ComponentAddress),
+
+    /// Component already runs the latest version of its package.
+    ComponentAlreadyMigrated(ComponentAddress),
+
+    /// Component has been migrated by the running method, and its old state can't be put.
+    ComponentMigrated(ComponentAddress),
+
+    /// Method declared `&self` attempted to write component state.
+    ImmutableMethodWrite(ComponentAddress, String),
+
+    /// Only methods declared `&self` can be called by a query.
+    MethodNotReadOnly(ComponentAddress, String),
+
+    /// Migrated state does not keep exactly the vaults, lazy maps and owned components of the old state.
+    InvalidMigratedState(ComponentAddress),
+
     /// Resource manager does not exist.
     ResourceManagerNotFound(ResourceAddress),
 
@@ -175,6 +250,9 @@ pub enum RuntimeError {
     /// Duplicate Vault added
     DuplicateVault(VaultId),
 
+    /// Vault to be deleted still holds resources.
+    VaultNotEmpty(VaultId),
+
     /// Bucket does not exist.
     BucketNotFound(BucketId),
 
@@ -184,6 +262,9 @@ pub enum RuntimeError {
     /// The bucket contains no resource.
     EmptyProof,
 
+    /// Component access error.
+    ComponentError(ComponentError),
+
     /// Resource manager access error.
     ResourceManagerError(ResourceManagerError),
 
@@ -211,6 +292,9 @@ pub enum RuntimeError {
     /// Lazy Map is not allowed
     LazyMapNotAllowed,
 
+    /// Owned component is not allowed
+    OwnedComponentNotAllowed,
+
     /// Interpreter is not started.
     InterpreterNotStarted,
 
@@ -241,7 +325,6 @@ pub enum RuntimeError {
 
     /// Can't move restricted proof.
     CantMoveRestrictedProof(ProofId),
-
 }
 
 impl fmt::Display for RuntimeError {
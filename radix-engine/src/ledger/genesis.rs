@@ -0,0 +1,330 @@
+use sbor::*;
+use scrypto::buffer::*;
+use scrypto::constants::*;
+use scrypto::core::{SNodeRef, ScryptoActor};
+use scrypto::crypto::*;
+use scrypto::engine::types::*;
+use scrypto::prelude::LOCKED;
+use scrypto::resource::AccessRule;
+use scrypto::resource::ResourceMethod::Withdraw;
+use scrypto::rule;
+use scrypto::rust::borrow::ToOwned;
+use scrypto::rust::collections::*;
+use scrypto::rust::string::String;
+use scrypto::rust::vec;
+use scrypto::rust::vec::Vec;
+use scrypto::values::ScryptoValue;
+
+use crate::engine::*;
+use crate::errors::*;
+use crate::ledger::*;
+use crate::model::*;
+
+const XRD_SYMBOL: &str = "XRD";
+const XRD_NAME: &str = "Radix";
+const XRD_DESCRIPTION: &str = "The Radix Public Network's native token, used to pay the network's required transaction fees and to secure the network through staking to its validator nodes.";
+const XRD_URL: &str = "https://tokens.radixdlt.com";
+const XRD_MAX_SUPPLY: i128 = 24_000_000_000i128;
+const XRD_VAULT_ID: VaultId = (Hash([0u8; 32]), 0);
+const XRD_VAULT: scrypto::resource::Vault = scrypto::resource::Vault(XRD_VAULT_ID);
+
+const SYSTEM_COMPONENT_NAME: &str = "System";
+const ACCOUNT_BLUEPRINT_NAME: &str = "Account";
+
+#[derive(TypeId, Encode, Decode)]
+struct SystemComponentState {
+    xrd: scrypto::resource::Vault,
+}
+
+/// Describes the initial state of a ledger.
+#[derive(Debug, Clone, TypeId, Encode, Decode)]
+pub struct GenesisConfig {
+    /// The starting epoch.
+    pub epoch: u64,
+    /// The metadata of the XRD resource.
+    pub xrd_metadata: HashMap<String, String>,
+    /// XRD allocated to the accounts of the given public keys; the rest of the supply is
+    /// kept by the system component.
+    pub xrd_allocations: Vec<(EcdsaPublicKey, Decimal)>,
+    /// Packages to preload, in addition to the system and account packages.
+    pub packages: Vec<PackageVersion>,
+    /// Fungible resources to create, in addition to XRD.
+    pub resources: Vec<GenesisResource>,
+}
+
+/// A fungible resource created at genesis, with its whole supply allocated to accounts.
+#[derive(Debug, Clone, TypeId, Encode, Decode)]
+pub struct GenesisResource {
+    pub divisibility: u8,
+    pub metadata: HashMap<String, String>,
+    pub allocations: Vec<(EcdsaPublicKey, Decimal)>,
+}
+
+/// The entities created at genesis, other than the well-known ones.
+#[derive(Debug, Clone)]
+pub struct GenesisReceipt {
+    pub package_addresses: Vec<PackageAddress>,
+    pub resource_addresses: Vec<ResourceAddress>,
+    pub account_addresses: Vec<(EcdsaPublicKey, ComponentAddress)>,
+}
+
+/// Represents an error when applying a genesis config.
+#[derive(Debug, Clone, PartialEq)]
+pub enum GenesisError {
+    /// The XRD allocations exceed the max supply.
+    XrdAllocationsExceedSupply,
+    ResourceManagerError(ResourceManagerError),
+    ResourceContainerError(ResourceContainerError),
+    /// Failed to instantiate the genesis accounts.
+    RuntimeError(RuntimeError),
+}
+
+impl GenesisConfig {
+    /// Returns the XRD metadata of the Radix Public Network.
+    pub fn default_xrd_metadata() -> HashMap<String, String> {
+        let mut metadata = HashMap::new();
+        metadata.insert("symbol".to_owned(), XRD_SYMBOL.to_owned());
+        metadata.insert("name".to_owned(), XRD_NAME.to_owned());
+        metadata.insert("description".to_owned(), XRD_DESCRIPTION.to_owned());
+        metadata.insert("url".to_owned(), XRD_URL.to_owned());
+        metadata
+    }
+}
+
+impl Default for GenesisConfig {
+    fn default() -> Self {
+        Self {
+            epoch: 0,
+            xrd_metadata: Self::default_xrd_metadata(),
+            xrd_allocations: Vec::new(),
+            packages: Vec::new(),
+            resources: Vec::new(),
+        }
+    }
+}
+
+/// Returns the index of the account of the given public key, adding one if missing.
+fn account_index(
+    accounts: &mut Vec<(EcdsaPublicKey, Vec<ResourceContainer>)>,
+    public_key: &EcdsaPublicKey,
+) -> usize {
+    if let Some(index) = accounts.iter().position(|(pk, _)| pk == public_key) {
+        index
+    } else {
+        accounts.push((*public_key, Vec::new()));
+        accounts.len() - 1
+    }
+}
+
+fn deposit(
+    containers: &mut Vec<ResourceContainer>,
+    container: ResourceContainer,
+) -> Result<(), GenesisError> {
+    if let Some(existing) = containers
+        .iter_mut()
+        .find(|c| c.resource_address() == container.resource_address())
+    {
+        existing
+            .put(container)
+            .map_err(GenesisError::ResourceContainerError)
+    } else {
+        containers.push(container);
+        Ok(())
+    }
+}
+
+/// Writes a package which can't be upgraded, with the given code as its first version.
+pub(crate) fn put_package<S: SubstateStore + ?Sized>(
+    substate_store: &mut S,
+    package_address: &PackageAddress,
+    package_version: &PackageVersion,
+    id_gen: &mut SubstateIdGenerator,
+) {
+    let package = Package::new(AccessRule::DenyAll);
+    substate_store.put_encoded_substate(package_address, &package, id_gen.next());
+    substate_store.put_encoded_child_substate(
+        package_address,
+        &package.latest_version(),
+        package_version,
+        id_gen.next(),
+    );
+}
+
+/// Writes the genesis state into an empty substate store.
+pub(crate) fn apply_genesis<S: SubstateStore>(
+    substate_store: &mut S,
+    genesis: &GenesisConfig,
+) -> Result<GenesisReceipt, GenesisError> {
+    let tx_hash = hash(substate_store.get_and_increase_nonce().to_le_bytes());
+    let mut id_gen = SubstateIdGenerator::new(tx_hash);
+    let mut id_allocator = IdAllocator::new(IdSpace::Application);
+    let mut receipt = GenesisReceipt {
+        package_addresses: Vec::new(),
+        resource_addresses: Vec::new(),
+        account_addresses: Vec::new(),
+    };
+
+    // System package
+    let system_package = PackageVersion::new(
+        include_bytes!("../../../assets/system.wasm").to_vec(),
+        HashMap::new(),
+    )
+    .unwrap();
+    put_package(
+        substate_store,
+        &SYSTEM_PACKAGE,
+        &system_package,
+        &mut id_gen,
+    );
+
+    // Account package
+    let account_package = PackageVersion::new(
+        include_bytes!("../../../assets/account.wasm").to_vec(),
+        HashMap::new(),
+    )
+    .unwrap();
+    put_package(
+        substate_store,
+        &ACCOUNT_PACKAGE,
+        &account_package,
+        &mut id_gen,
+    );
+
+    // Extra packages
+    for package in &genesis.packages {
+        let package_address = id_allocator.new_package_address(tx_hash).unwrap();
+        put_package(substate_store, &package_address, package, &mut id_gen);
+        receipt.package_addresses.push(package_address);
+    }
+
+    // Radix token resource address
+    let mut resource_auth = HashMap::new();
+    resource_auth.insert(Withdraw, (rule!(allow_all), LOCKED));
+
+    let mut xrd = ResourceManager::new(
+        ResourceType::Fungible { divisibility: 18 },
+        genesis.xrd_metadata.clone(),
+        resource_auth,
+    )
+    .unwrap();
+    substate_store.put_encoded_substate(&RADIX_TOKEN, &xrd, id_gen.next());
+    let mut minted_xrd = xrd
+        .mint_fungible(XRD_MAX_SUPPLY.into(), RADIX_TOKEN.clone())
+        .unwrap();
+
+    let mut ecdsa_resource_auth = HashMap::new();
+    ecdsa_resource_auth.insert(Withdraw, (rule!(allow_all), LOCKED));
+    let ecdsa_token = ResourceManager::new(
+        ResourceType::NonFungible,
+        HashMap::new(),
+        ecdsa_resource_auth,
+    )
+    .unwrap();
+    substate_store.put_encoded_substate(&ECDSA_TOKEN, &ecdsa_token, id_gen.next());
+
+    // Virtual badges of callers, which are never held in vaults
+    for caller_token in [PACKAGE_TOKEN, BLUEPRINT_TOKEN, COMPONENT_TOKEN] {
+        let caller_token_manager =
+            ResourceManager::new(ResourceType::NonFungible, HashMap::new(), HashMap::new())
+                .unwrap();
+        substate_store.put_encoded_substate(&caller_token, &caller_token_manager, id_gen.next());
+    }
+
+    // Allocations, grouped by account
+    let mut accounts: Vec<(EcdsaPublicKey, Vec<ResourceContainer>)> = Vec::new();
+    for (public_key, amount) in &genesis.xrd_allocations {
+        let container = minted_xrd.take_by_amount(*amount).map_err(|e| match e {
+            ResourceContainerError::InsufficientBalance => GenesisError::XrdAllocationsExceedSupply,
+            e => GenesisError::ResourceContainerError(e),
+        })?;
+        let index = account_index(&mut accounts, public_key);
+        deposit(&mut accounts[index].1, container)?;
+    }
+
+    // Extra resources
+    for resource in &genesis.resources {
+        let resource_address = id_allocator.new_resource_address(tx_hash).unwrap();
+        let mut resource_auth = HashMap::new();
+        resource_auth.insert(Withdraw, (rule!(allow_all), LOCKED));
+        let mut resource_manager = ResourceManager::new(
+            ResourceType::Fungible {
+                divisibility: resource.divisibility,
+            },
+            resource.metadata.clone(),
+            resource_auth,
+        )
+        .map_err(GenesisError::ResourceManagerError)?;
+
+        for (public_key, amount) in &resource.allocations {
+            let container = resource_manager
+                .mint_fungible(*amount, resource_address)
+                .map_err(GenesisError::ResourceManagerError)?;
+            let index = account_index(&mut accounts, public_key);
+            deposit(&mut accounts[index].1, container)?;
+        }
+
+        substate_store.put_encoded_substate(&resource_address, &resource_manager, id_gen.next());
+        receipt.resource_addresses.push(resource_address);
+    }
+
+    // Instantiate system component, with the remaining XRD
+    let system_vault = Vault::new(minted_xrd);
+    substate_store.put_encoded_child_substate(
+        &SYSTEM_COMPONENT,
+        &XRD_VAULT_ID,
+        &system_vault,
+        id_gen.next(),
+    );
+
+    let system_component = Component::new(
+        SYSTEM_PACKAGE,
+        1,
+        SYSTEM_COMPONENT_NAME.to_owned(),
+        vec![],
+        scrypto_encode(&SystemComponentState { xrd: XRD_VAULT }),
+    );
+    substate_store.put_encoded_substate(&SYSTEM_COMPONENT, &system_component, id_gen.next());
+
+    substate_store.set_epoch(genesis.epoch);
+
+    // Instantiate accounts through the account blueprint, in a transaction of their own
+    let tx_hash = hash(substate_store.get_and_increase_nonce().to_le_bytes());
+    let mut track = Track::new(substate_store, tx_hash, Vec::new());
+    for (public_key, containers) in accounts {
+        let mut buckets = HashMap::new();
+        let mut bucket_refs = Vec::new();
+        for container in containers {
+            let bucket_id = track.new_bucket_id();
+            buckets.insert(bucket_id, Bucket::new(container));
+            bucket_refs.push(scrypto::resource::Bucket(bucket_id));
+        }
+
+        let auth_address =
+            NonFungibleAddress::new(ECDSA_TOKEN, NonFungibleId::from_bytes(public_key.to_vec()));
+        let withdraw_rule = rule!(require(auth_address));
+        let mut proc = Process::new(0, false, &mut track, None, None, buckets, HashMap::new());
+        let output = proc
+            .invoke_snode(
+                SNodeRef::Scrypto(ScryptoActor::Blueprint(
+                    ACCOUNT_PACKAGE,
+                    ACCOUNT_BLUEPRINT_NAME.to_owned(),
+                )),
+                "new".to_owned(),
+                vec![ScryptoValue::from_value(&withdraw_rule)],
+            )
+            .map_err(GenesisError::RuntimeError)?;
+        let component_address: ComponentAddress = scrypto_decode(&output.raw).unwrap();
+        proc.invoke_snode(
+            SNodeRef::Scrypto(ScryptoActor::Component(component_address)),
+            "deposit_batch".to_owned(),
+            vec![ScryptoValue::from_value(&bucket_refs)],
+        )
+        .map_err(GenesisError::RuntimeError)?;
+        receipt
+            .account_addresses
+            .push((public_key, component_address));
+    }
+    track.commit();
+
+    Ok(receipt)
+}
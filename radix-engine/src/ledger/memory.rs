@@ -1,4 +1,4 @@
-use sbor::Encode;
+use sbor::{DecodeError, Encode};
 use scrypto::buffer::scrypto_encode;
 use scrypto::rust::collections::HashMap;
 use scrypto::rust::vec::Vec;
@@ -10,7 +10,7 @@ use crate::ledger::*;
 #[derive(Debug, Clone)]
 pub struct InMemorySubstateStore {
     substates: HashMap<Vec<u8>, Substate>,
-    child_substates: HashMap<Vec<u8>, Substate>,
+    child_substates: HashMap<(Vec<u8>, Vec<u8>), Substate>,
     current_epoch: u64,
     nonce: u64,
 }
@@ -47,18 +47,72 @@ impl SubstateStore for InMemorySubstateStore {
         self.substates.insert(scrypto_encode(address), substate);
     }
 
+    fn delete_substate<T: Encode>(&mut self, address: &T) {
+        self.substates.remove(&scrypto_encode(address));
+    }
+
     fn get_child_substate<T: Encode>(&self, address: &T, key: &[u8]) -> Option<Substate> {
-        let mut id = scrypto_encode(address);
-        id.extend(key.to_vec());
+        let id = (scrypto_encode(address), key.to_vec());
         self.child_substates.get(&id).cloned()
     }
 
     fn put_child_substate<T: Encode>(&mut self, address: &T, key: &[u8], substate: Substate) {
-        let mut id = scrypto_encode(address);
-        id.extend(key.to_vec());
+        let id = (scrypto_encode(address), key.to_vec());
         self.child_substates.insert(id, substate);
     }
 
+    fn delete_child_substate<T: Encode>(&mut self, address: &T, key: &[u8]) {
+        let id = (scrypto_encode(address), key.to_vec());
+        self.child_substates.remove(&id);
+    }
+
+    fn get_child_substates_by_prefix<T: Encode>(
+        &self,
+        address: &T,
+        prefix: &[u8],
+    ) -> Vec<(Vec<u8>, Substate)> {
+        let address = scrypto_encode(address);
+        let mut substates: Vec<(Vec<u8>, Substate)> = self
+            .child_substates
+            .iter()
+            .filter(|((a, k), _)| a == &address && k.starts_with(prefix))
+            .map(|((_, k), substate)| (k.clone(), substate.clone()))
+            .collect();
+        substates.sort_by(|a, b| a.0.cmp(&b.0));
+        substates
+    }
+
+    fn iter_substates<F: FnMut(&[u8], Substate)>(&self, mut f: F) -> Result<(), DecodeError> {
+        let mut substates: Vec<(&Vec<u8>, &Substate)> = self.substates.iter().collect();
+        substates.sort_by(|a, b| a.0.cmp(b.0));
+        for (address, substate) in substates {
+            f(address, substate.clone());
+        }
+        Ok(())
+    }
+
+    fn iter_child_substates<F: FnMut(&[u8], &[u8], Substate)>(
+        &self,
+        mut f: F,
+    ) -> Result<(), DecodeError> {
+        let mut child_substates: Vec<(&(Vec<u8>, Vec<u8>), &Substate)> =
+            self.child_substates.iter().collect();
+        child_substates.sort_by(|a, b| a.0.cmp(b.0));
+        for ((address, key), substate) in child_substates {
+            f(address, key, substate.clone());
+        }
+        Ok(())
+    }
+
+    fn put_raw_substate(&mut self, address: &[u8], substate: Substate) {
+        self.substates.insert(address.to_vec(), substate);
+    }
+
+    fn put_raw_child_substate(&mut self, address: &[u8], key: &[u8], substate: Substate) {
+        self.child_substates
+            .insert((address.to_vec(), key.to_vec()), substate);
+    }
+
     fn get_epoch(&self) -> u64 {
         self.current_epoch
     }
@@ -74,4 +128,8 @@ impl SubstateStore for InMemorySubstateStore {
     fn increase_nonce(&mut self) {
         self.nonce += 1;
     }
+
+    fn set_nonce(&mut self, nonce: u64) {
+        self.nonce = nonce;
+    }
 }
@@ -1,6 +1,9 @@
+mod genesis;
 mod memory;
 mod traits;
 
+pub(crate) use genesis::{apply_genesis, put_package};
+pub use genesis::{GenesisConfig, GenesisError, GenesisReceipt, GenesisResource};
 pub use memory::InMemorySubstateStore;
 pub use traits::QueryableSubstateStore;
 pub use traits::Substate;
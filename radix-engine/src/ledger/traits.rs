@@ -1,33 +1,13 @@
 use sbor::*;
-use scrypto::rule;
 use scrypto::buffer::*;
 use scrypto::constants::*;
-use scrypto::crypto::*;
 use scrypto::engine::types::*;
-use scrypto::prelude::LOCKED;
-use scrypto::resource::ResourceMethod::Withdraw;
-use scrypto::rust::borrow::ToOwned;
 use scrypto::rust::collections::*;
-use scrypto::rust::vec;
 use scrypto::rust::vec::Vec;
 
+use crate::ledger::*;
 use crate::model::*;
 
-const XRD_SYMBOL: &str = "XRD";
-const XRD_NAME: &str = "Radix";
-const XRD_DESCRIPTION: &str = "The Radix Public Network's native token, used to pay the network's required transaction fees and to secure the network through staking to its validator nodes.";
-const XRD_URL: &str = "https://tokens.radixdlt.com";
-const XRD_MAX_SUPPLY: i128 = 24_000_000_000i128;
-const XRD_VAULT_ID: VaultId = (Hash([0u8; 32]), 0);
-const XRD_VAULT: scrypto::resource::Vault = scrypto::resource::Vault(XRD_VAULT_ID);
-
-const SYSTEM_COMPONENT_NAME: &str = "System";
-
-#[derive(TypeId, Encode, Decode)]
-struct SystemComponentState {
-    xrd: scrypto::resource::Vault,
-}
-
 pub trait QueryableSubstateStore {
     fn get_lazy_map_entries(
         &self,
@@ -36,6 +16,19 @@ pub trait QueryableSubstateStore {
     ) -> HashMap<Vec<u8>, Vec<u8>>;
 }
 
+impl<T: SubstateStore> QueryableSubstateStore for T {
+    fn get_lazy_map_entries(
+        &self,
+        component_address: ComponentAddress,
+        lazy_map_id: &LazyMapId,
+    ) -> HashMap<Vec<u8>, Vec<u8>> {
+        self.get_grand_child_substates(&component_address, lazy_map_id)
+            .into_iter()
+            .map(|(key, substate)| (key, substate.value))
+            .collect()
+    }
+}
+
 #[derive(Clone, Debug, Encode, Decode, TypeId)]
 pub struct Substate {
     pub value: Vec<u8>,
@@ -64,9 +57,28 @@ impl SubstateIdGenerator {
 pub trait SubstateStore {
     fn get_substate<T: Encode>(&self, address: &T) -> Option<Substate>;
     fn put_substate<T: Encode>(&mut self, address: &T, substate: Substate);
+    fn delete_substate<T: Encode>(&mut self, address: &T);
 
     fn get_child_substate<T: Encode>(&self, address: &T, key: &[u8]) -> Option<Substate>;
     fn put_child_substate<T: Encode>(&mut self, address: &T, key: &[u8], substate: Substate);
+    fn delete_child_substate<T: Encode>(&mut self, address: &T, key: &[u8]);
+    /// Returns all child substates whose key starts with the given prefix, in key order.
+    fn get_child_substates_by_prefix<T: Encode>(
+        &self,
+        address: &T,
+        prefix: &[u8],
+    ) -> Vec<(Vec<u8>, Substate)>;
+
+    // Raw interface, keyed by the encoded address; used for exporting and importing ledger state
+    /// Calls the given function on every substate, in address order.
+    fn iter_substates<F: FnMut(&[u8], Substate)>(&self, f: F) -> Result<(), DecodeError>;
+    /// Calls the given function on every child substate, in address and key order.
+    fn iter_child_substates<F: FnMut(&[u8], &[u8], Substate)>(
+        &self,
+        f: F,
+    ) -> Result<(), DecodeError>;
+    fn put_raw_substate(&mut self, address: &[u8], substate: Substate);
+    fn put_raw_child_substate(&mut self, address: &[u8], key: &[u8], substate: Substate);
 
     // Temporary Encoded/Decoded interface
     fn get_decoded_substate<A: Encode, T: Decode>(&self, address: &A) -> Option<(T, (Hash, u32))> {
@@ -124,6 +136,28 @@ pub trait SubstateStore {
         self.get_child_substate(address, &key)
             .map(|s| (s.value, s.phys_id))
     }
+    fn delete_grand_child_substate<A: Encode, C: Encode>(
+        &mut self,
+        address: &A,
+        child_key: &C,
+        grand_child_key: &[u8],
+    ) {
+        let mut key = scrypto_encode(child_key);
+        key.extend(grand_child_key.to_vec());
+        self.delete_child_substate(address, &key);
+    }
+    /// Returns all grand child substates of a child, keyed by grand child key, in key order.
+    fn get_grand_child_substates<A: Encode, C: Encode>(
+        &self,
+        address: &A,
+        child_key: &C,
+    ) -> Vec<(Vec<u8>, Substate)> {
+        let prefix = scrypto_encode(child_key);
+        self.get_child_substates_by_prefix(address, &prefix)
+            .into_iter()
+            .map(|(key, substate)| (key[prefix.len()..].to_vec(), substate))
+            .collect()
+    }
     fn put_encoded_grand_child_substate<A: Encode, C: Encode>(
         &mut self,
         address: &A,
@@ -144,71 +178,29 @@ pub trait SubstateStore {
         );
     }
 
-    fn bootstrap(&mut self) {
+    fn bootstrap(&mut self)
+    where
+        Self: Sized,
+    {
+        self.bootstrap_with_genesis(&GenesisConfig::default())
+            .expect("Failed to apply the default genesis");
+    }
+
+    /// Bootstraps the ledger with the given genesis config, unless it's already bootstrapped.
+    fn bootstrap_with_genesis(
+        &mut self,
+        genesis: &GenesisConfig,
+    ) -> Result<Option<GenesisReceipt>, GenesisError>
+    where
+        Self: Sized,
+    {
         let package: Option<Package> = self
             .get_decoded_substate(&SYSTEM_PACKAGE)
             .map(|(package, _)| package);
         if package.is_none() {
-            let tx_hash = hash(self.get_and_increase_nonce().to_le_bytes());
-            let mut id_gen = SubstateIdGenerator::new(tx_hash);
-
-            // System package
-            let system_package =
-                Package::new(include_bytes!("../../../assets/system.wasm").to_vec()).unwrap();
-            self.put_encoded_substate(&SYSTEM_PACKAGE, &system_package, id_gen.next());
-
-            // Account package
-            let account_package =
-                Package::new(include_bytes!("../../../assets/account.wasm").to_vec()).unwrap();
-            self.put_encoded_substate(&ACCOUNT_PACKAGE, &account_package, id_gen.next());
-
-            // Radix token resource address
-            let mut metadata = HashMap::new();
-            metadata.insert("symbol".to_owned(), XRD_SYMBOL.to_owned());
-            metadata.insert("name".to_owned(), XRD_NAME.to_owned());
-            metadata.insert("description".to_owned(), XRD_DESCRIPTION.to_owned());
-            metadata.insert("url".to_owned(), XRD_URL.to_owned());
-
-            let mut resource_auth = HashMap::new();
-            resource_auth.insert(Withdraw, (rule!(allow_all), LOCKED));
-
-            let mut xrd = ResourceManager::new(
-                ResourceType::Fungible { divisibility: 18 },
-                metadata,
-                resource_auth,
-            )
-            .unwrap();
-            self.put_encoded_substate(&RADIX_TOKEN, &xrd, id_gen.next());
-            let minted_xrd = xrd
-                .mint_fungible(XRD_MAX_SUPPLY.into(), RADIX_TOKEN.clone())
-                .unwrap();
-
-            let mut ecdsa_resource_auth = HashMap::new();
-            ecdsa_resource_auth.insert(Withdraw, (rule!(allow_all), LOCKED));
-            let ecdsa_token = ResourceManager::new(
-                ResourceType::NonFungible,
-                HashMap::new(),
-                ecdsa_resource_auth,
-            )
-            .unwrap();
-            self.put_encoded_substate(&ECDSA_TOKEN, &ecdsa_token, id_gen.next());
-
-            // Instantiate system component
-            let system_vault = Vault::new(minted_xrd);
-            self.put_encoded_child_substate(
-                &SYSTEM_COMPONENT,
-                &XRD_VAULT_ID,
-                &system_vault,
-                id_gen.next(),
-            );
-
-            let system_component = Component::new(
-                SYSTEM_PACKAGE,
-                SYSTEM_COMPONENT_NAME.to_owned(),
-                vec![],
-                scrypto_encode(&SystemComponentState { xrd: XRD_VAULT }),
-            );
-            self.put_encoded_substate(&SYSTEM_COMPONENT, &system_component, id_gen.next());
+            apply_genesis(self, genesis).map(Some)
+        } else {
+            Ok(None)
         }
     }
 
@@ -224,6 +216,8 @@ pub trait SubstateStore {
 
     fn increase_nonce(&mut self);
 
+    fn set_nonce(&mut self, nonce: u64);
+
     fn get_and_increase_nonce(&mut self) -> u64 {
         let nonce = self.get_nonce();
         self.increase_nonce();
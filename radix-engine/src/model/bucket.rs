@@ -33,6 +33,27 @@ pub struct Bucket {
     container: Rc<RefCell<ResourceContainer>>,
 }
 
+/// A copy of the content of a bucket, which can be restored into the bucket.
+#[derive(Debug)]
+pub struct BucketSnapshot {
+    /// The container of the bucket, unless the snapshot is detached from it
+    container: Option<Rc<RefCell<ResourceContainer>>>,
+    content: ResourceContainer,
+}
+
+impl BucketSnapshot {
+    /// Restores the bucket to its content at the time of the snapshot, including locks.
+    pub fn restore(self) -> Bucket {
+        match self.container {
+            Some(container) => {
+                *container.borrow_mut() = self.content;
+                Bucket { container }
+            }
+            None => Bucket::new(self.content),
+        }
+    }
+}
+
 impl Bucket {
     pub fn new(container: ResourceContainer) -> Self {
         Self {
@@ -40,6 +61,22 @@ impl Bucket {
         }
     }
 
+    pub fn snapshot(&self) -> BucketSnapshot {
+        BucketSnapshot {
+            container: Some(self.container.clone()),
+            content: self.borrow_container().clone(),
+        }
+    }
+
+    /// Takes a snapshot which doesn't share the container, so that the bucket can still
+    /// be moved into another container.
+    pub fn detached_snapshot(&self) -> BucketSnapshot {
+        BucketSnapshot {
+            container: None,
+            content: self.borrow_container().clone(),
+        }
+    }
+
     fn put(&mut self, other: Bucket) -> Result<(), ResourceContainerError> {
         self.borrow_container_mut().put(other.into_container()?)
     }
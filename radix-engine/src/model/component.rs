@@ -1,36 +1,71 @@
 use sbor::*;
 use scrypto::engine::types::*;
-use scrypto::resource::AccessRules;
+use scrypto::resource::{AccessRule, AccessRules, Mutability};
 use scrypto::rust::string::String;
 use scrypto::rust::vec::Vec;
 use scrypto::values::*;
 
 use crate::model::{convert, MethodAuthorization};
 
+/// Represents an error when accessing a component.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ComponentError {
+    AccessRulesNotFound(usize),
+}
+
 /// A component is an instance of blueprint.
-#[derive(Debug, TypeId, Encode, Decode)]
+#[derive(Debug, Clone, TypeId, Encode, Decode)]
 pub struct Component {
     package_address: PackageAddress,
+    package_version: u32,
     blueprint_name: String,
     auths: Vec<AccessRules>,
     state: Vec<u8>,
+    owned: bool,
 }
 
 impl Component {
     pub fn new(
         package_address: PackageAddress,
+        package_version: u32,
         blueprint_name: String,
         method_auth: Vec<AccessRules>,
         state: Vec<u8>,
     ) -> Self {
         Self {
             package_address,
+            package_version,
             blueprint_name,
             auths: method_auth,
             state,
+            owned: false,
         }
     }
 
+    /// Creates a component which can only be invoked by the component owning it.
+    pub fn new_owned(
+        package_address: PackageAddress,
+        package_version: u32,
+        blueprint_name: String,
+        method_auth: Vec<AccessRules>,
+        state: Vec<u8>,
+    ) -> Self {
+        Self {
+            owned: true,
+            ..Self::new(
+                package_address,
+                package_version,
+                blueprint_name,
+                method_auth,
+                state,
+            )
+        }
+    }
+
+    pub fn is_owned(&self) -> bool {
+        self.owned
+    }
+
     pub fn method_authorization(
         &self,
         schema: &Type,
@@ -48,6 +83,51 @@ impl Component {
         (data, authorizations)
     }
 
+    /// Returns the authorization required to update the rule of a method.
+    pub fn method_mutability_authorization(
+        &self,
+        schema: &Type,
+        access_rules_index: usize,
+        method_name: &str,
+    ) -> Result<MethodAuthorization, ComponentError> {
+        let access_rules = self
+            .auths
+            .get(access_rules_index)
+            .ok_or(ComponentError::AccessRulesNotFound(access_rules_index))?;
+        match access_rules.get_mutability(method_name) {
+            Mutability::LOCKED => Ok(MethodAuthorization::DenyAll),
+            Mutability::MUTABLE(access_rule) => {
+                let data = ScryptoValue::from_slice(&self.state).unwrap();
+                Ok(convert(schema, &data.dom, access_rule))
+            }
+        }
+    }
+
+    pub fn set_method_auth(
+        &mut self,
+        access_rules_index: usize,
+        method_name: &str,
+        access_rule: AccessRule,
+    ) -> Result<(), ComponentError> {
+        self.auths
+            .get_mut(access_rules_index)
+            .ok_or(ComponentError::AccessRulesNotFound(access_rules_index))?
+            .set_method_auth(method_name, access_rule);
+        Ok(())
+    }
+
+    pub fn lock_method_auth(
+        &mut self,
+        access_rules_index: usize,
+        method_name: &str,
+    ) -> Result<(), ComponentError> {
+        self.auths
+            .get_mut(access_rules_index)
+            .ok_or(ComponentError::AccessRulesNotFound(access_rules_index))?
+            .lock_method_auth(method_name);
+        Ok(())
+    }
+
     pub fn authorization(&self) -> &[AccessRules] {
         &self.auths
     }
@@ -56,6 +136,17 @@ impl Component {
         self.package_address.clone()
     }
 
+    /// Returns the version of the package whose code this component runs.
+    pub fn package_version(&self) -> u32 {
+        self.package_version
+    }
+
+    /// Moves this component to a newer package version, along with its migrated state.
+    pub fn migrate(&mut self, package_version: u32, new_state: Vec<u8>) {
+        self.package_version = package_version;
+        self.state = new_state;
+    }
+
     pub fn blueprint_name(&self) -> &str {
         &self.blueprint_name
     }
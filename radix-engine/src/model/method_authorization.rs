@@ -1,5 +1,6 @@
 use sbor::*;
 use scrypto::engine::types::*;
+use scrypto::rust::collections::BTreeSet;
 use scrypto::rust::vec::Vec;
 
 use crate::model::method_authorization::MethodAuthorizationError::NotAuthorized;
@@ -7,10 +8,82 @@ use crate::model::{AuthZone, Proof};
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, TypeId, Encode, Decode)]
 pub enum MethodAuthorizationError {
-    NotAuthorized,
+    NotAuthorized(AuthorizationFailure),
     UnsupportedMethod,
 }
 
+/// Explains why an authorization check failed.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, TypeId, Encode, Decode)]
+pub struct AuthorizationFailure {
+    /// The outcome of each rule and sub-rule
+    pub explanation: AuthExplanation,
+    /// The proofs which were in the auth zones
+    pub held_proofs: Vec<HeldProof>,
+}
+
+/// The outcome of checking a rule against the auth zones, with the outcomes of its sub-rules.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, TypeId, Encode, Decode)]
+pub struct AuthExplanation {
+    pub requirement: AuthRequirement,
+    pub passed: bool,
+    pub sub_rules: Vec<AuthExplanation>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, TypeId, Encode, Decode)]
+pub enum AuthRequirement {
+    Require(HardResourceOrNonFungible),
+    AmountOf(Decimal, HardResourceOrNonFungible),
+    AllOf,
+    AnyOf,
+    CountOf(u8),
+    /// A rule referring to component state which could not be resolved
+    Unresolved,
+    DenyAll,
+}
+
+impl AuthExplanation {
+    fn require(resource: &HardResourceOrNonFungible, auth_zones: &[&AuthZone]) -> Self {
+        Self {
+            requirement: AuthRequirement::Require(resource.clone()),
+            passed: resource.check(auth_zones),
+            sub_rules: Vec::new(),
+        }
+    }
+
+    fn require_each(
+        resources: &[HardResourceOrNonFungible],
+        auth_zones: &[&AuthZone],
+    ) -> Vec<Self> {
+        resources
+            .iter()
+            .map(|resource| Self::require(resource, auth_zones))
+            .collect()
+    }
+}
+
+/// A summary of a proof held in an auth zone.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, TypeId, Encode, Decode)]
+pub struct HeldProof {
+    pub resource_address: ResourceAddress,
+    pub amount: Decimal,
+    /// The non-fungible IDs, if a non-fungible resource
+    pub ids: Option<BTreeSet<NonFungibleId>>,
+}
+
+impl HeldProof {
+    fn list(auth_zones: &[&AuthZone]) -> Vec<Self> {
+        auth_zones
+            .iter()
+            .flat_map(|auth_zone| auth_zone.proofs.iter())
+            .map(|proof| Self {
+                resource_address: proof.resource_address(),
+                amount: proof.total_amount(),
+                ids: proof.total_ids().ok(),
+            })
+            .collect()
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash, TypeId, Encode, Decode)]
 pub enum HardDecimal {
     Amount(Decimal),
@@ -31,6 +104,16 @@ pub enum HardResourceOrNonFungible {
 }
 
 impl HardResourceOrNonFungible {
+    pub fn references(&self, resource_address: ResourceAddress) -> bool {
+        match self {
+            HardResourceOrNonFungible::NonFungible(non_fungible_address) => {
+                non_fungible_address.resource_address() == resource_address
+            }
+            HardResourceOrNonFungible::Resource(address) => *address == resource_address,
+            HardResourceOrNonFungible::SoftResourceNotFound => false,
+        }
+    }
+
     pub fn proof_matches(&self, proof: &Proof) -> bool {
         match self {
             HardResourceOrNonFungible::NonFungible(non_fungible_address) => {
@@ -52,7 +135,8 @@ impl HardResourceOrNonFungible {
     pub fn check_has_amount(&self, amount: Decimal, auth_zones: &[&AuthZone]) -> bool {
         for auth_zone in auth_zones {
             // FIXME: Need to check the composite max amount rather than just each proof individually
-            if auth_zone.proofs
+            if auth_zone
+                .proofs
                 .iter()
                 .any(|p| self.proof_matches(p) && p.total_amount() >= amount)
             {
@@ -92,6 +176,17 @@ pub enum HardProofRuleResourceList {
     SoftResourceListNotFound,
 }
 
+impl HardProofRuleResourceList {
+    pub fn references(&self, resource_address: ResourceAddress) -> bool {
+        match self {
+            HardProofRuleResourceList::List(resources) => {
+                resources.iter().any(|r| r.references(resource_address))
+            }
+            HardProofRuleResourceList::SoftResourceListNotFound => false,
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash, TypeId, Encode, Decode)]
 pub enum HardProofRule {
     This(HardResourceOrNonFungible),
@@ -102,56 +197,60 @@ pub enum HardProofRule {
 }
 
 impl HardProofRule {
-    pub fn check(&self, auth_zones: &[&AuthZone]) -> Result<(), MethodAuthorizationError> {
+    pub fn references(&self, resource_address: ResourceAddress) -> bool {
         match self {
-            HardProofRule::This(resource) => {
-                if resource.check(auth_zones) {
-                    Ok(())
-                } else {
-                    Err(NotAuthorized)
-                }
+            HardProofRule::This(resource) | HardProofRule::SomeOfResource(_, resource) => {
+                resource.references(resource_address)
             }
+            HardProofRule::AllOf(resources)
+            | HardProofRule::AnyOf(resources)
+            | HardProofRule::CountOf(_, resources) => resources.references(resource_address),
+        }
+    }
+
+    pub fn explain(&self, auth_zones: &[&AuthZone]) -> AuthExplanation {
+        match self {
+            HardProofRule::This(resource) => AuthExplanation::require(resource, auth_zones),
             HardProofRule::SomeOfResource(HardDecimal::Amount(amount), resource) => {
-                if resource.check_has_amount(*amount, auth_zones) {
-                    Ok(())
-                } else {
-                    Err(NotAuthorized)
+                AuthExplanation {
+                    requirement: AuthRequirement::AmountOf(*amount, resource.clone()),
+                    passed: resource.check_has_amount(*amount, auth_zones),
+                    sub_rules: Vec::new(),
                 }
             }
             HardProofRule::AllOf(HardProofRuleResourceList::List(resources)) => {
-                for resource in resources {
-                    if !resource.check(auth_zones) {
-                        return Err(NotAuthorized);
-                    }
+                let sub_rules = AuthExplanation::require_each(resources, auth_zones);
+                AuthExplanation {
+                    requirement: AuthRequirement::AllOf,
+                    passed: sub_rules.iter().all(|r| r.passed),
+                    sub_rules,
                 }
-
-                Ok(())
             }
             HardProofRule::AnyOf(HardProofRuleResourceList::List(resources)) => {
-                for resource in resources {
-                    if resource.check(auth_zones) {
-                        return Ok(());
-                    }
+                let sub_rules = AuthExplanation::require_each(resources, auth_zones);
+                AuthExplanation {
+                    requirement: AuthRequirement::AnyOf,
+                    passed: sub_rules.iter().any(|r| r.passed),
+                    sub_rules,
                 }
-
-                Err(NotAuthorized)
             }
             HardProofRule::CountOf(
                 HardCount::Count(count),
                 HardProofRuleResourceList::List(resources),
             ) => {
-                let mut left = count.clone();
-                for resource in resources {
-                    if resource.check(auth_zones) {
-                        left -= 1;
-                        if left == 0 {
-                            return Ok(());
-                        }
-                    }
+                let sub_rules = AuthExplanation::require_each(resources, auth_zones);
+                let passed_count = sub_rules.iter().filter(|r| r.passed).count();
+                AuthExplanation {
+                    requirement: AuthRequirement::CountOf(*count),
+                    passed: *count > 0 && passed_count >= *count as usize,
+                    sub_rules,
                 }
-                Err(NotAuthorized)
             }
-            _ => Err(NotAuthorized),
+            _ => AuthExplanation {
+                requirement: AuthRequirement::Unresolved,
+                passed: false,
+                sub_rules: Vec::new(),
+            },
         }
     }
 }
@@ -164,20 +263,35 @@ pub enum HardAuthRule {
 }
 
 impl HardAuthRule {
-    fn check(&self, auth_zones: &[&AuthZone]) -> Result<(), MethodAuthorizationError> {
+    fn references(&self, resource_address: ResourceAddress) -> bool {
+        match self {
+            HardAuthRule::ProofRule(rule) => rule.references(resource_address),
+            HardAuthRule::AnyOf(rules) | HardAuthRule::AllOf(rules) => {
+                rules.iter().any(|r| r.references(resource_address))
+            }
+        }
+    }
+
+    fn explain(&self, auth_zones: &[&AuthZone]) -> AuthExplanation {
         match self {
-            HardAuthRule::ProofRule(rule) => rule.check(auth_zones),
+            HardAuthRule::ProofRule(rule) => rule.explain(auth_zones),
             HardAuthRule::AnyOf(rules) => {
-                if !rules.iter().any(|r| r.check(auth_zones).is_ok()) {
-                    return Err(NotAuthorized);
+                let sub_rules: Vec<AuthExplanation> =
+                    rules.iter().map(|r| r.explain(auth_zones)).collect();
+                AuthExplanation {
+                    requirement: AuthRequirement::AnyOf,
+                    passed: sub_rules.iter().any(|r| r.passed),
+                    sub_rules,
                 }
-                Ok(())
             }
             HardAuthRule::AllOf(rules) => {
-                if rules.iter().any(|r| r.check(auth_zones).is_err()) {
-                    return Err(NotAuthorized);
+                let sub_rules: Vec<AuthExplanation> =
+                    rules.iter().map(|r| r.explain(auth_zones)).collect();
+                AuthExplanation {
+                    requirement: AuthRequirement::AllOf,
+                    passed: sub_rules.iter().all(|r| r.passed),
+                    sub_rules,
                 }
-                Ok(())
             }
         }
     }
@@ -193,12 +307,35 @@ pub enum MethodAuthorization {
 }
 
 impl MethodAuthorization {
-    pub fn check(&self, auth_zones: &[&AuthZone]) -> Result<(), MethodAuthorizationError> {
+    /// Returns whether any proof rule of this authorization is on the given resource.
+    pub fn references(&self, resource_address: ResourceAddress) -> bool {
         match self {
-            MethodAuthorization::Protected(rule) => rule.check(auth_zones),
-            MethodAuthorization::AllowAll => Ok(()),
-            MethodAuthorization::DenyAll => Err(MethodAuthorizationError::NotAuthorized),
-            MethodAuthorization::Unsupported => Err(MethodAuthorizationError::UnsupportedMethod),
+            MethodAuthorization::Protected(rule) => rule.references(resource_address),
+            _ => false,
+        }
+    }
+
+    pub fn check(&self, auth_zones: &[&AuthZone]) -> Result<(), MethodAuthorizationError> {
+        let explanation = match self {
+            MethodAuthorization::Protected(rule) => rule.explain(auth_zones),
+            MethodAuthorization::AllowAll => return Ok(()),
+            MethodAuthorization::DenyAll => AuthExplanation {
+                requirement: AuthRequirement::DenyAll,
+                passed: false,
+                sub_rules: Vec::new(),
+            },
+            MethodAuthorization::Unsupported => {
+                return Err(MethodAuthorizationError::UnsupportedMethod)
+            }
+        };
+
+        if explanation.passed {
+            Ok(())
+        } else {
+            Err(NotAuthorized(AuthorizationFailure {
+                explanation,
+                held_proofs: HeldProof::list(auth_zones),
+            }))
         }
     }
 }
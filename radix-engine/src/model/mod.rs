@@ -13,25 +13,26 @@ mod transaction;
 mod transaction_process;
 mod validated_transaction;
 mod vault;
+mod wasm_symbols;
 mod worktop;
 
-pub use auth_zone::{AuthZone, AuthZoneError};
 pub use auth_converter::convert;
-pub use bucket::{Bucket, BucketError};
-pub use component::Component;
+pub use auth_zone::{AuthZone, AuthZoneError};
+pub use bucket::{Bucket, BucketError, BucketSnapshot};
+pub use component::{Component, ComponentError};
 pub use method_authorization::{
-    HardProofRule, HardResourceOrNonFungible, MethodAuthorization, MethodAuthorizationError,
+    AuthExplanation, AuthRequirement, AuthorizationFailure, HardProofRule,
+    HardResourceOrNonFungible, HeldProof, MethodAuthorization, MethodAuthorizationError,
 };
 pub use non_fungible::NonFungible;
-pub use package::{Package, PackageError};
+pub use package::{BlueprintAbi, Package, PackageError, PackageVersion};
 pub use proof::*;
 pub use receipt::Receipt;
 pub use resource::*;
 pub use resource_manager::{ResourceManager, ResourceManagerError};
-pub use transaction_process::{TransactionProcess};
-pub use transaction::{
-    Instruction, SignedTransaction, Transaction,
-};
-pub use validated_transaction::{ValidatedTransaction, ValidatedInstruction};
-pub use vault::{Vault, VaultError};
+pub use transaction::{Instruction, SignedTransaction, Transaction};
+pub use transaction_process::TransactionProcess;
+pub use validated_transaction::{ValidatedInstruction, ValidatedTransaction};
+pub use vault::{Vault, VaultError, VaultSnapshot};
+pub use wasm_symbols::WasmSymbols;
 pub use worktop::{Worktop, WorktopError};
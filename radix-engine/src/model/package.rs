@@ -1,6 +1,9 @@
 use sbor::*;
 use scrypto::abi::{Function, Method};
 use scrypto::buffer::scrypto_decode;
+use scrypto::crypto::{sha256, Hash};
+use scrypto::engine::types::PackageAddress;
+use scrypto::resource::AccessRule;
 use scrypto::rust::collections::HashMap;
 use scrypto::rust::string::String;
 use scrypto::rust::string::ToString;
@@ -14,25 +17,150 @@ use wasmi::{
 
 use crate::engine::{EnvModuleResolver, SystemApi};
 use crate::errors::WasmValidationError;
+use crate::model::{convert, MethodAuthorization, WasmSymbols};
 
 /// A collection of blueprints, compiled and published as a single unit.
+///
+/// All published versions are kept, as components keep running the version they were created
+/// with until migrated. Each version is stored as a child substate keyed by its number, so that
+/// the code of other versions isn't loaded.
 #[derive(Debug, Clone, TypeId, Encode, Decode)]
 pub struct Package {
+    latest_version: u32,
+    owner_rule: AccessRule,
+}
+
+/// The code, blueprints and metadata of a package version.
+#[derive(Debug, Clone, TypeId, Encode, Decode)]
+pub struct PackageVersion {
     code: Vec<u8>,
-    blueprints: HashMap<String, Type>,
+    code_hash: Hash,
+    blueprints: HashMap<String, BlueprintAbi>,
+    metadata: HashMap<String, String>,
+    symbols: WasmSymbols,
+}
+
+/// The schema, functions and methods of a blueprint, as exported at publish time.
+#[derive(Debug, Clone, TypeId, Encode, Decode)]
+pub struct BlueprintAbi {
+    pub schema: Type,
+    pub functions: Vec<Function>,
+    pub methods: Vec<Method>,
 }
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum PackageError {
     InvalidRequestData(DecodeError),
     BlueprintNotFound,
+    VersionNotFound(u32),
     WasmValidationError(WasmValidationError),
     MethodNotFound(String),
 }
 
 impl Package {
-    /// Validates and creates a package
-    pub fn new(code: Vec<u8>) -> Result<Self, WasmValidationError> {
+    /// Creates a package, whose first version is stored along with it.
+    ///
+    /// Unless the owner rule is `DenyAll`, the package can be upgraded by the owner.
+    pub fn new(owner_rule: AccessRule) -> Self {
+        Self {
+            latest_version: 1,
+            owner_rule,
+        }
+    }
+
+    /// Returns the latest version number, with versions numbered from 1.
+    pub fn latest_version(&self) -> u32 {
+        self.latest_version
+    }
+
+    pub fn owner_rule(&self) -> &AccessRule {
+        &self.owner_rule
+    }
+
+    pub fn get_auth(&self, method_name: &str) -> MethodAuthorization {
+        match method_name {
+            "upgrade" => convert(&Type::Unit, &Value::Unit, &self.owner_rule),
+            _ => MethodAuthorization::DenyAll,
+        }
+    }
+
+    pub fn main<S: SystemApi>(
+        &mut self,
+        package_address: PackageAddress,
+        function: &str,
+        args: Vec<ScryptoValue>,
+        system_api: &mut S,
+    ) -> Result<ScryptoValue, PackageError> {
+        match function {
+            "upgrade" => {
+                let bytes =
+                    scrypto_decode(&args[0].raw).map_err(PackageError::InvalidRequestData)?;
+                let metadata =
+                    scrypto_decode(&args[1].raw).map_err(PackageError::InvalidRequestData)?;
+                let package_version = PackageVersion::new(bytes, metadata)
+                    .map_err(PackageError::WasmValidationError)?;
+                self.latest_version += 1;
+                system_api.create_package_version(
+                    package_address,
+                    self.latest_version,
+                    package_version,
+                );
+                Ok(ScryptoValue::from_value(&self.latest_version))
+            }
+            _ => Err(PackageError::MethodNotFound(function.to_string())),
+        }
+    }
+
+    pub fn static_main<S: SystemApi>(
+        function: &str,
+        args: Vec<ScryptoValue>,
+        system_api: &mut S,
+    ) -> Result<ScryptoValue, PackageError> {
+        match function {
+            "publish" => {
+                let bytes =
+                    scrypto_decode(&args[0].raw).map_err(PackageError::InvalidRequestData)?;
+                let package_version = PackageVersion::new(bytes, HashMap::new())
+                    .map_err(PackageError::WasmValidationError)?;
+                let package_address =
+                    system_api.create_package(Package::new(AccessRule::DenyAll), package_version);
+                Ok(ScryptoValue::from_value(&package_address))
+            }
+            "publish_with_metadata" => {
+                let bytes =
+                    scrypto_decode(&args[0].raw).map_err(PackageError::InvalidRequestData)?;
+                let metadata =
+                    scrypto_decode(&args[1].raw).map_err(PackageError::InvalidRequestData)?;
+                let package_version = PackageVersion::new(bytes, metadata)
+                    .map_err(PackageError::WasmValidationError)?;
+                let package_address =
+                    system_api.create_package(Package::new(AccessRule::DenyAll), package_version);
+                Ok(ScryptoValue::from_value(&package_address))
+            }
+            "publish_with_owner" => {
+                let bytes =
+                    scrypto_decode(&args[0].raw).map_err(PackageError::InvalidRequestData)?;
+                let metadata =
+                    scrypto_decode(&args[1].raw).map_err(PackageError::InvalidRequestData)?;
+                let owner_rule: AccessRule =
+                    scrypto_decode(&args[2].raw).map_err(PackageError::InvalidRequestData)?;
+                let package_version = PackageVersion::new(bytes, metadata)
+                    .map_err(PackageError::WasmValidationError)?;
+                let package_address =
+                    system_api.create_package(Package::new(owner_rule), package_version);
+                Ok(ScryptoValue::from_value(&package_address))
+            }
+            _ => Err(PackageError::MethodNotFound(function.to_string())),
+        }
+    }
+}
+
+impl PackageVersion {
+    /// Validates and creates a package version
+    pub fn new(
+        code: Vec<u8>,
+        metadata: HashMap<String, String>,
+    ) -> Result<Self, WasmValidationError> {
         // Parse
         let parsed = Self::parse_module(&code)?;
 
@@ -79,7 +207,7 @@ impl Package {
                 .map_err(|e| WasmValidationError::NoPackageInitExport(e.into()))?
                 .ok_or(WasmValidationError::InvalidPackageInit)?;
 
-            let blueprint_type: Type = match rtn {
+            let blueprint_abi: BlueprintAbi = match rtn {
                 RuntimeValue::I32(ptr) => {
                     let len: u32 = memory
                         .get_value(ptr as u32)
@@ -91,32 +219,62 @@ impl Package {
                         .get_into((ptr + 4) as u32, &mut data)
                         .map_err(|_| WasmValidationError::InvalidPackageInit)?;
 
-                    let result: (Type, Vec<Function>, Vec<Method>) = scrypto_decode(&data)
-                        .map_err(|_| WasmValidationError::InvalidPackageInit)?;
-                    Ok(result.0)
+                    let (schema, functions, methods): (Type, Vec<Function>, Vec<Method>) =
+                        scrypto_decode(&data)
+                            .map_err(|_| WasmValidationError::InvalidPackageInit)?;
+                    Ok(BlueprintAbi {
+                        schema,
+                        functions,
+                        methods,
+                    })
                 }
                 _ => Err(WasmValidationError::InvalidPackageInit),
             }?;
 
-            if let Type::Struct { name, fields: _ } = &blueprint_type {
-                blueprints.insert(name.clone(), blueprint_type);
+            if let Type::Struct { name, fields: _ } = &blueprint_abi.schema {
+                blueprints.insert(name.clone(), blueprint_abi);
             } else {
                 return Err(WasmValidationError::InvalidPackageInit);
             }
         }
 
-        Ok(Self { blueprints, code })
+        Ok(Self {
+            code_hash: sha256(&code),
+            symbols: WasmSymbols::parse(&code),
+            code,
+            blueprints,
+            metadata,
+        })
     }
 
     pub fn code(&self) -> &[u8] {
         &self.code
     }
 
+    /// Returns the SHA-256 hash of the code.
+    pub fn code_hash(&self) -> Hash {
+        self.code_hash
+    }
+
+    pub fn metadata(&self) -> &HashMap<String, String> {
+        &self.metadata
+    }
+
+    /// Returns the function names of the code, for symbolicating traps.
+    pub fn symbols(&self) -> &WasmSymbols {
+        &self.symbols
+    }
+
     pub fn contains_blueprint(&self, blueprint_name: &str) -> bool {
         self.blueprints.contains_key(blueprint_name)
     }
 
     pub fn load_blueprint_schema(&self, blueprint_name: &str) -> Result<&Type, PackageError> {
+        self.load_blueprint_abi(blueprint_name)
+            .map(|blueprint_abi| &blueprint_abi.schema)
+    }
+
+    pub fn load_blueprint_abi(&self, blueprint_name: &str) -> Result<&BlueprintAbi, PackageError> {
         self.blueprints
             .get(blueprint_name)
             .ok_or(PackageError::BlueprintNotFound)
@@ -148,21 +306,4 @@ impl Package {
             Err(WasmValidationError::NoValidMemoryExport)
         }
     }
-
-    pub fn static_main<S: SystemApi>(
-        function: &str,
-        args: Vec<ScryptoValue>,
-        system_api: &mut S,
-    ) -> Result<ScryptoValue, PackageError> {
-        match function {
-            "publish" => {
-                let bytes =
-                    scrypto_decode(&args[0].raw).map_err(PackageError::InvalidRequestData)?;
-                let package = Package::new(bytes).map_err(PackageError::WasmValidationError)?;
-                let package_address = system_api.create_package(package);
-                Ok(ScryptoValue::from_value(&package_address))
-            }
-            _ => Err(PackageError::MethodNotFound(function.to_string())),
-        }
-    }
 }
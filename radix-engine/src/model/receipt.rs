@@ -48,6 +48,32 @@ impl fmt::Debug for Receipt {
             .bold()
         )?;
 
+        if let Err(RuntimeError::AuthorizationError {
+            error: MethodAuthorizationError::NotAuthorized(failure),
+            ..
+        }) = &self.result
+        {
+            write!(f, "\n{}", "Authorization:".bold().green())?;
+            fmt_auth_explanation(f, &failure.explanation, "", true)?;
+
+            write!(
+                f,
+                "\n{} {}",
+                "Auth Zone Proofs:".bold().green(),
+                failure.held_proofs.len()
+            )?;
+            for (i, proof) in failure.held_proofs.iter().enumerate() {
+                write!(f, "\n{} {:?}", prefix!(i, failure.held_proofs), proof)?;
+            }
+        }
+
+        if let Err(RuntimeError::WasmTrap { call_frames, .. }) = &self.result {
+            write!(f, "\n{}", "Call Frames:".bold().green())?;
+            for (i, frame) in call_frames.iter().enumerate() {
+                write!(f, "\n{} {}", prefix!(i, call_frames), frame)?;
+            }
+        }
+
         write!(
             f,
             "\n{} {} ms",
@@ -65,6 +91,20 @@ impl fmt::Debug for Receipt {
                 prefix!(i, self.validated_transaction.instructions),
                 match inst {
                     ValidatedInstruction::PublishPackage { .. } => "PublishPackage {..}".to_owned(),
+                    ValidatedInstruction::PublishPackageWithOwner { owner_rule, .. } => {
+                        format!(
+                            "PublishPackageWithOwner {{ owner_rule: {:?}, .. }}",
+                            owner_rule
+                        )
+                    }
+                    ValidatedInstruction::UpgradePackage {
+                        package_address, ..
+                    } => {
+                        format!(
+                            "UpgradePackage {{ package_address: {}, .. }}",
+                            package_address
+                        )
+                    }
                     i @ _ => format!("{:?}", i),
                 }
             )?;
@@ -124,3 +164,30 @@ impl fmt::Debug for Receipt {
         Ok(())
     }
 }
+
+/// Writes an explanation of an authorization check as a tree.
+fn fmt_auth_explanation(
+    f: &mut fmt::Formatter<'_>,
+    explanation: &AuthExplanation,
+    indent: &str,
+    last: bool,
+) -> fmt::Result {
+    write!(
+        f,
+        "\n{}{} {} {:?}",
+        indent,
+        if last { "└─" } else { "├─" },
+        if explanation.passed {
+            "PASS".green()
+        } else {
+            "FAIL".red()
+        },
+        explanation.requirement
+    )?;
+
+    let indent = format!("{}{}", indent, if last { "   " } else { "│  " });
+    for (i, sub_rule) in explanation.sub_rules.iter().enumerate() {
+        fmt_auth_explanation(f, sub_rule, &indent, i == explanation.sub_rules.len() - 1)?;
+    }
+    Ok(())
+}
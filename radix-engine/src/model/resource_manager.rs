@@ -416,7 +416,7 @@ impl ResourceManager {
     ) -> Result<ScryptoValue, ResourceManagerError> {
         match function {
             "is_shrouded" => Ok(ScryptoValue::from_value(&self.is_shrouded())),
-            "shroud" => { 
+            "shroud" => {
                 self.shroud();
                 Ok(ScryptoValue::from_value(&()))
             }
@@ -489,7 +489,7 @@ impl ResourceManager {
             }
             "get_non_fungible" => {
                 if self.shrouded {
-                    return Err(ResourceManagerError::CannotAccessShroudedData)
+                    return Err(ResourceManagerError::CannotAccessShroudedData);
                 }
                 let non_fungible_id: NonFungibleId = scrypto_decode(&args[0].raw)
                     .map_err(|e| ResourceManagerError::InvalidRequestData(e))?;
@@ -2,7 +2,8 @@ use sbor::*;
 use scrypto::buffer::scrypto_encode;
 use scrypto::crypto::*;
 use scrypto::engine::types::*;
-use scrypto::rust::collections::BTreeSet;
+use scrypto::resource::AccessRule;
+use scrypto::rust::collections::{BTreeSet, HashMap};
 use scrypto::rust::string::String;
 use scrypto::rust::vec;
 use scrypto::rust::vec::Vec;
@@ -26,7 +27,6 @@ pub struct SignedTransaction {
     pub signatures: Vec<(EcdsaPublicKey, EcdsaSignature)>,
 }
 
-
 /// Represents an instruction
 #[derive(Debug, Clone, TypeId, Encode, Decode, PartialEq, Eq)]
 pub enum Instruction {
@@ -123,7 +123,24 @@ pub enum Instruction {
     },
 
     /// Publishes a package.
-    PublishPackage { code: Vec<u8> },
+    PublishPackage {
+        code: Vec<u8>,
+        metadata: HashMap<String, String>,
+    },
+
+    /// Publishes a package, which can be upgraded by the owner.
+    PublishPackageWithOwner {
+        code: Vec<u8>,
+        metadata: HashMap<String, String>,
+        owner_rule: AccessRule,
+    },
+
+    /// Publishes new code as the latest version of a package.
+    UpgradePackage {
+        package_address: PackageAddress,
+        code: Vec<u8>,
+        metadata: HashMap<String, String>,
+    },
 
     /// Specifies transaction nonce
     Nonce {
@@ -336,8 +353,30 @@ impl SignedTransaction {
                         method,
                     });
                 }
-                Instruction::PublishPackage { code } => {
-                    instructions.push(ValidatedInstruction::PublishPackage { code });
+                Instruction::PublishPackage { code, metadata } => {
+                    instructions.push(ValidatedInstruction::PublishPackage { code, metadata });
+                }
+                Instruction::PublishPackageWithOwner {
+                    code,
+                    metadata,
+                    owner_rule,
+                } => {
+                    instructions.push(ValidatedInstruction::PublishPackageWithOwner {
+                        code,
+                        metadata,
+                        owner_rule,
+                    });
+                }
+                Instruction::UpgradePackage {
+                    package_address,
+                    code,
+                    metadata,
+                } => {
+                    instructions.push(ValidatedInstruction::UpgradePackage {
+                        package_address,
+                        code,
+                        metadata,
+                    });
                 }
                 Instruction::Nonce { .. } => {
                     // TODO: validate nonce
@@ -373,6 +412,11 @@ impl SignedTransaction {
                     lazy_map_id.clone(),
                 ));
             }
+            if let Some(component_address) = validated_arg.owned_component_addresses.iter().nth(0) {
+                return Err(TransactionValidationError::OwnedComponentNotAllowed(
+                    component_address.clone(),
+                ));
+            }
             result.push(validated_arg);
         }
         Ok(result)
@@ -435,4 +479,26 @@ mod tests {
             ))),
         );
     }
+
+    #[test]
+    fn should_reject_transaction_passing_owned_component() {
+        assert_eq!(
+            SignedTransaction {
+                transaction: Transaction {
+                    instructions: vec![Instruction::CallMethod {
+                        component_address: ComponentAddress([1u8; 26]),
+                        method: "test".to_owned(),
+                        args: vec![scrypto_encode(&scrypto::component::OwnedComponent(
+                            ComponentAddress([2u8; 26])
+                        ))],
+                    }],
+                },
+                signatures: Vec::new()
+            }
+            .validate(),
+            Err(TransactionValidationError::OwnedComponentNotAllowed(
+                ComponentAddress([2u8; 26])
+            )),
+        );
+    }
 }
@@ -1,6 +1,7 @@
 use scrypto::crypto::*;
 use scrypto::engine::types::*;
-use scrypto::rust::collections::{BTreeSet};
+use scrypto::resource::AccessRule;
+use scrypto::rust::collections::{BTreeSet, HashMap};
 use scrypto::rust::string::String;
 use scrypto::rust::vec::Vec;
 use scrypto::values::*;
@@ -82,5 +83,16 @@ pub enum ValidatedInstruction {
     },
     PublishPackage {
         code: Vec<u8>,
+        metadata: HashMap<String, String>,
     },
-}
\ No newline at end of file
+    PublishPackageWithOwner {
+        code: Vec<u8>,
+        metadata: HashMap<String, String>,
+        owner_rule: AccessRule,
+    },
+    UpgradePackage {
+        package_address: PackageAddress,
+        code: Vec<u8>,
+        metadata: HashMap<String, String>,
+    },
+}
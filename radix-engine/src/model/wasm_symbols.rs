@@ -0,0 +1,165 @@
+use sbor::*;
+use scrypto::rust::collections::HashMap;
+use scrypto::rust::string::String;
+use scrypto::rust::string::ToString;
+
+/// Function names of a WASM module, as found in its `name` custom section.
+#[derive(Debug, Clone, Default, TypeId, Encode, Decode)]
+pub struct WasmSymbols {
+    /// Function names by function index
+    functions: HashMap<u32, String>,
+    /// Function indices by export name
+    exports: HashMap<String, u32>,
+}
+
+/// A cursor over WASM binary data.
+struct Reader<'a> {
+    data: &'a [u8],
+    offset: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn new(data: &'a [u8]) -> Self {
+        Self { data, offset: 0 }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.offset >= self.data.len()
+    }
+
+    fn read_u8(&mut self) -> Option<u8> {
+        let byte = *self.data.get(self.offset)?;
+        self.offset += 1;
+        Some(byte)
+    }
+
+    fn read_u32(&mut self) -> Option<u32> {
+        let mut result = 0u32;
+        for shift in (0..35).step_by(7) {
+            let byte = self.read_u8()?;
+            result |= ((byte & 0x7f) as u32).checked_shl(shift)?;
+            if byte & 0x80 == 0 {
+                return Some(result);
+            }
+        }
+        None
+    }
+
+    fn read_bytes(&mut self, len: usize) -> Option<&'a [u8]> {
+        let end = self.offset.checked_add(len)?;
+        let bytes = self.data.get(self.offset..end)?;
+        self.offset = end;
+        Some(bytes)
+    }
+
+    fn read_name(&mut self) -> Option<String> {
+        let len = self.read_u32()? as usize;
+        let bytes = self.read_bytes(len)?;
+        Some(String::from_utf8_lossy(bytes).to_string())
+    }
+}
+
+impl WasmSymbols {
+    /// Reads the function names of a module, skipping anything malformed.
+    ///
+    /// Modules without a `name` section, e.g. stripped ones, have no function names.
+    pub fn parse(code: &[u8]) -> Self {
+        let mut symbols = Self::default();
+        let mut reader = Reader::new(code);
+        if reader.read_bytes(8).is_none() {
+            return symbols;
+        }
+
+        while !reader.is_empty() {
+            let section = reader.read_u8().and_then(|id| {
+                let len = reader.read_u32()? as usize;
+                Some((id, reader.read_bytes(len)?))
+            });
+            match section {
+                Some((0, payload)) => {
+                    symbols.read_custom_section(payload);
+                }
+                Some((7, payload)) => {
+                    symbols.read_export_section(payload);
+                }
+                Some(_) => {}
+                None => break,
+            }
+        }
+        symbols
+    }
+
+    fn read_custom_section(&mut self, payload: &[u8]) -> Option<()> {
+        let mut reader = Reader::new(payload);
+        if reader.read_name()? != "name" {
+            return None;
+        }
+        while !reader.is_empty() {
+            let id = reader.read_u8()?;
+            let len = reader.read_u32()? as usize;
+            let subsection = reader.read_bytes(len)?;
+            // Function names
+            if id == 1 {
+                let mut reader = Reader::new(subsection);
+                for _ in 0..reader.read_u32()? {
+                    let index = reader.read_u32()?;
+                    let name = reader.read_name()?;
+                    self.functions.insert(index, name);
+                }
+            }
+        }
+        Some(())
+    }
+
+    fn read_export_section(&mut self, payload: &[u8]) -> Option<()> {
+        let mut reader = Reader::new(payload);
+        for _ in 0..reader.read_u32()? {
+            let name = reader.read_name()?;
+            let kind = reader.read_u8()?;
+            let index = reader.read_u32()?;
+            // Function exports
+            if kind == 0 {
+                self.exports.insert(name, index);
+            }
+        }
+        Some(())
+    }
+
+    /// Returns the name of a function by index.
+    pub fn function_name(&self, index: u32) -> Option<&str> {
+        self.functions.get(&index).map(String::as_str)
+    }
+
+    /// Returns the name of the function behind an export, falling back to the export name.
+    pub fn export_function_name<'a>(&'a self, export_name: &'a str) -> &'a str {
+        self.exports
+            .get(export_name)
+            .and_then(|index| self.function_name(*index))
+            .unwrap_or(export_name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use scrypto::rust::vec::Vec;
+
+    #[test]
+    fn test_parse_function_names() {
+        let mut code = Vec::new();
+        code.extend(b"\0asm\x01\0\0\0");
+        code.extend([7, 8, 1, 4]);
+        code.extend(b"main");
+        code.extend([0, 0]);
+        code.extend([0, 18, 4]);
+        code.extend(b"name");
+        code.extend([1, 11, 1, 0, 8]);
+        code.extend(b"my::main");
+
+        let symbols = WasmSymbols::parse(&code);
+
+        assert_eq!(symbols.function_name(0), Some("my::main"));
+        assert_eq!(symbols.export_function_name("main"), "my::main");
+        assert_eq!(symbols.export_function_name("other"), "other");
+    }
+}
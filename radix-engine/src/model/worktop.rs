@@ -1,3 +1,4 @@
+use crate::engine::SystemApi;
 use sbor::DecodeError;
 use scrypto::buffer::scrypto_decode;
 use scrypto::engine::types::*;
@@ -5,11 +6,10 @@ use scrypto::rust::cell::{Ref, RefCell, RefMut};
 use scrypto::rust::collections::BTreeSet;
 use scrypto::rust::collections::HashMap;
 use scrypto::rust::rc::Rc;
-use scrypto::rust::vec::Vec;
 use scrypto::rust::string::String;
 use scrypto::rust::string::ToString;
+use scrypto::rust::vec::Vec;
 use scrypto::values::ScryptoValue;
-use crate::engine::SystemApi;
 
 use crate::model::{Bucket, ResourceContainer, ResourceContainerError, ResourceManager};
 
@@ -53,9 +53,7 @@ impl Worktop {
         resource_address: ResourceAddress,
     ) -> Result<Option<ResourceContainer>, ResourceContainerError> {
         if let Some(mut container) = self.borrow_container_mut(resource_address) {
-            container
-                .take_by_amount(amount)
-                .map(Option::Some)
+            container.take_by_amount(amount).map(Option::Some)
         } else if !amount.is_zero() {
             Err(ResourceContainerError::InsufficientBalance)
         } else {
@@ -69,9 +67,7 @@ impl Worktop {
         resource_address: ResourceAddress,
     ) -> Result<Option<ResourceContainer>, ResourceContainerError> {
         if let Some(mut container) = self.borrow_container_mut(resource_address) {
-            container
-                .take_by_ids(ids)
-                .map(Option::Some)
+            container.take_by_ids(ids).map(Option::Some)
         } else if !ids.is_empty() {
             Err(ResourceContainerError::InsufficientBalance)
         } else {
@@ -172,8 +168,8 @@ impl Worktop {
     ) -> Result<ScryptoValue, WorktopError> {
         match function {
             "put" => {
-                let bucket_id: scrypto::resource::Bucket =
-                    scrypto_decode(&args[0].raw).map_err(|e| WorktopError::InvalidRequestData(e))?;
+                let bucket_id: scrypto::resource::Bucket = scrypto_decode(&args[0].raw)
+                    .map_err(|e| WorktopError::InvalidRequestData(e))?;
                 let bucket = system_api
                     .take_bucket(bucket_id.0)
                     .map_err(|_| WorktopError::CouldNotTakeBucket)?;
@@ -182,75 +178,96 @@ impl Worktop {
                 Ok(ScryptoValue::from_value(&()))
             }
             "take_amount" => {
-                let amount: Decimal =
-                    scrypto_decode(&args[0].raw).map_err(|e| WorktopError::InvalidRequestData(e))?;
-                let resource_address: ResourceAddress =
-                    scrypto_decode(&args[1].raw).map_err(|e| WorktopError::InvalidRequestData(e))?;
+                let amount: Decimal = scrypto_decode(&args[0].raw)
+                    .map_err(|e| WorktopError::InvalidRequestData(e))?;
+                let resource_address: ResourceAddress = scrypto_decode(&args[1].raw)
+                    .map_err(|e| WorktopError::InvalidRequestData(e))?;
 
-                let maybe_container = self.take(amount, resource_address)
+                let maybe_container = self
+                    .take(amount, resource_address)
                     .map_err(WorktopError::ResourceContainerError)?;
                 let resource_container = if let Some(container) = maybe_container {
                     container
                 } else {
-                    let resource_manager: ResourceManager = system_api.borrow_global_mut_resource_manager(resource_address)
+                    let resource_manager: ResourceManager = system_api
+                        .borrow_global_mut_resource_manager(resource_address)
                         .map_err(|_| WorktopError::ResourceDoesNotExist(resource_address))?;
                     let resource_type = resource_manager.resource_type();
-                    system_api.return_borrowed_global_resource_manager(resource_address, resource_manager);
+                    system_api.return_borrowed_global_resource_manager(
+                        resource_address,
+                        resource_manager,
+                    );
                     ResourceContainer::new_empty(resource_address, resource_type)
                 };
 
-                let bucket_id = system_api.create_bucket(resource_container).map_err(|_| WorktopError::CouldNotCreateBucket)?;
+                let bucket_id = system_api
+                    .create_bucket(resource_container)
+                    .map_err(|_| WorktopError::CouldNotCreateBucket)?;
                 Ok(ScryptoValue::from_value(&scrypto::resource::Bucket(
                     bucket_id,
                 )))
             }
             "take_all" => {
-                let resource_address: ResourceAddress =
-                    scrypto_decode(&args[0].raw).map_err(|e| WorktopError::InvalidRequestData(e))?;
+                let resource_address: ResourceAddress = scrypto_decode(&args[0].raw)
+                    .map_err(|e| WorktopError::InvalidRequestData(e))?;
 
-                let maybe_container = self.take_all(resource_address)
+                let maybe_container = self
+                    .take_all(resource_address)
                     .map_err(WorktopError::ResourceContainerError)?;
                 let resource_container = if let Some(container) = maybe_container {
                     container
                 } else {
-                    let resource_manager: ResourceManager = system_api.borrow_global_mut_resource_manager(resource_address)
+                    let resource_manager: ResourceManager = system_api
+                        .borrow_global_mut_resource_manager(resource_address)
                         .map_err(|_| WorktopError::ResourceDoesNotExist(resource_address))?;
                     let resource_type = resource_manager.resource_type();
-                    system_api.return_borrowed_global_resource_manager(resource_address, resource_manager);
+                    system_api.return_borrowed_global_resource_manager(
+                        resource_address,
+                        resource_manager,
+                    );
                     ResourceContainer::new_empty(resource_address, resource_type)
                 };
 
-                let bucket_id = system_api.create_bucket(resource_container).map_err(|_| WorktopError::CouldNotCreateBucket)?;
+                let bucket_id = system_api
+                    .create_bucket(resource_container)
+                    .map_err(|_| WorktopError::CouldNotCreateBucket)?;
                 Ok(ScryptoValue::from_value(&scrypto::resource::Bucket(
                     bucket_id,
                 )))
             }
             "take_non_fungibles" => {
-                let non_fungible_ids =
-                    scrypto_decode(&args[0].raw).map_err(|e| WorktopError::InvalidRequestData(e))?;
-                let resource_address: ResourceAddress =
-                    scrypto_decode(&args[1].raw).map_err(|e| WorktopError::InvalidRequestData(e))?;
+                let non_fungible_ids = scrypto_decode(&args[0].raw)
+                    .map_err(|e| WorktopError::InvalidRequestData(e))?;
+                let resource_address: ResourceAddress = scrypto_decode(&args[1].raw)
+                    .map_err(|e| WorktopError::InvalidRequestData(e))?;
 
-                let maybe_container = self.take_non_fungibles(&non_fungible_ids, resource_address)
+                let maybe_container = self
+                    .take_non_fungibles(&non_fungible_ids, resource_address)
                     .map_err(WorktopError::ResourceContainerError)?;
                 let resource_container = if let Some(container) = maybe_container {
                     container
                 } else {
-                    let resource_manager: ResourceManager = system_api.borrow_global_mut_resource_manager(resource_address)
+                    let resource_manager: ResourceManager = system_api
+                        .borrow_global_mut_resource_manager(resource_address)
                         .map_err(|_| WorktopError::ResourceDoesNotExist(resource_address))?;
                     let resource_type = resource_manager.resource_type();
-                    system_api.return_borrowed_global_resource_manager(resource_address, resource_manager);
+                    system_api.return_borrowed_global_resource_manager(
+                        resource_address,
+                        resource_manager,
+                    );
                     ResourceContainer::new_empty(resource_address, resource_type)
                 };
 
-                let bucket_id = system_api.create_bucket(resource_container).map_err(|_| WorktopError::CouldNotCreateBucket)?;
+                let bucket_id = system_api
+                    .create_bucket(resource_container)
+                    .map_err(|_| WorktopError::CouldNotCreateBucket)?;
                 Ok(ScryptoValue::from_value(&scrypto::resource::Bucket(
                     bucket_id,
                 )))
             }
             "assert_contains" => {
-                let resource_address: ResourceAddress =
-                    scrypto_decode(&args[0].raw).map_err(|e| WorktopError::InvalidRequestData(e))?;
+                let resource_address: ResourceAddress = scrypto_decode(&args[0].raw)
+                    .map_err(|e| WorktopError::InvalidRequestData(e))?;
                 if self.total_amount(resource_address).is_zero() {
                     Err(WorktopError::AssertionFailed)
                 } else {
@@ -258,10 +275,10 @@ impl Worktop {
                 }
             }
             "assert_contains_amount" => {
-                let amount: Decimal =
-                    scrypto_decode(&args[0].raw).map_err(|e| WorktopError::InvalidRequestData(e))?;
-                let resource_address =
-                    scrypto_decode(&args[1].raw).map_err(|e| WorktopError::InvalidRequestData(e))?;
+                let amount: Decimal = scrypto_decode(&args[0].raw)
+                    .map_err(|e| WorktopError::InvalidRequestData(e))?;
+                let resource_address = scrypto_decode(&args[1].raw)
+                    .map_err(|e| WorktopError::InvalidRequestData(e))?;
 
                 if self.total_amount(resource_address) < amount {
                     Err(WorktopError::AssertionFailed)
@@ -270,10 +287,10 @@ impl Worktop {
                 }
             }
             "assert_contains_non_fungibles" => {
-                let ids =
-                    scrypto_decode(&args[0].raw).map_err(|e| WorktopError::InvalidRequestData(e))?;
-                let resource_address =
-                    scrypto_decode(&args[1].raw).map_err(|e| WorktopError::InvalidRequestData(e))?;
+                let ids = scrypto_decode(&args[0].raw)
+                    .map_err(|e| WorktopError::InvalidRequestData(e))?;
+                let resource_address = scrypto_decode(&args[1].raw)
+                    .map_err(|e| WorktopError::InvalidRequestData(e))?;
 
                 if !self
                     .total_ids(resource_address)
@@ -288,15 +305,20 @@ impl Worktop {
             "drain" => {
                 let mut buckets = Vec::new();
                 for (_, container) in self.containers.drain() {
-                    let container = container.borrow_mut().take_all_liquid().map_err(WorktopError::ResourceContainerError)?;
+                    let container = container
+                        .borrow_mut()
+                        .take_all_liquid()
+                        .map_err(WorktopError::ResourceContainerError)?;
                     if !container.is_empty() {
-                        let bucket_id = system_api.create_bucket(container).map_err(|_| WorktopError::CouldNotCreateBucket)?;
+                        let bucket_id = system_api
+                            .create_bucket(container)
+                            .map_err(|_| WorktopError::CouldNotCreateBucket)?;
                         buckets.push(scrypto::resource::Bucket(bucket_id));
                     }
                 }
                 Ok(ScryptoValue::from_value(&buckets))
             }
-            _ => Err(WorktopError::MethodNotFound(function.to_string()))
+            _ => Err(WorktopError::MethodNotFound(function.to_string())),
         }
     }
 }
@@ -1,13 +1,9 @@
-use sbor::Type;
 use scrypto::abi;
-use scrypto::buffer::*;
 use scrypto::crypto::hash;
 use scrypto::engine::types::*;
 use scrypto::rust::borrow::ToOwned;
 use scrypto::rust::string::ToString;
-use scrypto::rust::vec::Vec;
 
-use crate::engine::*;
 use crate::errors::*;
 use crate::ledger::*;
 use crate::model::*;
@@ -31,55 +27,81 @@ pub trait AbiProvider {
 /// Provides ABIs for blueprints either installed during bootstrap or added manually.
 pub struct BasicAbiProvider {
     substate_store: InMemorySubstateStore,
-    trace: bool,
+}
+
+impl Default for BasicAbiProvider {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl BasicAbiProvider {
-    pub fn new(trace: bool) -> Self {
+    pub fn new() -> Self {
         Self {
             substate_store: InMemorySubstateStore::with_bootstrap(),
-            trace,
         }
     }
 
     pub fn with_package(
         &mut self,
         package_address: &PackageAddress,
-        package: Package,
+        package_version: PackageVersion,
     ) -> &mut Self {
         let tx_hash = hash(self.substate_store.get_and_increase_nonce().to_le_bytes());
         let mut id_gen = SubstateIdGenerator::new(tx_hash);
 
-        self.substate_store
-            .put_encoded_substate(package_address, &package, id_gen.next());
+        put_package(
+            &mut self.substate_store,
+            package_address,
+            &package_version,
+            &mut id_gen,
+        );
         self
     }
 }
 
+/// Exports the ABI of a blueprint, from the given version of a package.
+pub(crate) fn export_package_abi<S: SubstateStore>(
+    substate_store: &S,
+    package_address: PackageAddress,
+    package_version: u32,
+    blueprint_name: &str,
+) -> Result<abi::Blueprint, RuntimeError> {
+    let package_version: PackageVersion = substate_store
+        .get_decoded_child_substate(&package_address, &package_version)
+        .map(|(package_version, _)| package_version)
+        .ok_or(RuntimeError::PackageError(PackageError::VersionNotFound(
+            package_version,
+        )))?;
+    let blueprint_abi = package_version
+        .load_blueprint_abi(blueprint_name)
+        .map_err(|_| RuntimeError::BlueprintNotFound(package_address, blueprint_name.to_owned()))?;
+
+    Ok(abi::Blueprint {
+        package_address: package_address.to_string(),
+        blueprint_name: blueprint_name.to_owned(),
+        functions: blueprint_abi.functions.clone(),
+        methods: blueprint_abi.methods.clone(),
+    })
+}
+
 impl AbiProvider for BasicAbiProvider {
     fn export_abi(
         &self,
         package_address: PackageAddress,
         blueprint_name: &str,
     ) -> Result<abi::Blueprint, RuntimeError> {
-        // Deterministic transaction context
-        let mut ledger = self.substate_store.clone();
-        let transaction_hash = hash([]);
-
-        // Start a process and run abi generator
-        let mut track = Track::new(&mut ledger, transaction_hash, Vec::new());
-        let mut proc = track.start_process(self.trace);
-        let output: (Type, Vec<abi::Function>, Vec<abi::Method>) = proc
-            .call_abi(package_address, blueprint_name)
-            .and_then(|rtn| scrypto_decode(&rtn.raw).map_err(RuntimeError::AbiValidationError))?;
-
-        // Return ABI
-        Ok(abi::Blueprint {
-            package_address: package_address.to_string(),
-            blueprint_name: blueprint_name.to_owned(),
-            functions: output.1,
-            methods: output.2,
-        })
+        let package: Package = self
+            .substate_store
+            .get_decoded_substate(&package_address)
+            .map(|(package, _)| package)
+            .ok_or(RuntimeError::PackageNotFound(package_address))?;
+        export_package_abi(
+            &self.substate_store,
+            package_address,
+            package.latest_version(),
+            blueprint_name,
+        )
     }
 
     fn export_abi_by_component(
@@ -91,6 +113,11 @@ impl AbiProvider for BasicAbiProvider {
             .get_decoded_substate(&component_address)
             .map(|(component, _)| component)
             .ok_or(RuntimeError::ComponentNotFound(component_address))?;
-        self.export_abi(component.package_address(), component.blueprint_name())
+        export_package_abi(
+            &self.substate_store,
+            component.package_address(),
+            component.package_version(),
+            component.blueprint_name(),
+        )
     }
 }
@@ -3,7 +3,7 @@ use sbor::*;
 use scrypto::buffer::*;
 use scrypto::crypto::*;
 use scrypto::engine::types::*;
-use scrypto::prelude::{AccessRuleNode, Burn, AccessRule, Mint, Withdraw};
+use scrypto::prelude::{AccessRule, AccessRuleNode, Burn, Mint, Withdraw};
 use scrypto::resource::{require, LOCKED};
 use scrypto::rust::borrow::ToOwned;
 use scrypto::rust::collections::BTreeSet;
@@ -101,7 +101,10 @@ impl TransactionBuilder {
             Instruction::CallMethodWithAllResources { .. } => {
                 self.id_validator.move_all_resources().unwrap();
             }
-            Instruction::PublishPackage { .. } | Instruction::Nonce { .. } => {}
+            Instruction::PublishPackage { .. }
+            | Instruction::PublishPackageWithOwner { .. }
+            | Instruction::UpgradePackage { .. }
+            | Instruction::Nonce { .. } => {}
         }
 
         self.instructions.push(inst);
@@ -369,16 +372,7 @@ impl TransactionBuilder {
         account: Option<ComponentAddress>,
         blueprint_abi: &abi::Blueprint,
     ) -> Result<&mut Self, CallWithAbiError> {
-        let abi = blueprint_abi
-            .methods
-            .iter()
-            .find(|m| m.name == method)
-            .map(Clone::clone)
-            .ok_or_else(|| CallWithAbiError::MethodNotFound(method.to_owned()))?;
-
-        let arguments = self
-            .parse_args(&abi.inputs, args, account)
-            .map_err(|e| CallWithAbiError::FailedToBuildArgs(e))?;
+        let arguments = self.parse_method_args(method, args, account, blueprint_abi)?;
 
         Ok(self
             .add_instruction(Instruction::CallMethod {
@@ -389,6 +383,27 @@ impl TransactionBuilder {
             .0)
     }
 
+    /// Parses the arguments of a method, in string format, based on its ABI.
+    ///
+    /// Resources needed by the arguments are taken from the given account.
+    pub fn parse_method_args(
+        &mut self,
+        method: &str,
+        args: Vec<String>,
+        account: Option<ComponentAddress>,
+        blueprint_abi: &abi::Blueprint,
+    ) -> Result<Vec<Vec<u8>>, CallWithAbiError> {
+        let abi = blueprint_abi
+            .methods
+            .iter()
+            .find(|m| m.name == method)
+            .map(Clone::clone)
+            .ok_or_else(|| CallWithAbiError::MethodNotFound(method.to_owned()))?;
+
+        self.parse_args(&abi.inputs, args, account)
+            .map_err(|e| CallWithAbiError::FailedToBuildArgs(e))
+    }
+
     /// Calls a method with all the resources on worktop.
     ///
     /// The callee method must have only one parameter with type `Vec<Bucket>`; otherwise,
@@ -407,8 +422,48 @@ impl TransactionBuilder {
 
     /// Publishes a package.
     pub fn publish_package(&mut self, code: &[u8]) -> &mut Self {
+        self.publish_package_with_metadata(code, HashMap::new())
+    }
+
+    /// Publishes a package, along with metadata such as its name and version.
+    pub fn publish_package_with_metadata(
+        &mut self,
+        code: &[u8],
+        metadata: HashMap<String, String>,
+    ) -> &mut Self {
         self.add_instruction(Instruction::PublishPackage {
             code: code.to_vec(),
+            metadata,
+        })
+        .0
+    }
+
+    /// Publishes a package, which can be upgraded by the owner.
+    pub fn publish_package_with_owner(
+        &mut self,
+        code: &[u8],
+        metadata: HashMap<String, String>,
+        owner_rule: AccessRule,
+    ) -> &mut Self {
+        self.add_instruction(Instruction::PublishPackageWithOwner {
+            code: code.to_vec(),
+            metadata,
+            owner_rule,
+        })
+        .0
+    }
+
+    /// Publishes new code as the latest version of a package.
+    pub fn upgrade_package(
+        &mut self,
+        package_address: PackageAddress,
+        code: &[u8],
+        metadata: HashMap<String, String>,
+    ) -> &mut Self {
+        self.add_instruction(Instruction::UpgradePackage {
+            package_address,
+            code: code.to_vec(),
+            metadata,
         })
         .0
     }
@@ -1,21 +1,27 @@
+use scrypto::core::{SNodeRef, ScryptoActor};
 use scrypto::crypto::hash;
 use scrypto::engine::types::*;
 use scrypto::resource::*;
+use scrypto::rust::collections::HashMap;
+use scrypto::rust::string::String;
+use scrypto::rust::string::ToString;
 use scrypto::rust::vec;
 use scrypto::rust::vec::Vec;
-use scrypto::rust::string::ToString;
-use scrypto::{abi, rule, access_rule_node};
+use scrypto::values::ScryptoValue;
+use scrypto::{abi, access_rule_node, rule};
 
 use crate::engine::*;
 use crate::errors::*;
 use crate::ledger::*;
 use crate::model::*;
+use crate::transaction::abi_provider::export_package_abi;
 use crate::transaction::*;
 
 /// An executor that runs transactions.
 pub struct TransactionExecutor<'l, L: SubstateStore> {
     substate_store: &'l mut L,
     trace: bool,
+    pass_signature_proofs: bool,
 }
 
 impl<'l, L: SubstateStore> NonceProvider for TransactionExecutor<'l, L> {
@@ -35,10 +41,12 @@ impl<'l, L: SubstateStore> AbiProvider for TransactionExecutor<'l, L> {
             .get_decoded_substate(&package_address)
             .map(|(package, _)| package)
             .ok_or(RuntimeError::PackageNotFound(package_address))?;
-
-        BasicAbiProvider::new(self.trace)
-            .with_package(&package_address, package)
-            .export_abi(package_address, blueprint_name)
+        export_package_abi(
+            &*self.substate_store,
+            package_address,
+            package.latest_version(),
+            blueprint_name,
+        )
     }
 
     fn export_abi_by_component(
@@ -50,14 +58,12 @@ impl<'l, L: SubstateStore> AbiProvider for TransactionExecutor<'l, L> {
             .get_decoded_substate(&component_address)
             .map(|(component, _)| component)
             .ok_or(RuntimeError::ComponentNotFound(component_address))?;
-        let package: Package = self
-            .substate_store
-            .get_decoded_substate(&component.package_address())
-            .map(|(package, _)| package)
-            .unwrap();
-        BasicAbiProvider::new(self.trace)
-            .with_package(&component.package_address(), package)
-            .export_abi(component.package_address(), component.blueprint_name())
+        export_package_abi(
+            &*self.substate_store,
+            component.package_address(),
+            component.package_version(),
+            component.blueprint_name(),
+        )
     }
 }
 
@@ -66,9 +72,19 @@ impl<'l, L: SubstateStore> TransactionExecutor<'l, L> {
         Self {
             substate_store,
             trace,
+            pass_signature_proofs: false,
         }
     }
 
+    /// Sets whether signature proofs authorize the methods blueprints call, rather than only
+    /// those called by the transaction.
+    ///
+    /// A blueprint presents the signature proofs it can see to the method check of the blueprint
+    /// it calls, but they're not passed on any further.
+    pub fn set_pass_signature_proofs(&mut self, pass_signature_proofs: bool) {
+        self.pass_signature_proofs = pass_signature_proofs;
+    }
+
     /// Returns an immutable reference to the ledger.
     pub fn substate_store(&self) -> &L {
         self.substate_store
@@ -121,11 +137,20 @@ impl<'l, L: SubstateStore> TransactionExecutor<'l, L> {
     pub fn publish_package<T: AsRef<[u8]>>(
         &mut self,
         code: T,
+    ) -> Result<PackageAddress, RuntimeError> {
+        self.publish_package_with_metadata(code, HashMap::new())
+    }
+
+    /// Publishes a package, along with metadata such as its name and version.
+    pub fn publish_package_with_metadata<T: AsRef<[u8]>>(
+        &mut self,
+        code: T,
+        metadata: HashMap<String, String>,
     ) -> Result<PackageAddress, RuntimeError> {
         let receipt = self
             .validate_and_execute(
                 &TransactionBuilder::new()
-                    .publish_package(code.as_ref())
+                    .publish_package_with_metadata(code.as_ref(), metadata)
                     .build(self.get_nonce([]))
                     .sign([]),
             )
@@ -143,16 +168,56 @@ impl<'l, L: SubstateStore> TransactionExecutor<'l, L> {
         &mut self,
         package_address: PackageAddress,
         code: Vec<u8>,
+        metadata: HashMap<String, String>,
     ) -> Result<(), WasmValidationError> {
         let tx_hash = hash(self.substate_store.get_and_increase_nonce().to_le_bytes());
         let mut id_gen = SubstateIdGenerator::new(tx_hash);
 
-        let package = Package::new(code)?;
-        self.substate_store
-            .put_encoded_substate(&package_address, &package, id_gen.next());
+        let package_version = PackageVersion::new(code, metadata)?;
+        put_package(
+            self.substate_store,
+            &package_address,
+            &package_version,
+            &mut id_gen,
+        );
         Ok(())
     }
 
+    /// Calls a read-only method of a component, without a transaction.
+    ///
+    /// The method runs on a throw-away track with no auth zone and no worktop, and
+    /// nothing is committed. Any call it makes to a method which may write component
+    /// state fails.
+    pub fn query(
+        &mut self,
+        component_address: ComponentAddress,
+        method: &str,
+        args: Vec<Vec<u8>>,
+    ) -> Result<ScryptoValue, RuntimeError> {
+        let args = args
+            .iter()
+            .map(|arg| ScryptoValue::from_slice(arg).map_err(RuntimeError::ParseScryptoValueError))
+            .collect::<Result<Vec<ScryptoValue>, RuntimeError>>()?;
+
+        let tx_hash = hash(self.substate_store.get_nonce().to_le_bytes());
+        let mut track = Track::new(self.substate_store, tx_hash, Vec::new());
+        let mut proc = Process::new(
+            0,
+            self.trace,
+            &mut track,
+            None,
+            None,
+            HashMap::new(),
+            HashMap::new(),
+        );
+        proc.set_read_only(true);
+        proc.invoke_snode(
+            SNodeRef::Scrypto(ScryptoActor::Component(component_address)),
+            method.to_string(),
+            args,
+        )
+    }
+
     pub fn validate_and_execute(
         &mut self,
         signed: &SignedTransaction,
@@ -172,6 +237,7 @@ impl<'l, L: SubstateStore> TransactionExecutor<'l, L> {
             validated.signers.clone(),
         );
         let mut proc = track.start_process(self.trace);
+        proc.set_pass_signature_proofs(self.pass_signature_proofs);
 
         let txn_process = TransactionProcess::new(validated.clone());
         let mut txn_snode = SNodeState::Transaction(txn_process);
@@ -200,8 +266,6 @@ impl<'l, L: SubstateStore> TransactionExecutor<'l, L> {
             None
         };
 
-
-
         #[cfg(feature = "alloc")]
         let execution_time = None;
         #[cfg(not(feature = "alloc"))]
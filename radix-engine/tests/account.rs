@@ -122,3 +122,42 @@ fn test_account_balance() {
         ScryptoValue::from_value(&Decimal::from(1000000))
     );
 }
+
+#[test]
+fn query_account_balance_should_not_need_transaction() {
+    // Arrange
+    let mut substate_store = InMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(&mut substate_store);
+    let (_, _, account) = test_runner.new_account();
+    let nonce = test_runner.get_nonce([]);
+
+    // Act
+    let result = test_runner.query(account, "balance", args![RADIX_TOKEN]);
+
+    // Assert
+    assert_eq!(
+        result,
+        Ok(ScryptoValue::from_value(&Decimal::from(1000000)))
+    );
+    assert_eq!(test_runner.get_nonce([]), nonce);
+}
+
+#[test]
+fn query_mutable_method_should_fail() {
+    // Arrange
+    let mut substate_store = InMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(&mut substate_store);
+    let (_, _, account) = test_runner.new_account();
+
+    // Act
+    let result = test_runner.query(account, "withdraw", args![RADIX_TOKEN]);
+
+    // Assert
+    assert_eq!(
+        result,
+        Err(RuntimeError::MethodNotReadOnly(
+            account,
+            "withdraw".to_string()
+        ))
+    );
+}
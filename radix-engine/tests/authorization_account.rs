@@ -6,6 +6,7 @@ pub mod test_runner;
 use crate::test_runner::TestRunner;
 use radix_engine::errors::RuntimeError;
 use radix_engine::ledger::InMemorySubstateStore;
+use radix_engine::model::{AuthRequirement, HardResourceOrNonFungible, MethodAuthorizationError};
 use scrypto::prelude::*;
 
 fn test_auth_rule(
@@ -328,3 +329,60 @@ fn cannot_withdraw_from_my_any_xrd_auth_account_with_less_than_amount_of_proof()
     let error = receipt.result.expect_err("Should be an error");
     assert_auth_error!(error);
 }
+
+#[test]
+fn failed_withdraw_should_explain_missing_signature() {
+    // Arrange
+    let mut substate_store = InMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(&mut substate_store);
+    let (pk0, sk0, auth0) = test_runner.new_key_pair_with_pk_address();
+    let (_, _, auth1) = test_runner.new_key_pair_with_pk_address();
+    let account = test_runner
+        .new_account_with_auth_rule(&rule!(require(auth0.clone()) && require(auth1.clone())));
+    let (_, _, other_account) = test_runner.new_account();
+
+    // Act
+    let transaction = test_runner
+        .new_transaction_builder()
+        .withdraw_from_account(RADIX_TOKEN, account)
+        .call_method_with_all_resources(other_account, "deposit_batch")
+        .build(test_runner.get_nonce([pk0]))
+        .sign([&sk0]);
+    let receipt = test_runner.validate_and_execute(&transaction);
+
+    // Assert
+    let error = receipt.result.expect_err("Should be an error");
+    let failure = match error {
+        RuntimeError::AuthorizationError {
+            error: MethodAuthorizationError::NotAuthorized(failure),
+            ..
+        } => failure,
+        _ => panic!("Expected auth error but got: {:?}", error),
+    };
+    let explanation = failure.explanation;
+    assert_eq!(explanation.requirement, AuthRequirement::AllOf);
+    assert!(!explanation.passed);
+    assert_eq!(
+        explanation
+            .sub_rules
+            .iter()
+            .map(|r| (r.requirement.clone(), r.passed))
+            .collect::<Vec<(AuthRequirement, bool)>>(),
+        vec![
+            (
+                AuthRequirement::Require(HardResourceOrNonFungible::NonFungible(auth0.clone())),
+                true
+            ),
+            (
+                AuthRequirement::Require(HardResourceOrNonFungible::NonFungible(auth1)),
+                false
+            ),
+        ]
+    );
+    assert_eq!(failure.held_proofs.len(), 1);
+    assert_eq!(failure.held_proofs[0].resource_address, ECDSA_TOKEN);
+    assert_eq!(
+        failure.held_proofs[0].ids,
+        Some(BTreeSet::from([auth0.non_fungible_id()]))
+    );
+}
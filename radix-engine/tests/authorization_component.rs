@@ -129,3 +129,442 @@ fn can_make_cross_component_call_with_authorization() {
     // Assert
     receipt.result.expect("Should be okay");
 }
+
+#[test]
+fn can_make_cross_component_call_with_package_badge() {
+    // Arrange
+    let mut substate_store = InMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(&mut substate_store);
+    let package_address = test_runner.publish_package("component");
+    let authorization = AccessRules::new().method(
+        "get_component_state",
+        rule!(require(package_badge(package_address))),
+    );
+
+    let transaction = test_runner
+        .new_transaction_builder()
+        .call_function(
+            package_address,
+            "CrossComponent",
+            "create_component_with_auth",
+            vec![scrypto_encode(&authorization)],
+        )
+        .build(test_runner.get_nonce([]))
+        .sign([]);
+    let receipt = test_runner.validate_and_execute(&transaction);
+    receipt.result.expect("Should be okay");
+    let secured_component = receipt.new_component_addresses[0];
+
+    let transaction = test_runner
+        .new_transaction_builder()
+        .call_function(
+            package_address,
+            "CrossComponent",
+            "create_component",
+            vec![],
+        )
+        .build(test_runner.get_nonce([]))
+        .sign([]);
+    let receipt = test_runner.validate_and_execute(&transaction);
+    assert!(receipt.result.is_ok());
+    let my_component = receipt.new_component_addresses[0];
+
+    // Act
+    let transaction = test_runner
+        .new_transaction_builder()
+        .call_method(
+            my_component,
+            "cross_component_call",
+            vec![scrypto_encode(&secured_component)],
+        )
+        .build(test_runner.get_nonce([]))
+        .sign([]);
+    let receipt = test_runner.validate_and_execute(&transaction);
+
+    // Assert
+    receipt.result.expect("Should be okay");
+}
+
+#[test]
+fn called_blueprint_cannot_use_package_badge_of_caller() {
+    // Arrange
+    let mut substate_store = InMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(&mut substate_store);
+    let package_address = test_runner.publish_package("component");
+    let untrusted_package_address = test_runner.publish_package("component");
+    let authorization = AccessRules::new().method(
+        "get_component_state",
+        rule!(require(package_badge(package_address))),
+    );
+
+    let transaction = test_runner
+        .new_transaction_builder()
+        .call_function(
+            package_address,
+            "CrossComponent",
+            "create_component_with_auth",
+            vec![scrypto_encode(&authorization)],
+        )
+        .call_function(
+            package_address,
+            "CrossComponent",
+            "create_component",
+            vec![],
+        )
+        .call_function(
+            untrusted_package_address,
+            "CrossComponent",
+            "create_component",
+            vec![],
+        )
+        .build(test_runner.get_nonce([]))
+        .sign([]);
+    let receipt = test_runner.validate_and_execute(&transaction);
+    receipt.result.expect("Should be okay");
+    let secured_component = receipt.new_component_addresses[0];
+    let my_component = receipt.new_component_addresses[1];
+    let untrusted_component = receipt.new_component_addresses[2];
+
+    // Act
+    let transaction = test_runner
+        .new_transaction_builder()
+        .call_method(
+            my_component,
+            "relayed_cross_component_call",
+            args![untrusted_component, secured_component],
+        )
+        .build(test_runner.get_nonce([]))
+        .sign([]);
+    let receipt = test_runner.validate_and_execute(&transaction);
+
+    // Assert
+    let error = receipt.result.expect_err("Should be error");
+    assert_auth_error!(error);
+}
+
+#[test]
+fn cannot_call_method_requiring_component_badge_from_transaction() {
+    // Arrange
+    let mut substate_store = InMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(&mut substate_store);
+    let package_address = test_runner.publish_package("component");
+
+    let transaction = test_runner
+        .new_transaction_builder()
+        .call_function(
+            package_address,
+            "CrossComponent",
+            "create_component",
+            vec![],
+        )
+        .build(test_runner.get_nonce([]))
+        .sign([]);
+    let receipt = test_runner.validate_and_execute(&transaction);
+    assert!(receipt.result.is_ok());
+    let my_component = receipt.new_component_addresses[0];
+
+    let authorization = AccessRules::new().method(
+        "get_component_state",
+        rule!(require(component_badge(my_component))),
+    );
+    let transaction = test_runner
+        .new_transaction_builder()
+        .call_function(
+            package_address,
+            "CrossComponent",
+            "create_component_with_auth",
+            vec![scrypto_encode(&authorization)],
+        )
+        .build(test_runner.get_nonce([]))
+        .sign([]);
+    let receipt = test_runner.validate_and_execute(&transaction);
+    receipt.result.expect("Should be okay");
+    let secured_component = receipt.new_component_addresses[0];
+
+    // Act
+    let transaction = test_runner
+        .new_transaction_builder()
+        .call_method(secured_component, "get_component_state", vec![])
+        .build(test_runner.get_nonce([]))
+        .sign([]);
+    let receipt = test_runner.validate_and_execute(&transaction);
+
+    // Assert
+    let error = receipt.result.expect_err("Should be error");
+    assert_auth_error!(error);
+}
+
+fn test_cross_component_call_with_signature(
+    pass_signature_proofs: bool,
+    relayed: bool,
+    should_succeed: bool,
+) {
+    // Arrange
+    let mut substate_store = InMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(&mut substate_store);
+    test_runner.set_pass_signature_proofs(pass_signature_proofs);
+    let (pk, sk, auth_address) = test_runner.new_key_pair_with_pk_address();
+    let authorization =
+        AccessRules::new().method("get_component_state", rule!(require(auth_address)));
+
+    let package_address = test_runner.publish_package("component");
+    let transaction = test_runner
+        .new_transaction_builder()
+        .call_function(
+            package_address,
+            "CrossComponent",
+            "create_component_with_auth",
+            vec![scrypto_encode(&authorization)],
+        )
+        .call_function(
+            package_address,
+            "CrossComponent",
+            "create_component",
+            vec![],
+        )
+        .call_function(
+            package_address,
+            "CrossComponent",
+            "create_component",
+            vec![],
+        )
+        .build(test_runner.get_nonce([]))
+        .sign([]);
+    let receipt = test_runner.validate_and_execute(&transaction);
+    receipt.result.expect("Should be okay");
+    let secured_component = receipt.new_component_addresses[0];
+    let my_component = receipt.new_component_addresses[1];
+    let relay_component = receipt.new_component_addresses[2];
+
+    // Act
+    let builder = test_runner.new_transaction_builder();
+    let builder = if relayed {
+        builder.call_method(
+            my_component,
+            "relayed_cross_component_call",
+            args![relay_component, secured_component],
+        )
+    } else {
+        builder.call_method(
+            my_component,
+            "cross_component_call",
+            args![secured_component],
+        )
+    };
+    let transaction = builder.build(test_runner.get_nonce([pk])).sign([&sk]);
+    let receipt = test_runner.validate_and_execute(&transaction);
+
+    // Assert
+    if should_succeed {
+        receipt.result.expect("Should be okay");
+    } else {
+        let error = receipt.result.expect_err("Should be error");
+        assert_auth_error!(error);
+    }
+}
+
+#[test]
+fn cannot_make_cross_component_call_with_signature_by_default() {
+    test_cross_component_call_with_signature(false, false, false);
+}
+
+#[test]
+fn can_make_cross_component_call_with_signature_if_passed_down() {
+    test_cross_component_call_with_signature(true, false, true);
+}
+
+#[test]
+fn cannot_make_relayed_cross_component_call_with_signature_passed_down() {
+    test_cross_component_call_with_signature(true, true, false);
+}
+
+#[test]
+fn can_update_method_auth_with_mutability_authorization() {
+    // Arrange
+    let mut substate_store = InMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(&mut substate_store);
+    let (key, sk, account) = test_runner.new_account();
+    let admin_badge = test_runner.create_fungible_resource(1.into(), 0, account);
+    let old_user_badge = test_runner.create_fungible_resource(1.into(), 0, account);
+    let new_user_badge = test_runner.create_fungible_resource(1.into(), 0, account);
+    let package_address = test_runner.publish_package("component");
+    let transaction = test_runner
+        .new_transaction_builder()
+        .call_function(
+            package_address,
+            "UpdatableAuthComponent",
+            "create_component",
+            args![admin_badge, old_user_badge],
+        )
+        .build(test_runner.get_nonce([]))
+        .sign([]);
+    let receipt = test_runner.validate_and_execute(&transaction);
+    let component_address = receipt.new_component_addresses[0];
+
+    // Act
+    let transaction = test_runner
+        .new_transaction_builder()
+        .create_proof_from_account(admin_badge, account)
+        .call_method(component_address, "set_secret_auth", args![new_user_badge])
+        .build(test_runner.get_nonce([key]))
+        .sign([&sk]);
+    let receipt = test_runner.validate_and_execute(&transaction);
+    receipt.result.expect("Should be okay");
+
+    // Assert
+    let transaction = test_runner
+        .new_transaction_builder()
+        .create_proof_from_account(old_user_badge, account)
+        .call_method(component_address, "get_secret", args![])
+        .build(test_runner.get_nonce([key]))
+        .sign([&sk]);
+    let receipt = test_runner.validate_and_execute(&transaction);
+    let error = receipt.result.expect_err("Should be error");
+    assert_auth_error!(error);
+
+    let transaction = test_runner
+        .new_transaction_builder()
+        .create_proof_from_account(new_user_badge, account)
+        .call_method(component_address, "get_secret", args![])
+        .build(test_runner.get_nonce([key]))
+        .sign([&sk]);
+    let receipt = test_runner.validate_and_execute(&transaction);
+    receipt.result.expect("Should be okay");
+}
+
+#[test]
+fn cannot_update_method_auth_without_mutability_authorization() {
+    // Arrange
+    let mut substate_store = InMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(&mut substate_store);
+    let (_, _, account) = test_runner.new_account();
+    let admin_badge = test_runner.create_fungible_resource(1.into(), 0, account);
+    let user_badge = test_runner.create_fungible_resource(1.into(), 0, account);
+    let package_address = test_runner.publish_package("component");
+    let transaction = test_runner
+        .new_transaction_builder()
+        .call_function(
+            package_address,
+            "UpdatableAuthComponent",
+            "create_component",
+            args![admin_badge, user_badge],
+        )
+        .build(test_runner.get_nonce([]))
+        .sign([]);
+    let receipt = test_runner.validate_and_execute(&transaction);
+    let component_address = receipt.new_component_addresses[0];
+
+    // Act
+    let transaction = test_runner
+        .new_transaction_builder()
+        .call_method(component_address, "set_secret_auth", args![admin_badge])
+        .build(test_runner.get_nonce([]))
+        .sign([]);
+    let receipt = test_runner.validate_and_execute(&transaction);
+
+    // Assert
+    let error = receipt.result.expect_err("Should be error");
+    assert_auth_error!(error);
+}
+
+#[test]
+fn cannot_update_method_auth_once_locked() {
+    // Arrange
+    let mut substate_store = InMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(&mut substate_store);
+    let (key, sk, account) = test_runner.new_account();
+    let admin_badge = test_runner.create_fungible_resource(1.into(), 0, account);
+    let user_badge = test_runner.create_fungible_resource(1.into(), 0, account);
+    let package_address = test_runner.publish_package("component");
+    let transaction = test_runner
+        .new_transaction_builder()
+        .call_function(
+            package_address,
+            "UpdatableAuthComponent",
+            "create_component",
+            args![admin_badge, user_badge],
+        )
+        .build(test_runner.get_nonce([]))
+        .sign([]);
+    let receipt = test_runner.validate_and_execute(&transaction);
+    let component_address = receipt.new_component_addresses[0];
+    let transaction = test_runner
+        .new_transaction_builder()
+        .create_proof_from_account(admin_badge, account)
+        .call_method(component_address, "lock_secret_auth", args![])
+        .build(test_runner.get_nonce([key]))
+        .sign([&sk]);
+    let receipt = test_runner.validate_and_execute(&transaction);
+    receipt.result.expect("Should be okay");
+
+    // Act
+    let transaction = test_runner
+        .new_transaction_builder()
+        .create_proof_from_account(admin_badge, account)
+        .call_method(component_address, "set_secret_auth", args![admin_badge])
+        .build(test_runner.get_nonce([key]))
+        .sign([&sk]);
+    let receipt = test_runner.validate_and_execute(&transaction);
+
+    // Assert
+    let error = receipt.result.expect_err("Should be error");
+    assert_auth_error!(error);
+}
+
+#[test]
+fn method_auth_attributes_should_be_enforced() {
+    // Arrange
+    let mut substate_store = InMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(&mut substate_store);
+    let (key, sk, account) = test_runner.new_account();
+    let admin_badge = test_runner.create_fungible_resource(1.into(), 0, account);
+    let package_address = test_runner.publish_package("component");
+    let transaction = test_runner
+        .new_transaction_builder()
+        .call_function(
+            package_address,
+            "AttributeAuthComponent",
+            "create_component",
+            args![admin_badge],
+        )
+        .build(test_runner.get_nonce([]))
+        .sign([]);
+    let receipt = test_runner.validate_and_execute(&transaction);
+    let component_address = receipt.new_component_addresses[0];
+
+    // Act
+    let transaction1 = test_runner
+        .new_transaction_builder()
+        .call_method(component_address, "get_secret", args![])
+        .build(test_runner.get_nonce([]))
+        .sign([]);
+    let receipt1 = test_runner.validate_and_execute(&transaction1);
+    let transaction2 = test_runner
+        .new_transaction_builder()
+        .create_proof_from_account(admin_badge, account)
+        .call_method(component_address, "get_secret", args![])
+        .build(test_runner.get_nonce([key]))
+        .sign([&sk]);
+    let receipt2 = test_runner.validate_and_execute(&transaction2);
+    let transaction3 = test_runner
+        .new_transaction_builder()
+        .create_proof_from_account(admin_badge, account)
+        .call_method(component_address, "get_hidden_secret", args![])
+        .build(test_runner.get_nonce([key]))
+        .sign([&sk]);
+    let receipt3 = test_runner.validate_and_execute(&transaction3);
+    let transaction4 = test_runner
+        .new_transaction_builder()
+        .call_method(component_address, "get_public_secret", args![])
+        .build(test_runner.get_nonce([]))
+        .sign([]);
+    let receipt4 = test_runner.validate_and_execute(&transaction4);
+
+    // Assert
+    let error = receipt1.result.expect_err("Should be error");
+    assert_auth_error!(error);
+    receipt2.result.expect("Should be okay");
+    let error = receipt3.result.expect_err("Should be error");
+    assert_auth_error!(error);
+    receipt4.result.expect("Should be okay");
+}
@@ -0,0 +1,28 @@
+use scrypto::prelude::*;
+
+blueprint! {
+    #[auth(get_public_secret, rule!(allow_all))]
+    struct AttributeAuthComponent {
+        admin_badge: ResourceAddress,
+    }
+
+    impl AttributeAuthComponent {
+        pub fn create_component(admin_badge: ResourceAddress) -> ComponentAddress {
+            Self { admin_badge }.instantiate().globalize()
+        }
+
+        #[restrict_to(admin_badge)]
+        pub fn get_secret(&self) -> String {
+            "Secret".to_owned()
+        }
+
+        #[auth(rule!(deny_all))]
+        pub fn get_hidden_secret(&self) -> String {
+            "Hidden secret".to_owned()
+        }
+
+        pub fn get_public_secret(&self) -> String {
+            "Public secret".to_owned()
+        }
+    }
+}
@@ -1,3 +1,5 @@
+use scrypto::engine::api::*;
+use scrypto::engine::call_engine;
 use scrypto::prelude::*;
 
 blueprint! {
@@ -43,5 +45,29 @@ blueprint! {
 
             bucket
         }
+
+        pub fn create_component_with_invalid_state() -> ComponentAddress {
+            let input = CreateComponentInput {
+                blueprint_name: "ComponentTest".to_owned(),
+                state: scrypto_encode(&"Not a ComponentTest".to_owned()),
+                access_rules_list: Vec::new(),
+            };
+            let output: CreateComponentOutput = call_engine(CREATE_COMPONENT, input);
+            output.component_address
+        }
+
+        pub fn put_invalid_component_state(&mut self) {
+            let input = PutComponentStateInput {
+                state: scrypto_encode(&(self.secret.clone(), 1u32)),
+            };
+            let _: PutComponentStateOutput = call_engine(PUT_COMPONENT_STATE, input);
+        }
+
+        pub fn put_component_state_in_immutable_method(&self) {
+            let input = PutComponentStateInput {
+                state: scrypto_encode(self),
+            };
+            let _: PutComponentStateOutput = call_engine(PUT_COMPONENT_STATE, input);
+        }
     }
 }
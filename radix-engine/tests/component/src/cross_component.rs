@@ -44,6 +44,15 @@ blueprint! {
             }
         }
 
+        pub fn relayed_cross_component_call(
+            &self,
+            relay_address: ComponentAddress,
+            component_address: ComponentAddress,
+        ) -> String {
+            borrow_component!(relay_address)
+                .call("cross_component_call", args![component_address])
+        }
+
         pub fn get_component_state(&self) -> String {
             self.secret.clone()
         }
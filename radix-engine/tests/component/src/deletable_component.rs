@@ -0,0 +1,74 @@
+use scrypto::prelude::*;
+
+blueprint! {
+    struct DeletableComponent {
+        vault: Vault,
+        vaults: LazyMap<u32, Vault>,
+        child: Option<OwnedComponent>,
+    }
+
+    impl DeletableComponent {
+        fn new_resource() -> ResourceAddress {
+            ResourceBuilder::new_fungible()
+                .divisibility(DIVISIBILITY_MAXIMUM)
+                .metadata("name", "TestToken")
+                .no_initial_supply()
+        }
+
+        fn new_state(vault: Vault, child: Option<OwnedComponent>) -> Self {
+            let vaults = LazyMap::new();
+            vaults.insert(0, Vault::new(vault.resource_address()));
+            Self {
+                vault,
+                vaults,
+                child,
+            }
+        }
+
+        pub fn create_empty() -> ComponentAddress {
+            let resource_address = Self::new_resource();
+            let child = Self::new_state(Vault::new(resource_address), None)
+                .instantiate()
+                .own();
+            Self::new_state(Vault::new(resource_address), Some(child))
+                .instantiate()
+                .globalize()
+        }
+
+        pub fn create_with_resources() -> ComponentAddress {
+            let bucket = ResourceBuilder::new_fungible()
+                .divisibility(DIVISIBILITY_MAXIMUM)
+                .metadata("name", "TestToken")
+                .initial_supply(1);
+            Self::new_state(Vault::with_bucket(bucket), None)
+                .instantiate()
+                .globalize()
+        }
+
+        pub fn destroy(&mut self) {
+            let component_address = Runtime::actor().component_address().unwrap();
+            borrow_component!(component_address).delete();
+        }
+
+        pub fn destroy_in_immutable_method(&self) {
+            let component_address = Runtime::actor().component_address().unwrap();
+            borrow_component!(component_address).delete();
+        }
+
+        pub fn drop_vault_in_immutable_method(&self) {
+            Vault(self.vault.0).drop_empty();
+        }
+
+        pub fn drop_empty_vault() {
+            Vault::new(Self::new_resource()).drop_empty();
+        }
+
+        pub fn drop_non_empty_vault() {
+            let bucket = ResourceBuilder::new_fungible()
+                .divisibility(DIVISIBILITY_MAXIMUM)
+                .metadata("name", "TestToken")
+                .initial_supply(1);
+            Vault::with_bucket(bucket).drop_empty();
+        }
+    }
+}
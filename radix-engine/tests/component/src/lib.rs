@@ -1,8 +1,12 @@
+pub mod attribute_auth_component;
 pub mod auth_component;
 pub mod auth_list_component;
 pub mod chess;
 pub mod component;
 pub mod cross_component;
+pub mod deletable_component;
+pub mod owned_component;
 pub mod package;
 pub mod reentrant_component;
 pub mod typed_cross_component_call;
+pub mod updatable_auth_component;
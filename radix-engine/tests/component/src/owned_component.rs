@@ -0,0 +1,52 @@
+use scrypto::prelude::*;
+
+blueprint! {
+    struct OwnedComponentTest {
+        count: u32,
+        child: Option<OwnedComponent>,
+    }
+
+    impl OwnedComponentTest {
+        pub fn create_parent() -> ComponentAddress {
+            let child = Self {
+                count: 0,
+                child: None,
+            }
+            .instantiate()
+            .own();
+
+            Self {
+                count: 0,
+                child: Some(child),
+            }
+            .instantiate()
+            .globalize()
+        }
+
+        pub fn create_dangling_child() {
+            Self {
+                count: 0,
+                child: None,
+            }
+            .instantiate()
+            .own();
+        }
+
+        pub fn increment(&mut self) -> u32 {
+            self.count += 1;
+            self.count
+        }
+
+        pub fn increment_child(&self) -> u32 {
+            self.child.as_ref().unwrap().call("increment", vec![])
+        }
+
+        pub fn get_child_address(&self) -> ComponentAddress {
+            self.child.as_ref().unwrap().component_address()
+        }
+
+        pub fn drop_child(&mut self) {
+            self.child = None;
+        }
+    }
+}
@@ -1,20 +1,31 @@
 use scrypto::prelude::*;
 
 blueprint! {
-    struct ReentrantComponent {}
+    struct ReentrantComponent {
+        count: u32,
+    }
 
     impl ReentrantComponent {
         pub fn new() -> ComponentAddress {
-            Self {}.instantiate().globalize()
+            Self { count: 1 }.instantiate().globalize()
         }
 
         pub fn func(&mut self) {}
 
+        pub fn get_count(&self) -> u32 {
+            self.count
+        }
+
         pub fn call_self(&mut self) {
             if let ScryptoActor::Component(addr) = Runtime::actor().actor() {
                 let self_component = borrow_component!(addr);
                 self_component.call("func", vec![])
             }
         }
+
+        pub fn call_self_read_only(&mut self) -> u32 {
+            let addr = Runtime::actor().component_address().unwrap();
+            borrow_component!(addr).call("get_count", vec![])
+        }
     }
 }
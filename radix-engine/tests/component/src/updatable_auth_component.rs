@@ -0,0 +1,40 @@
+use scrypto::prelude::*;
+
+blueprint! {
+    struct UpdatableAuthComponent {}
+
+    impl UpdatableAuthComponent {
+        pub fn create_component(
+            admin_badge: ResourceAddress,
+            user_badge: ResourceAddress,
+        ) -> ComponentAddress {
+            Self {}
+                .instantiate()
+                .add_access_check(
+                    AccessRules::new()
+                        .method("get_secret", rule!(require(user_badge)))
+                        .mutability("get_secret", MUTABLE(rule!(require(admin_badge))))
+                        .default(rule!(allow_all)),
+                )
+                .globalize()
+        }
+
+        pub fn get_secret(&self) -> String {
+            "Secret".to_owned()
+        }
+
+        pub fn set_secret_auth(&mut self, user_badge: ResourceAddress) {
+            let component_address = Runtime::actor().component_address().unwrap();
+            borrow_component!(component_address).set_method_auth(
+                0,
+                "get_secret",
+                rule!(require(user_badge)),
+            );
+        }
+
+        pub fn lock_secret_auth(&mut self) {
+            let component_address = Runtime::actor().component_address().unwrap();
+            borrow_component!(component_address).lock_method_auth(0, "get_secret");
+        }
+    }
+}
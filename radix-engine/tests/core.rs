@@ -1,6 +1,13 @@
+use radix_engine::errors::{RuntimeError, WasmTrapKind};
 use radix_engine::ledger::*;
 use radix_engine::transaction::*;
 use scrypto::prelude::*;
+use scrypto::values::ScryptoValue;
+
+#[derive(Debug, TypeId, Encode, Decode, Describe)]
+pub enum WithdrawError {
+    InsufficientFunds { requested: u32, available: u32 },
+}
 
 #[test]
 fn test_process_and_transaction() {
@@ -18,6 +25,45 @@ fn test_process_and_transaction() {
     receipt1.result.expect("Should be okay.");
 }
 
+#[test]
+fn transaction_signers_should_be_visible_to_blueprints() {
+    let mut ledger = InMemorySubstateStore::with_bootstrap();
+    let mut executor = TransactionExecutor::new(&mut ledger, true);
+    let (pk, sk) = executor.new_key_pair();
+    let package = executor
+        .publish_package(&compile_package!(format!("./tests/{}", "core")))
+        .unwrap();
+
+    let transaction = TransactionBuilder::new()
+        .call_function(package, "CoreTest", "transaction_signers", args![])
+        .build(executor.get_nonce([pk]))
+        .sign([&sk]);
+    let receipt = executor.validate_and_execute(&transaction).unwrap();
+
+    receipt.result.expect("Should be okay.");
+    assert_eq!(receipt.outputs[0], ScryptoValue::from_value(&vec![pk]));
+}
+
+#[test]
+fn caller_should_be_the_calling_blueprint_or_none() {
+    let mut ledger = InMemorySubstateStore::with_bootstrap();
+    let mut executor = TransactionExecutor::new(&mut ledger, true);
+    let package = executor
+        .publish_package(&compile_package!(format!("./tests/{}", "core")))
+        .unwrap();
+
+    let transaction = TransactionBuilder::new()
+        .call_function(package, "CoreTest", "is_called_by_transaction", args![])
+        .call_function(package, "CoreTest", "call_self", args![])
+        .build(executor.get_nonce([]))
+        .sign([]);
+    let receipt = executor.validate_and_execute(&transaction).unwrap();
+
+    receipt.result.expect("Should be okay.");
+    assert_eq!(receipt.outputs[0], ScryptoValue::from_value(&true));
+    assert_eq!(receipt.outputs[1], ScryptoValue::from_value(&true));
+}
+
 #[test]
 fn test_call() {
     let mut ledger = InMemorySubstateStore::with_bootstrap();
@@ -36,3 +82,188 @@ fn test_call() {
     let receipt = executor.validate_and_execute(&transaction).unwrap();
     receipt.result.expect("Should be okay.");
 }
+
+#[test]
+fn returning_ok_should_succeed() {
+    let mut ledger = InMemorySubstateStore::with_bootstrap();
+    let mut executor = TransactionExecutor::new(&mut ledger, true);
+    let package = executor
+        .publish_package(&compile_package!(format!("./tests/{}", "core")))
+        .unwrap();
+
+    let transaction = TransactionBuilder::new()
+        .call_function(package, "ErrorTest", "withdraw", args![5u32])
+        .build(executor.get_nonce([]))
+        .sign([]);
+    let receipt = executor.validate_and_execute(&transaction).unwrap();
+    receipt.result.expect("Should be okay.");
+    assert_eq!(
+        receipt.outputs[0],
+        ScryptoValue::from_value(&Result::<u32, WithdrawError>::Ok(5))
+    );
+}
+
+#[test]
+fn returning_err_should_abort_with_application_error() {
+    let mut ledger = InMemorySubstateStore::with_bootstrap();
+    let mut executor = TransactionExecutor::new(&mut ledger, true);
+    let package = executor
+        .publish_package(&compile_package!(format!("./tests/{}", "core")))
+        .unwrap();
+
+    let transaction = TransactionBuilder::new()
+        .call_function(package, "ErrorTest", "withdraw", args![10u32])
+        .build(executor.get_nonce([]))
+        .sign([]);
+    let receipt = executor.validate_and_execute(&transaction).unwrap();
+    let error = receipt.result.expect_err("Should be an error.");
+    assert_eq!(
+        error,
+        RuntimeError::ApplicationError {
+            actor: ScryptoActor::Blueprint(package, "ErrorTest".to_string()),
+            error: ScryptoValue::from_value(&WithdrawError::InsufficientFunds {
+                requested: 10,
+                available: 5,
+            }),
+        }
+    );
+}
+
+#[test]
+fn returning_err_without_abort_on_err_should_succeed() {
+    let mut ledger = InMemorySubstateStore::with_bootstrap();
+    let mut executor = TransactionExecutor::new(&mut ledger, true);
+    let package = executor
+        .publish_package(&compile_package!(format!("./tests/{}", "core")))
+        .unwrap();
+
+    let transaction = TransactionBuilder::new()
+        .call_function(package, "ErrorTest", "try_withdraw", args![10u32])
+        .build(executor.get_nonce([]))
+        .sign([]);
+    let receipt = executor.validate_and_execute(&transaction).unwrap();
+    receipt.result.expect("Should be okay.");
+    assert_eq!(
+        receipt.outputs[0],
+        ScryptoValue::from_value(&Result::<u32, WithdrawError>::Err(
+            WithdrawError::InsufficientFunds {
+                requested: 10,
+                available: 5,
+            }
+        ))
+    );
+}
+
+#[test]
+fn panicking_should_fail_with_panic_message() {
+    let mut ledger = InMemorySubstateStore::with_bootstrap();
+    let mut executor = TransactionExecutor::new(&mut ledger, true);
+    let package = executor
+        .publish_package(&compile_package!(format!("./tests/{}", "core")))
+        .unwrap();
+
+    let transaction = TransactionBuilder::new()
+        .call_function(package, "ErrorTest", "panic", args![])
+        .build(executor.get_nonce([]))
+        .sign([]);
+    let receipt = executor.validate_and_execute(&transaction).unwrap();
+    let error = receipt.result.expect_err("Should be an error.");
+    match error {
+        RuntimeError::Panic { actor, message, .. } => {
+            assert_eq!(
+                actor,
+                ScryptoActor::Blueprint(package, "ErrorTest".to_string())
+            );
+            assert_eq!(message, "Something went wrong");
+        }
+        _ => panic!("Should be a panic: {:?}", error),
+    }
+}
+
+#[test]
+fn trapping_should_fail_with_trap_kind_and_call_frames() {
+    let mut ledger = InMemorySubstateStore::with_bootstrap();
+    let mut executor = TransactionExecutor::new(&mut ledger, true);
+    let package = executor
+        .publish_package(&compile_package!(format!("./tests/{}", "core")))
+        .unwrap();
+
+    let transaction = TransactionBuilder::new()
+        .call_function(package, "ErrorTest", "trap", args![])
+        .build(executor.get_nonce([]))
+        .sign([]);
+    let receipt = executor.validate_and_execute(&transaction).unwrap();
+    let error = receipt.result.expect_err("Should be an error.");
+    match error {
+        RuntimeError::WasmTrap {
+            actor,
+            kind,
+            call_frames,
+        } => {
+            assert_eq!(
+                actor,
+                ScryptoActor::Blueprint(package, "ErrorTest".to_string())
+            );
+            assert_eq!(kind, WasmTrapKind::Unreachable);
+            assert_eq!(call_frames, vec!["ErrorTest_main".to_string()]);
+        }
+        _ => panic!("Should be a trap: {:?}", error),
+    }
+}
+
+#[test]
+fn failed_try_call_should_roll_back_and_return_bucket() {
+    let mut ledger = InMemorySubstateStore::with_bootstrap();
+    let mut executor = TransactionExecutor::new(&mut ledger, true);
+    let (_, _, account) = executor.new_account();
+    let package = executor
+        .publish_package(&compile_package!(format!("./tests/{}", "core")))
+        .unwrap();
+
+    let transaction = TransactionBuilder::new()
+        .call_function(package, "TryCallTest", "try_deposit", args![])
+        .call_method_with_all_resources(account, "deposit_batch")
+        .build(executor.get_nonce([]))
+        .sign([]);
+    let receipt = executor.validate_and_execute(&transaction).unwrap();
+
+    receipt.result.expect("Should be okay.");
+}
+
+#[test]
+fn failed_try_call_should_return_borrowed_buckets_and_component() {
+    let mut ledger = InMemorySubstateStore::with_bootstrap();
+    let mut executor = TransactionExecutor::new(&mut ledger, true);
+    let (_, _, account) = executor.new_account();
+    let package = executor
+        .publish_package(&compile_package!(format!("./tests/{}", "core")))
+        .unwrap();
+
+    let transaction = TransactionBuilder::new()
+        .call_function(package, "TryCallTest", "try_deposit_locked_bucket", args![])
+        .call_method_with_all_resources(account, "deposit_batch")
+        .build(executor.get_nonce([]))
+        .sign([]);
+    let receipt = executor.validate_and_execute(&transaction).unwrap();
+
+    receipt.result.expect("Should be okay.");
+}
+
+#[test]
+fn failed_try_call_should_release_proofs() {
+    let mut ledger = InMemorySubstateStore::with_bootstrap();
+    let mut executor = TransactionExecutor::new(&mut ledger, true);
+    let (_, _, account) = executor.new_account();
+    let package = executor
+        .publish_package(&compile_package!(format!("./tests/{}", "core")))
+        .unwrap();
+
+    let transaction = TransactionBuilder::new()
+        .call_function(package, "TryCallTest", "try_check_proof", args![])
+        .call_method_with_all_resources(account, "deposit_batch")
+        .build(executor.get_nonce([]))
+        .sign([]);
+    let receipt = executor.validate_and_execute(&transaction).unwrap();
+
+    receipt.result.expect("Should be okay.");
+}
@@ -12,5 +12,31 @@ blueprint! {
                 Runtime::generate_uuid(),
             )
         }
+
+        pub fn transaction_signers() -> Vec<EcdsaPublicKey> {
+            Runtime::transaction_signers()
+        }
+
+        pub fn is_called_by_transaction() -> bool {
+            Runtime::caller().is_none()
+        }
+
+        pub fn is_called_by_self() -> bool {
+            Runtime::caller()
+                == Some(ScryptoActor::Blueprint(
+                    Runtime::package_address(),
+                    "CoreTest".to_owned(),
+                ))
+        }
+
+        pub fn call_self() -> bool {
+            scrypto_decode(&Runtime::call_function(
+                Runtime::package_address(),
+                "CoreTest",
+                "is_called_by_self",
+                args!(),
+            ))
+            .unwrap()
+        }
     }
 }
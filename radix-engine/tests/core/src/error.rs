@@ -0,0 +1,36 @@
+use scrypto::prelude::*;
+
+#[derive(Debug, TypeId, Encode, Decode, Describe)]
+pub enum WithdrawError {
+    InsufficientFunds { requested: u32, available: u32 },
+}
+
+blueprint! {
+    struct ErrorTest {}
+
+    impl ErrorTest {
+        #[abort_on_err]
+        pub fn withdraw(amount: u32) -> Result<u32, WithdrawError> {
+            Self::try_withdraw(amount)
+        }
+
+        pub fn try_withdraw(amount: u32) -> Result<u32, WithdrawError> {
+            if amount > 5 {
+                Err(WithdrawError::InsufficientFunds {
+                    requested: amount,
+                    available: 5,
+                })
+            } else {
+                Ok(amount)
+            }
+        }
+
+        pub fn panic() {
+            panic!("Something went wrong")
+        }
+
+        pub fn trap() {
+            core::arch::wasm32::unreachable()
+        }
+    }
+}
@@ -1,2 +1,4 @@
 pub mod call;
 pub mod context;
+pub mod error;
+pub mod try_call;
@@ -0,0 +1,124 @@
+use scrypto::prelude::*;
+
+blueprint! {
+    struct TryCallTest {
+        vault: Vault,
+        count: u32,
+    }
+
+    impl TryCallTest {
+        pub fn deposit_and_panic(&mut self, bucket: Bucket) {
+            self.vault.put(bucket);
+            self.count += 1;
+            panic!("Deposit refused")
+        }
+
+        pub fn deposit_pair(&mut self, first: Bucket, second: Bucket) {
+            self.vault.put(first);
+            self.vault.put(second);
+            self.count += 1;
+        }
+
+        pub fn check_proof_and_panic(&self, _proof: Proof) {
+            panic!("Proof refused")
+        }
+
+        pub fn get_count(&self) -> u32 {
+            self.count
+        }
+
+        pub fn try_deposit() -> Bucket {
+            let bucket = ResourceBuilder::new_fungible()
+                .divisibility(DIVISIBILITY_NONE)
+                .initial_supply(100);
+            let component_address = TryCallTest {
+                vault: Vault::new(bucket.resource_address()),
+                count: 0,
+            }
+            .instantiate()
+            .globalize();
+
+            let bucket_id = bucket.0;
+            let result =
+                Runtime::try_call_method(component_address, "deposit_and_panic", args!(bucket));
+            match result {
+                Err(CallError::Panic { message, .. }) => assert_eq!(message, "Deposit refused"),
+                _ => panic!("Deposit should have panicked"),
+            }
+
+            let count: u32 = scrypto_decode(&Runtime::call_method(
+                component_address,
+                "get_count",
+                args!(),
+            ))
+            .unwrap();
+            assert_eq!(count, 0);
+
+            let bucket = Bucket(bucket_id);
+            assert_eq!(bucket.amount(), Decimal::from(100));
+            bucket
+        }
+
+        pub fn try_check_proof() -> Bucket {
+            let bucket = ResourceBuilder::new_fungible()
+                .divisibility(DIVISIBILITY_NONE)
+                .initial_supply(100);
+            let component_address = TryCallTest {
+                vault: Vault::new(bucket.resource_address()),
+                count: 0,
+            }
+            .instantiate()
+            .globalize();
+
+            let proof = bucket.create_proof();
+            let proof_id = proof.0;
+            let result = Runtime::try_call_method(
+                component_address,
+                "check_proof_and_panic",
+                args!(proof),
+            );
+            assert!(result.is_err());
+            Proof(proof_id).drop();
+
+            // A proof leaked by the failed call would keep the bucket locked
+            bucket
+        }
+
+        pub fn try_deposit_locked_bucket() -> Bucket {
+            let mut bucket = ResourceBuilder::new_fungible()
+                .divisibility(DIVISIBILITY_NONE)
+                .initial_supply(100);
+            let component_address = TryCallTest {
+                vault: Vault::new(bucket.resource_address()),
+                count: 0,
+            }
+            .instantiate()
+            .globalize();
+
+            let locked = bucket.take(40);
+            let proof = locked.create_proof();
+            let bucket_id = bucket.0;
+            let locked_id = locked.0;
+            let result = Runtime::try_call_method(
+                component_address,
+                "deposit_pair",
+                args!(bucket, locked),
+            );
+            assert_eq!(result, Err(CallError::CantMoveLockedBucket));
+
+            // Both buckets and the component are still usable by this caller
+            proof.drop();
+            let mut bucket = Bucket(bucket_id);
+            bucket.put(Bucket(locked_id));
+            assert_eq!(bucket.amount(), Decimal::from(100));
+            let count: u32 = scrypto_decode(&Runtime::call_method(
+                component_address,
+                "get_count",
+                args!(),
+            ))
+            .unwrap();
+            assert_eq!(count, 0);
+            bucket
+        }
+    }
+}
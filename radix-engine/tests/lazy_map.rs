@@ -3,6 +3,7 @@ use radix_engine::errors::RuntimeError;
 use radix_engine::ledger::*;
 use radix_engine::transaction::*;
 use scrypto::prelude::*;
+use scrypto::values::ScryptoValue;
 
 #[test]
 fn dangling_lazy_map_should_fail() {
@@ -22,7 +23,10 @@ fn dangling_lazy_map_should_fail() {
 
     // Assert
     let runtime_error = receipt.result.expect_err("Should be runtime error");
-    assert_eq!(runtime_error, RuntimeError::ResourceCheckFailure(ResourceFailure::UnclaimedLazyMap));
+    assert_eq!(
+        runtime_error,
+        RuntimeError::ResourceCheckFailure(ResourceFailure::UnclaimedLazyMap)
+    );
 }
 
 #[test]
@@ -228,3 +232,247 @@ fn create_lazy_map_and_put() {
     // Assert
     assert!(receipt.result.is_ok());
 }
+
+#[test]
+fn can_remove_and_iterate_lazy_map_entries() {
+    // Arrange
+    let mut ledger = InMemorySubstateStore::with_bootstrap();
+    let mut executor = TransactionExecutor::new(&mut ledger, true);
+    let package = executor
+        .publish_package(&compile_package!(format!("./tests/{}", "lazy_map")))
+        .unwrap();
+
+    // Act
+    let transaction = TransactionBuilder::new()
+        .call_function(package, "LazyMapTest", "remove_and_iterate", args![])
+        .build(executor.get_nonce([]))
+        .sign([]);
+    let receipt = executor.validate_and_execute(&transaction).unwrap();
+
+    // Assert
+    assert!(receipt.result.is_ok());
+}
+
+#[test]
+fn can_remove_committed_lazy_map_entry() {
+    // Arrange
+    let mut ledger = InMemorySubstateStore::with_bootstrap();
+    let mut executor = TransactionExecutor::new(&mut ledger, true);
+    let package = executor
+        .publish_package(&compile_package!(format!("./tests/{}", "lazy_map")))
+        .unwrap();
+    let transaction = TransactionBuilder::new()
+        .call_function(package, "LazyMapTest", "new_lazy_map_with_put", args![])
+        .build(executor.get_nonce([]))
+        .sign([]);
+    let receipt = executor.validate_and_execute(&transaction).unwrap();
+    let component_address = receipt.new_component_addresses[0];
+
+    // Act
+    let transaction = TransactionBuilder::new()
+        .call_method(component_address, "remove_entry", args!["hello".to_owned()])
+        .call_method(component_address, "remove_entry", args!["hello".to_owned()])
+        .call_method(component_address, "entries", args![])
+        .build(executor.get_nonce([]))
+        .sign([]);
+    let receipt = executor.validate_and_execute(&transaction).unwrap();
+
+    // Assert
+    assert!(receipt.result.is_ok());
+    assert_eq!(
+        receipt.outputs[0],
+        ScryptoValue::from_value(&Some("world".to_owned()))
+    );
+    assert_eq!(
+        receipt.outputs[1],
+        ScryptoValue::from_value(&Option::<String>::None)
+    );
+    assert_eq!(
+        receipt.outputs[2],
+        ScryptoValue::from_value(&Vec::<(String, String)>::new())
+    );
+}
+
+#[test]
+fn cannot_remove_lazy_map_entry_holding_lazy_map() {
+    // Arrange
+    let mut ledger = InMemorySubstateStore::with_bootstrap();
+    let mut executor = TransactionExecutor::new(&mut ledger, true);
+    let package = executor
+        .publish_package(&compile_package!(format!("./tests/{}", "lazy_map")))
+        .unwrap();
+    let transaction = TransactionBuilder::new()
+        .call_function(
+            package,
+            "LazyMapTest",
+            "new_lazy_map_into_lazy_map",
+            args![],
+        )
+        .build(executor.get_nonce([]))
+        .sign([]);
+    let receipt = executor.validate_and_execute(&transaction).unwrap();
+    let component_address = receipt.new_component_addresses[0];
+
+    // Act
+    let transaction = TransactionBuilder::new()
+        .call_method(component_address, "remove_lazy_map", args![])
+        .build(executor.get_nonce([]))
+        .sign([]);
+    let receipt = executor.validate_and_execute(&transaction).unwrap();
+
+    // Assert
+    let runtime_error = receipt.result.expect_err("Should be runtime error");
+    match runtime_error {
+        RuntimeError::LazyMapRemoved(_) => {}
+        _ => panic!("Should be lazy map removed error but was {}", runtime_error),
+    }
+}
+
+#[test]
+fn dangling_lazy_vec_should_fail() {
+    // Arrange
+    let mut ledger = InMemorySubstateStore::with_bootstrap();
+    let mut executor = TransactionExecutor::new(&mut ledger, true);
+    let package = executor
+        .publish_package(&compile_package!(format!("./tests/{}", "lazy_map")))
+        .unwrap();
+
+    // Act
+    let transaction = TransactionBuilder::new()
+        .call_function(package, "LazyCollections", "dangling_lazy_vec", args![])
+        .build(executor.get_nonce([]))
+        .sign([]);
+    let receipt = executor.validate_and_execute(&transaction).unwrap();
+
+    // Assert
+    let runtime_error = receipt.result.expect_err("Should be runtime error");
+    assert_eq!(
+        runtime_error,
+        RuntimeError::ResourceCheckFailure(ResourceFailure::UnclaimedLazyMap)
+    );
+}
+
+#[test]
+fn can_push_and_pop_lazy_vec() {
+    // Arrange
+    let mut ledger = InMemorySubstateStore::with_bootstrap();
+    let mut executor = TransactionExecutor::new(&mut ledger, true);
+    let package = executor
+        .publish_package(&compile_package!(format!("./tests/{}", "lazy_map")))
+        .unwrap();
+    let transaction = TransactionBuilder::new()
+        .call_function(package, "LazyCollections", "new", args![])
+        .build(executor.get_nonce([]))
+        .sign([]);
+    let receipt = executor.validate_and_execute(&transaction).unwrap();
+    let component_address = receipt.new_component_addresses[0];
+
+    // Act
+    let transaction = TransactionBuilder::new()
+        .call_method(component_address, "push_and_pop", args![4u32])
+        .build(executor.get_nonce([]))
+        .sign([]);
+    let receipt = executor.validate_and_execute(&transaction).unwrap();
+
+    // Assert
+    assert!(receipt.result.is_ok());
+    assert_eq!(
+        receipt.outputs[0],
+        ScryptoValue::from_value(&(Some(4u32), Some(1u32), 6u32))
+    );
+}
+
+#[test]
+fn lazy_vec_length_should_persist() {
+    // Arrange
+    let mut ledger = InMemorySubstateStore::with_bootstrap();
+    let mut executor = TransactionExecutor::new(&mut ledger, true);
+    let package = executor
+        .publish_package(&compile_package!(format!("./tests/{}", "lazy_map")))
+        .unwrap();
+    let transaction = TransactionBuilder::new()
+        .call_function(package, "LazyCollections", "new", args![])
+        .build(executor.get_nonce([]))
+        .sign([]);
+    let receipt = executor.validate_and_execute(&transaction).unwrap();
+    let component_address = receipt.new_component_addresses[0];
+
+    // Act
+    let transaction = TransactionBuilder::new()
+        .call_method(component_address, "numbers_len", args![])
+        .build(executor.get_nonce([]))
+        .sign([]);
+    let receipt = executor.validate_and_execute(&transaction).unwrap();
+
+    // Assert
+    assert!(receipt.result.is_ok());
+    assert_eq!(receipt.outputs[0], ScryptoValue::from_value(&3usize));
+}
+
+#[test]
+fn can_insert_and_remove_lazy_set() {
+    // Arrange
+    let mut ledger = InMemorySubstateStore::with_bootstrap();
+    let mut executor = TransactionExecutor::new(&mut ledger, true);
+    let package = executor
+        .publish_package(&compile_package!(format!("./tests/{}", "lazy_map")))
+        .unwrap();
+    let transaction = TransactionBuilder::new()
+        .call_function(package, "LazyCollections", "new", args![])
+        .build(executor.get_nonce([]))
+        .sign([]);
+    let receipt = executor.validate_and_execute(&transaction).unwrap();
+    let component_address = receipt.new_component_addresses[0];
+
+    // Act
+    let transaction = TransactionBuilder::new()
+        .call_method(
+            component_address,
+            "insert_and_remove",
+            args!["bob".to_owned()],
+        )
+        .build(executor.get_nonce([]))
+        .sign([]);
+    let receipt = executor.validate_and_execute(&transaction).unwrap();
+
+    // Assert
+    assert!(receipt.result.is_ok());
+    assert_eq!(
+        receipt.outputs[0],
+        ScryptoValue::from_value(&(true, false, true, vec!["bob".to_owned()]))
+    );
+}
+
+#[test]
+fn can_pop_vault_from_lazy_vec() {
+    // Arrange
+    let mut ledger = InMemorySubstateStore::with_bootstrap();
+    let mut executor = TransactionExecutor::new(&mut ledger, true);
+    let (_, _, account) = executor.new_account();
+    let package = executor
+        .publish_package(&compile_package!(format!("./tests/{}", "lazy_map")))
+        .unwrap();
+    let transaction = TransactionBuilder::new()
+        .call_function(package, "LazyCollections", "new", args![])
+        .build(executor.get_nonce([]))
+        .sign([]);
+    let receipt = executor.validate_and_execute(&transaction).unwrap();
+    let component_address = receipt.new_component_addresses[0];
+    let transaction = TransactionBuilder::new()
+        .call_method(component_address, "push_vault", args![])
+        .build(executor.get_nonce([]))
+        .sign([]);
+    let receipt = executor.validate_and_execute(&transaction).unwrap();
+    receipt.result.expect("Should be okay");
+
+    // Act
+    let transaction = TransactionBuilder::new()
+        .call_method(component_address, "pop_vault", args![])
+        .call_method_with_all_resources(account, "deposit_batch")
+        .build(executor.get_nonce([]))
+        .sign([]);
+    let receipt = executor.validate_and_execute(&transaction).unwrap();
+
+    // Assert
+    receipt.result.expect("Should be okay");
+}
@@ -0,0 +1,61 @@
+use scrypto::prelude::*;
+
+blueprint! {
+    struct LazyCollections {
+        numbers: LazyVec<u32>,
+        names: LazySet<String>,
+        vaults: LazyVec<Vault>,
+    }
+
+    impl LazyCollections {
+        pub fn new() -> ComponentAddress {
+            let mut numbers = LazyVec::new();
+            numbers.push(1);
+            numbers.push(2);
+            numbers.push(3);
+            let names = LazySet::new();
+            names.insert("alice".to_owned());
+            LazyCollections {
+                numbers,
+                names,
+                vaults: LazyVec::new(),
+            }
+            .instantiate()
+            .globalize()
+        }
+
+        pub fn dangling_lazy_vec() {
+            let mut numbers = LazyVec::new();
+            numbers.push(1u32);
+        }
+
+        pub fn push_and_pop(&mut self, number: u32) -> (Option<u32>, Option<u32>, u32) {
+            self.numbers.push(number);
+            let popped = self.numbers.pop();
+            (popped, self.numbers.get(0), self.numbers.iter().sum())
+        }
+
+        pub fn numbers_len(&self) -> usize {
+            self.numbers.len()
+        }
+
+        pub fn insert_and_remove(&mut self, name: String) -> (bool, bool, bool, Vec<String>) {
+            let inserted = self.names.insert(name.clone());
+            let inserted_again = self.names.insert(name.clone());
+            let removed = self.names.remove(&"alice".to_owned());
+            (inserted, inserted_again, removed, self.names.iter().collect())
+        }
+
+        pub fn push_vault(&mut self) {
+            let bucket = ResourceBuilder::new_fungible().initial_supply(1);
+            self.vaults.push(Vault::with_bucket(bucket));
+        }
+
+        pub fn pop_vault(&mut self) -> Bucket {
+            let mut vault = self.vaults.pop().unwrap();
+            let bucket = vault.take_all();
+            vault.drop_empty();
+            bucket
+        }
+    }
+}
@@ -84,6 +84,39 @@ blueprint! {
             .globalize()
         }
 
+        pub fn remove_and_iterate() -> Vec<String> {
+            let map = LazyMap::new();
+            map.insert("c".to_owned(), "3".to_owned());
+            map.insert("a".to_owned(), "1".to_owned());
+            map.insert("b".to_owned(), "2".to_owned());
+            assert_eq!(map.remove(&"b".to_owned()), Some("2".to_owned()));
+            assert_eq!(map.remove(&"b".to_owned()), None);
+            assert!(!map.contains_key(&"b".to_owned()));
+            assert!(map.contains_key(&"a".to_owned()));
+            assert_eq!(map.len(), 2);
+            let keys = map.keys().collect();
+            LazyMapTest {
+                map,
+                vector: Vec::new(),
+                lazy_maps: LazyMap::new(),
+            }
+            .instantiate()
+            .globalize();
+            keys
+        }
+
+        pub fn remove_entry(&mut self, key: String) -> Option<String> {
+            self.map.remove(&key)
+        }
+
+        pub fn entries(&self) -> Vec<(String, String)> {
+            self.map.iter().collect()
+        }
+
+        pub fn remove_lazy_map(&mut self) -> () {
+            self.lazy_maps.remove(&"hello".to_owned());
+        }
+
         pub fn overwrite_lazy_map(&mut self) -> () {
             self.lazy_maps.insert("hello".to_owned(), LazyMap::new())
         }
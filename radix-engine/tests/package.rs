@@ -5,8 +5,9 @@ use crate::test_runner::TestRunner;
 use radix_engine::errors::RuntimeError;
 use radix_engine::errors::WasmValidationError::NoValidMemoryExport;
 use radix_engine::ledger::InMemorySubstateStore;
-use scrypto::prelude::*;
 use radix_engine::model::PackageError;
+use scrypto::prelude::*;
+use scrypto::values::ScryptoValue;
 
 #[test]
 fn missing_memory_should_cause_error() {
@@ -101,3 +102,323 @@ fn zero_return_len_should_cause_data_validation_error() {
         panic!("{} should be data validation error", error);
     }
 }
+
+#[test]
+fn publish_package_should_record_metadata_and_code_hash() {
+    // Arrange
+    let mut substate_store = InMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(&mut substate_store);
+    let code = TestRunner::compile("package");
+    let metadata = HashMap::from([
+        ("name".to_owned(), "package".to_owned()),
+        ("version".to_owned(), "0.1.0".to_owned()),
+    ]);
+
+    // Act
+    let transaction = test_runner
+        .new_transaction_builder()
+        .publish_package_with_metadata(&code, metadata.clone())
+        .build(test_runner.get_nonce([]))
+        .sign([]);
+    let receipt = test_runner.validate_and_execute(&transaction);
+
+    // Assert
+    receipt.result.expect("Should be okay.");
+    let package_version = test_runner.package_version(receipt.new_package_addresses[0], 1);
+    assert_eq!(package_version.metadata(), &metadata);
+    assert_eq!(package_version.code_hash(), sha256(&code));
+}
+
+fn publish_upgradable_package(
+    test_runner: &mut TestRunner,
+) -> (
+    PackageAddress,
+    EcdsaPublicKey,
+    EcdsaPrivateKey,
+    ComponentAddress,
+    ResourceAddress,
+) {
+    let (pk, sk, account) = test_runner.new_account();
+    let owner_badge = test_runner.create_non_fungible_resource(account);
+    let transaction = test_runner
+        .new_transaction_builder()
+        .publish_package_with_owner(
+            &TestRunner::compile("package_upgrade"),
+            HashMap::new(),
+            rule!(require(owner_badge)),
+        )
+        .build(test_runner.get_nonce([]))
+        .sign([]);
+    let receipt = test_runner.validate_and_execute(&transaction);
+    (
+        receipt.new_package_addresses[0],
+        pk,
+        sk,
+        account,
+        owner_badge,
+    )
+}
+
+#[test]
+fn upgrade_package_with_owner_badge_should_succeed() {
+    // Arrange
+    let mut substate_store = InMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(&mut substate_store);
+    let (package, pk, sk, account, owner_badge) = publish_upgradable_package(&mut test_runner);
+    let code = TestRunner::compile("package_upgrade_v2");
+
+    // Act
+    let transaction = test_runner
+        .new_transaction_builder()
+        .create_proof_from_account(owner_badge, account)
+        .upgrade_package(package, &code, HashMap::new())
+        .build(test_runner.get_nonce([pk]))
+        .sign([sk]);
+    let receipt = test_runner.validate_and_execute(&transaction);
+
+    // Assert
+    receipt.result.expect("Should be okay.");
+    assert_eq!(
+        receipt.outputs.last(),
+        Some(&ScryptoValue::from_value(&2u32))
+    );
+    assert_eq!(test_runner.package(package).latest_version(), 2);
+    assert_eq!(
+        test_runner.package_version(package, 2).code_hash(),
+        sha256(&code)
+    );
+    assert_ne!(
+        test_runner.package_version(package, 1).code_hash(),
+        sha256(&code)
+    );
+}
+
+#[test]
+fn upgrade_package_without_owner_badge_should_fail() {
+    // Arrange
+    let mut substate_store = InMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(&mut substate_store);
+    let (package, _, _, _, _) = publish_upgradable_package(&mut test_runner);
+
+    // Act
+    let transaction = test_runner
+        .new_transaction_builder()
+        .upgrade_package(
+            package,
+            &TestRunner::compile("package_upgrade_v2"),
+            HashMap::new(),
+        )
+        .build(test_runner.get_nonce([]))
+        .sign([]);
+    let receipt = test_runner.validate_and_execute(&transaction);
+
+    // Assert
+    let error = receipt.result.expect_err("Should be an error.");
+    assert_auth_error!(error);
+}
+
+#[test]
+fn upgrade_package_without_owner_should_fail() {
+    // Arrange
+    let mut substate_store = InMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(&mut substate_store);
+    let package = test_runner.publish_package("package_upgrade");
+
+    // Act
+    let transaction = test_runner
+        .new_transaction_builder()
+        .upgrade_package(
+            package,
+            &TestRunner::compile("package_upgrade_v2"),
+            HashMap::new(),
+        )
+        .build(test_runner.get_nonce([]))
+        .sign([]);
+    let receipt = test_runner.validate_and_execute(&transaction);
+
+    // Assert
+    let error = receipt.result.expect_err("Should be an error.");
+    assert_auth_error!(error);
+}
+
+#[test]
+fn component_should_run_its_version_until_migrated() {
+    // Arrange
+    let mut substate_store = InMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(&mut substate_store);
+    let (package, pk, sk, account, owner_badge) = publish_upgradable_package(&mut test_runner);
+    let transaction = test_runner
+        .new_transaction_builder()
+        .call_function(package, "Counter", "new", args![])
+        .build(test_runner.get_nonce([]))
+        .sign([]);
+    let receipt = test_runner.validate_and_execute(&transaction);
+    let component = receipt.new_component_addresses[0];
+    let transaction = test_runner
+        .new_transaction_builder()
+        .create_proof_from_account(owner_badge, account)
+        .upgrade_package(
+            package,
+            &TestRunner::compile("package_upgrade_v2"),
+            HashMap::new(),
+        )
+        .build(test_runner.get_nonce([pk]))
+        .sign([sk]);
+    test_runner
+        .validate_and_execute(&transaction)
+        .result
+        .expect("Should be okay.");
+
+    // Act
+    let transaction = test_runner
+        .new_transaction_builder()
+        .call_method(component, "increment", args![])
+        .call_method(component, "get_count", args![])
+        .build(test_runner.get_nonce([]))
+        .sign([]);
+    let receipt = test_runner.validate_and_execute(&transaction);
+
+    // Assert
+    receipt.result.expect("Should be okay.");
+    assert_eq!(receipt.outputs[1], ScryptoValue::from_value(&1u32));
+    assert_eq!(test_runner.component(component).package_version(), 1);
+}
+
+#[test]
+fn migrated_component_should_run_latest_version() {
+    // Arrange
+    let mut substate_store = InMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(&mut substate_store);
+    let (package, pk, sk, account, owner_badge) = publish_upgradable_package(&mut test_runner);
+    let transaction = test_runner
+        .new_transaction_builder()
+        .call_function(package, "Counter", "new", args![])
+        .build(test_runner.get_nonce([]))
+        .sign([]);
+    let receipt = test_runner.validate_and_execute(&transaction);
+    let component = receipt.new_component_addresses[0];
+    let transaction = test_runner
+        .new_transaction_builder()
+        .call_method(component, "increment", args![])
+        .create_proof_from_account(owner_badge, account)
+        .upgrade_package(
+            package,
+            &TestRunner::compile("package_upgrade_v2"),
+            HashMap::new(),
+        )
+        .build(test_runner.get_nonce([pk]))
+        .sign([sk]);
+    test_runner
+        .validate_and_execute(&transaction)
+        .result
+        .expect("Should be okay.");
+
+    // Act
+    let transaction = test_runner
+        .new_transaction_builder()
+        .call_method(component, "migrate_to_latest", args![])
+        .call_method(component, "increment", args![])
+        .call_method(component, "get_count", args![])
+        .build(test_runner.get_nonce([]))
+        .sign([]);
+    let receipt = test_runner.validate_and_execute(&transaction);
+
+    // Assert
+    receipt.result.expect("Should be okay.");
+    assert_eq!(receipt.outputs[0], ScryptoValue::from_value(&2u32));
+    assert_eq!(receipt.outputs[2], ScryptoValue::from_value(&11u64));
+    assert_eq!(test_runner.component(component).package_version(), 2);
+}
+
+#[test]
+fn migrating_component_of_latest_version_should_fail() {
+    // Arrange
+    let mut substate_store = InMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(&mut substate_store);
+    let package = test_runner.publish_package("package_upgrade");
+    let transaction = test_runner
+        .new_transaction_builder()
+        .call_function(package, "Counter", "new", args![])
+        .build(test_runner.get_nonce([]))
+        .sign([]);
+    let receipt = test_runner.validate_and_execute(&transaction);
+    let component = receipt.new_component_addresses[0];
+
+    // Act
+    let transaction = test_runner
+        .new_transaction_builder()
+        .call_method(component, "migrate_to_latest", args![])
+        .build(test_runner.get_nonce([]))
+        .sign([]);
+    let receipt = test_runner.validate_and_execute(&transaction);
+
+    // Assert
+    let error = receipt.result.expect_err("Should be an error.");
+    assert_eq!(error, RuntimeError::ComponentAlreadyMigrated(component));
+}
+
+#[test]
+fn migrating_component_in_read_only_method_should_fail() {
+    // Arrange
+    let mut substate_store = InMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(&mut substate_store);
+    let package = test_runner.publish_package("package_upgrade");
+    let transaction = test_runner
+        .new_transaction_builder()
+        .call_function(package, "Counter", "new", args![])
+        .build(test_runner.get_nonce([]))
+        .sign([]);
+    let receipt = test_runner.validate_and_execute(&transaction);
+    let component = receipt.new_component_addresses[0];
+
+    // Act
+    let transaction = test_runner
+        .new_transaction_builder()
+        .call_method(component, "migrate_in_read_only_method", args![])
+        .build(test_runner.get_nonce([]))
+        .sign([]);
+    let receipt = test_runner.validate_and_execute(&transaction);
+
+    // Assert
+    let error = receipt.result.expect_err("Should be an error.");
+    assert_eq!(
+        error,
+        RuntimeError::ImmutableMethodWrite(component, "migrate_in_read_only_method".to_string())
+    );
+}
+
+#[test]
+fn export_abi_should_return_abi_stored_with_package_version() {
+    // Arrange
+    let mut substate_store = InMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(&mut substate_store);
+    let (package, pk, sk, account, owner_badge) = publish_upgradable_package(&mut test_runner);
+    let transaction = test_runner
+        .new_transaction_builder()
+        .call_function(package, "Counter", "new", args![])
+        .create_proof_from_account(owner_badge, account)
+        .upgrade_package(
+            package,
+            &TestRunner::compile("package_upgrade_v2"),
+            HashMap::new(),
+        )
+        .build(test_runner.get_nonce([pk]))
+        .sign([sk]);
+    let receipt = test_runner.validate_and_execute(&transaction);
+    let component = receipt.new_component_addresses[0];
+
+    // Act
+    let latest_abi = test_runner.export_abi(package, "Counter");
+    let component_abi = test_runner.export_abi_by_component(component);
+
+    // Assert
+    let function_names = |abi: &abi::Blueprint| {
+        abi.functions
+            .iter()
+            .map(|f| f.name.clone())
+            .collect::<Vec<String>>()
+    };
+    assert_eq!(function_names(&latest_abi), vec!["new", "migrate"]);
+    assert_eq!(function_names(&component_abi), vec!["new"]);
+    assert_eq!(component_abi.methods.len(), 4);
+}
@@ -0,0 +1,31 @@
+use scrypto::prelude::*;
+
+blueprint! {
+    struct Counter {
+        count: u32,
+    }
+
+    impl Counter {
+        pub fn new() -> ComponentAddress {
+            Self { count: 0 }.instantiate().globalize()
+        }
+
+        pub fn increment(&mut self) {
+            self.count += 1;
+        }
+
+        pub fn get_count(&self) -> u32 {
+            self.count
+        }
+
+        pub fn migrate_to_latest(&mut self) -> u32 {
+            let component_address = Runtime::actor().component_address().unwrap();
+            borrow_component!(component_address).migrate()
+        }
+
+        pub fn migrate_in_read_only_method(&self) -> u32 {
+            let component_address = Runtime::actor().component_address().unwrap();
+            borrow_component!(component_address).migrate()
+        }
+    }
+}
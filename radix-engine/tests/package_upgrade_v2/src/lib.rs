@@ -0,0 +1,40 @@
+use scrypto::prelude::*;
+
+#[derive(Decode)]
+struct CounterV1 {
+    count: u32,
+}
+
+blueprint! {
+    struct Counter {
+        count: u64,
+        step: u64,
+    }
+
+    impl Counter {
+        pub fn new() -> ComponentAddress {
+            Self { count: 0, step: 10 }.instantiate().globalize()
+        }
+
+        pub fn migrate(old_state: Vec<u8>) -> Vec<u8> {
+            let old: CounterV1 = scrypto_decode(&old_state).unwrap();
+            scrypto_encode(&Self {
+                count: old.count as u64,
+                step: 10,
+            })
+        }
+
+        pub fn increment(&mut self) {
+            self.count += self.step;
+        }
+
+        pub fn get_count(&self) -> u64 {
+            self.count
+        }
+
+        pub fn migrate_to_latest(&mut self) -> u32 {
+            let component_address = Runtime::actor().component_address().unwrap();
+            borrow_component!(component_address).migrate()
+        }
+    }
+}
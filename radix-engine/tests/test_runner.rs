@@ -1,8 +1,10 @@
+use radix_engine::errors::RuntimeError;
 use radix_engine::ledger::*;
-use radix_engine::model::{Component, Receipt, SignedTransaction};
+use radix_engine::model::{Component, Package, PackageVersion, Receipt, SignedTransaction};
 use radix_engine::transaction::*;
 use scrypto::abi;
 use scrypto::prelude::*;
+use scrypto::values::ScryptoValue;
 
 pub struct TestRunner<'l> {
     executor: TransactionExecutor<'l, InMemorySubstateStore>,
@@ -15,6 +17,11 @@ impl<'l> TestRunner<'l> {
         Self { executor }
     }
 
+    pub fn set_pass_signature_proofs(&mut self, pass_signature_proofs: bool) {
+        self.executor
+            .set_pass_signature_proofs(pass_signature_proofs);
+    }
+
     pub fn new_transaction_builder(&self) -> TransactionBuilder {
         TransactionBuilder::new()
     }
@@ -46,6 +53,15 @@ impl<'l> TestRunner<'l> {
         self.executor.validate_and_execute(transaction).unwrap()
     }
 
+    pub fn query(
+        &mut self,
+        component_address: ComponentAddress,
+        method: &str,
+        args: Vec<Vec<u8>>,
+    ) -> Result<ScryptoValue, RuntimeError> {
+        self.executor.query(component_address, method, args)
+    }
+
     pub fn publish_package(&mut self, name: &str) -> PackageAddress {
         self.executor.publish_package(&Self::compile(name)).unwrap()
     }
@@ -54,6 +70,22 @@ impl<'l> TestRunner<'l> {
         compile_package!(format!("./tests/{}", name))
     }
 
+    pub fn package(&self, package_address: PackageAddress) -> Package {
+        self.executor
+            .substate_store()
+            .get_decoded_substate(&package_address)
+            .map(|(package, _)| package)
+            .unwrap()
+    }
+
+    pub fn package_version(&self, package_address: PackageAddress, version: u32) -> PackageVersion {
+        self.executor
+            .substate_store()
+            .get_decoded_child_substate(&package_address, &version)
+            .map(|(package_version, _)| package_version)
+            .unwrap()
+    }
+
     pub fn component(&self, component_address: ComponentAddress) -> Component {
         self.executor
             .substate_store()
@@ -269,7 +301,7 @@ macro_rules! assert_auth_error {
             RuntimeError::AuthorizationError {
                 authorization: _,
                 function: _,
-                error: ::radix_engine::model::MethodAuthorizationError::NotAuthorized
+                error: ::radix_engine::model::MethodAuthorizationError::NotAuthorized(_)
             }
         ) {
             panic!("Expected auth error but got: {:?}", $error);
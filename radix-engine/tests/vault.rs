@@ -144,7 +144,12 @@ fn dangling_vault_should_fail() {
 
     // Assert
     let runtime_error = receipt.result.expect_err("Should be runtime error");
-    assert_eq!(runtime_error, RuntimeError::ResourceCheckFailure(ResourceFailure::Resource(receipt.new_resource_addresses[0])));
+    assert_eq!(
+        runtime_error,
+        RuntimeError::ResourceCheckFailure(ResourceFailure::Resource(
+            receipt.new_resource_addresses[0]
+        ))
+    );
 }
 
 #[test]
@@ -417,7 +422,6 @@ fn create_mutable_vault_with_get_nonfungible_id() {
     receipt.result.expect("Should be okay");
 }
 
-
 #[test]
 fn create_mutable_vault_with_get_amount() {
     // Arrange
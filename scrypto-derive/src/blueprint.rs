@@ -23,7 +23,7 @@ pub fn handle_blueprint(input: TokenStream) -> Result<TokenStream> {
     let bp_semi_token = &bp_strut.semi_token;
     let bp_impl = &bp.implementation;
     let bp_ident = &bp_strut.ident;
-    let bp_items = &bp_impl.items;
+    let bp_items = &strip_macro_attributes(&bp_impl.items);
     let bp_name = bp_ident.to_string();
     trace!("Blueprint name: {}", bp_name);
 
@@ -42,6 +42,22 @@ pub fn handle_blueprint(input: TokenStream) -> Result<TokenStream> {
         ));
     }
 
+    let instantiate = match generate_access_rules(bp_strut, &bp_impl.items)? {
+        Some(access_rules) => quote! {
+            ::scrypto::component::component_system().to_component_state_with_auth(
+                #bp_name,
+                self
+            )
+            .add_access_check(#access_rules)
+        },
+        None => quote! {
+            ::scrypto::component::component_system().to_component_state_with_auth(
+                #bp_name,
+                self
+            )
+        },
+    };
+
     let output_mod = quote! {
         pub mod blueprint {
             use super::*;
@@ -55,10 +71,7 @@ pub fn handle_blueprint(input: TokenStream) -> Result<TokenStream> {
 
             impl ::scrypto::component::ComponentState for #bp_ident {
                 fn instantiate(self) -> ::scrypto::component::LocalComponent {
-                    ::scrypto::component::component_system().to_component_state_with_auth(
-                        #bp_name,
-                        self
-                    )
+                    #instantiate
                 }
             }
         }
@@ -66,7 +79,7 @@ pub fn handle_blueprint(input: TokenStream) -> Result<TokenStream> {
     trace!("Generated mod: \n{}", quote! { #output_mod });
 
     let dispatcher_ident = format_ident!("{}_main", bp_ident);
-    let (arm_guards, arm_bodies) = generate_dispatcher(bp_ident, bp_items)?;
+    let (arm_guards, arm_bodies) = generate_dispatcher(bp_ident, &bp_impl.items)?;
     let output_dispatcher = quote! {
         #[no_mangle]
         pub extern "C" fn #dispatcher_ident() -> *mut u8 {
@@ -145,6 +158,133 @@ pub fn handle_blueprint(input: TokenStream) -> Result<TokenStream> {
     Ok(output)
 }
 
+fn is_auth_attribute(attr: &Attribute) -> bool {
+    attr.path.is_ident("auth") || attr.path.is_ident("restrict_to")
+}
+
+fn is_abort_on_err_attribute(attr: &Attribute) -> bool {
+    attr.path.is_ident("abort_on_err")
+}
+
+// Removes the auth and `#[abort_on_err]` attributes from methods, as they're consumed by this macro.
+fn strip_macro_attributes(items: &[ImplItem]) -> Vec<ImplItem> {
+    items
+        .iter()
+        .map(|item| match item {
+            ImplItem::Method(m) => {
+                let mut m = m.clone();
+                m.attrs
+                    .retain(|attr| !is_auth_attribute(attr) && !is_abort_on_err_attribute(attr));
+                ImplItem::Method(m)
+            }
+            _ => item.clone(),
+        })
+        .collect()
+}
+
+// Generates `AccessRules` from the `#[auth(rule)]` and `#[restrict_to(field)]` method attributes
+// and the `#[auth(method, rule)]` struct attributes, if any.
+//
+// Methods without a rule are accessible by anyone.
+fn generate_access_rules(bp_strut: &ItemStruct, items: &[ImplItem]) -> Result<Option<Expr>> {
+    let fields: Vec<String> = bp_strut
+        .fields
+        .iter()
+        .filter_map(|f| f.ident.as_ref().map(|i| i.to_string()))
+        .collect();
+    let methods: Vec<String> = items
+        .iter()
+        .filter_map(|item| match item {
+            ImplItem::Method(m)
+                if matches!(m.vis, Visibility::Public(_))
+                    && matches!(m.sig.inputs.first(), Some(FnArg::Receiver(_))) =>
+            {
+                Some(m.sig.ident.to_string())
+            }
+            _ => None,
+        })
+        .collect();
+
+    let mut rules: Vec<(String, Span, Expr)> = Vec::new();
+    for item in items {
+        if let ImplItem::Method(m) = item {
+            for attr in m.attrs.iter().filter(|attr| is_auth_attribute(attr)) {
+                let name = m.sig.ident.to_string();
+                if !methods.contains(&name) {
+                    return Err(Error::new(
+                        attr.span(),
+                        "Auth attributes are only supported on public methods",
+                    ));
+                }
+
+                let rule: Expr = if attr.path.is_ident("auth") {
+                    attr.parse_args()?
+                } else {
+                    let field: Ident = attr.parse_args()?;
+                    let field_name = field.to_string();
+                    if !fields.contains(&field_name) {
+                        return Err(Error::new(
+                            field.span(),
+                            format!("Field `{}` does not exist", field_name),
+                        ));
+                    }
+                    parse_quote! {
+                        ::scrypto::resource::AccessRule::Protected(
+                            ::scrypto::resource::AccessRuleNode::ProofRule(
+                                ::scrypto::resource::require(#field_name)
+                            )
+                        )
+                    }
+                };
+                rules.push((name, attr.span(), rule));
+            }
+        }
+    }
+    for attr in bp_strut
+        .attrs
+        .iter()
+        .filter(|attr| attr.path.is_ident("auth"))
+    {
+        let (method, rule) = attr.parse_args_with(|input: syn::parse::ParseStream| {
+            let method: Ident = input.parse()?;
+            input.parse::<syn::Token![,]>()?;
+            let rule: Expr = input.parse()?;
+            Ok((method, rule))
+        })?;
+        let name = method.to_string();
+        if !methods.contains(&name) {
+            return Err(Error::new(
+                method.span(),
+                format!("Method `{}` does not exist", name),
+            ));
+        }
+        rules.push((name, attr.span(), rule));
+    }
+
+    if rules.is_empty() {
+        return Ok(None);
+    }
+
+    let mut names = Vec::new();
+    let mut exprs = Vec::new();
+    for (name, span, rule) in rules {
+        if names.contains(&name) {
+            return Err(Error::new(
+                span,
+                format!("Method `{}` has more than one auth rule", name),
+            ));
+        }
+        names.push(name);
+        exprs.push(rule);
+    }
+
+    Ok(Some(parse_quote! {
+        ::scrypto::resource::AccessRules::new()
+            #(.method(#names, #exprs))*
+            .default(::scrypto::resource::AccessRule::AllowAll)
+    }))
+}
+
 // Parses function items in an `Impl` and returns the arm guards and bodies
 // used for call matching.
 fn generate_dispatcher(bp_ident: &Ident, items: &[ImplItem]) -> Result<(Vec<Expr>, Vec<Expr>)> {
@@ -155,6 +295,16 @@ fn generate_dispatcher(bp_ident: &Ident, items: &[ImplItem]) -> Result<(Vec<Expr
         trace!("Processing item: {}", quote! { #item });
 
         if let ImplItem::Method(ref m) = item {
+            let abort_on_err = m.attrs.iter().find(|attr| is_abort_on_err_attribute(attr));
+            if let Some(attr) = abort_on_err {
+                if !matches!(m.vis, Visibility::Public(_)) || !returns_result(&m.sig.output) {
+                    return Err(Error::new(
+                        attr.span(),
+                        "`#[abort_on_err]` is only supported on public functions and methods returning a `Result`",
+                    ));
+                }
+            }
+
             if let Visibility::Public(_) = &m.vis {
                 let fn_name = &m.sig.ident.to_string();
                 let fn_ident = &m.sig.ident;
@@ -215,14 +365,34 @@ fn generate_dispatcher(bp_ident: &Ident, items: &[ImplItem]) -> Result<(Vec<Expr
                     trace!("Generated stmt: {}", quote! { #stmt });
                     stmts.push(stmt);
                 }
-                // call the function
-                let stmt: Stmt = parse_quote! {
-                    rtn = ::scrypto::buffer::scrypto_encode_for_radix_engine(
-                        &blueprint::#bp_ident::#fn_ident(#(#args),*)
-                    );
-                };
-                trace!("Generated stmt: {}", quote! { #stmt });
-                stmts.push(stmt);
+                // call the function, aborting on errors if annotated with `#[abort_on_err]`
+                if abort_on_err.is_some() {
+                    let stmt: Stmt = parse_quote! {
+                        let result = blueprint::#bp_ident::#fn_ident(#(#args),*);
+                    };
+                    trace!("Generated stmt: {}", quote! { #stmt });
+                    stmts.push(stmt);
+                    let stmt: Stmt = parse_quote! {
+                        if let Err(error) = &result {
+                            ::scrypto::core::Runtime::abort(error);
+                        }
+                    };
+                    trace!("Generated stmt: {}", quote! { #stmt });
+                    stmts.push(stmt);
+                    let stmt: Stmt = parse_quote! {
+                        rtn = ::scrypto::buffer::scrypto_encode_for_radix_engine(&result);
+                    };
+                    trace!("Generated stmt: {}", quote! { #stmt });
+                    stmts.push(stmt);
+                } else {
+                    let stmt: Stmt = parse_quote! {
+                        rtn = ::scrypto::buffer::scrypto_encode_for_radix_engine(
+                            &blueprint::#bp_ident::#fn_ident(#(#args),*)
+                        );
+                    };
+                    trace!("Generated stmt: {}", quote! { #stmt });
+                    stmts.push(stmt);
+                }
                 // update state
                 if let Some(stmt) = put_state {
                     trace!("Generated stmt: {}", quote! { #stmt });
@@ -442,6 +612,22 @@ fn generate_stubs(bp_ident: &Ident, items: &[ImplItem]) -> Result<TokenStream> {
     Ok(output)
 }
 
+// Returns whether a function returns a `Result`.
+fn returns_result(output: &ReturnType) -> bool {
+    match output {
+        ReturnType::Type(_, t) => match t.as_ref() {
+            Type::Path(tp) => tp
+                .path
+                .segments
+                .last()
+                .map(|seg| seg.ident == "Result")
+                .unwrap_or(false),
+            _ => false,
+        },
+        ReturnType::Default => false,
+    }
+}
+
 fn replace_self_with(t: &Type, name: &str) -> Type {
     match t {
         Type::Path(tp) => {
@@ -469,10 +655,112 @@ mod tests {
     }
 
     #[test]
-    #[should_panic]
     fn test_inconsistent_names_should_fail() {
         let input = TokenStream::from_str("struct A {} impl B { }").unwrap();
-        handle_blueprint(input).unwrap();
+        let error = handle_blueprint(input).unwrap_err();
+
+        assert_eq!(error.to_string(), "Only `impl A` is allowed here");
+    }
+
+    #[test]
+    fn test_auth_rule_for_unknown_method_should_fail() {
+        let input = TokenStream::from_str(
+            "#[auth(y, rule!(allow_all))] struct Test {a: u32} impl Test { pub fn x(&self) -> u32 { self.a } }",
+        )
+        .unwrap();
+        let error = handle_blueprint(input).unwrap_err();
+
+        assert_eq!(error.to_string(), "Method `y` does not exist");
+    }
+
+    #[test]
+    fn test_restrict_to_unknown_field_should_fail() {
+        let input = TokenStream::from_str(
+            "struct Test {a: u32} impl Test { #[restrict_to(b)] pub fn x(&self) -> u32 { self.a } }",
+        )
+        .unwrap();
+        let error = handle_blueprint(input).unwrap_err();
+
+        assert_eq!(error.to_string(), "Field `b` does not exist");
+    }
+
+    #[test]
+    fn test_auth_rule_on_function_should_fail() {
+        let input = TokenStream::from_str(
+            "struct Test {a: u32} impl Test { #[auth(rule!(allow_all))] pub fn x() -> u32 { 1 } }",
+        )
+        .unwrap();
+        let error = handle_blueprint(input).unwrap_err();
+
+        assert_eq!(
+            error.to_string(),
+            "Auth attributes are only supported on public methods"
+        );
+    }
+
+    #[test]
+    fn test_abort_on_err_without_result_should_fail() {
+        let item: ImplItem = parse_quote! {
+            #[abort_on_err]
+            pub fn x(&self) -> u32 { self.a }
+        };
+        let error = generate_dispatcher(&format_ident!("Test"), &[item]).unwrap_err();
+
+        assert_eq!(
+            error.to_string(),
+            "`#[abort_on_err]` is only supported on public functions and methods returning a `Result`"
+        );
+    }
+
+    #[test]
+    fn test_result_method_should_return_error() {
+        let item: ImplItem = parse_quote! {
+            pub fn x(&self) -> Result<u32, String> { Ok(self.a) }
+        };
+        let (_, arm_bodies) = generate_dispatcher(&format_ident!("Test"), &[item]).unwrap();
+
+        assert_code_eq(
+            quote! { #(#arm_bodies)* },
+            quote! {
+                {
+                    let arg0 =
+                        ::scrypto::buffer::scrypto_decode::<::scrypto::component::ComponentAddress>(
+                            &calldata.args[0usize]
+                        ).unwrap();
+                    let state: blueprint::Test = borrow_component!(arg0).get_state();
+                    rtn = ::scrypto::buffer::scrypto_encode_for_radix_engine(
+                        &blueprint::Test::x(&state)
+                    );
+                }
+            },
+        );
+    }
+
+    #[test]
+    fn test_abort_on_err_method_should_abort_on_error() {
+        let item: ImplItem = parse_quote! {
+            #[abort_on_err]
+            pub fn x(&self) -> Result<u32, String> { Ok(self.a) }
+        };
+        let (_, arm_bodies) = generate_dispatcher(&format_ident!("Test"), &[item]).unwrap();
+
+        assert_code_eq(
+            quote! { #(#arm_bodies)* },
+            quote! {
+                {
+                    let arg0 =
+                        ::scrypto::buffer::scrypto_decode::<::scrypto::component::ComponentAddress>(
+                            &calldata.args[0usize]
+                        ).unwrap();
+                    let state: blueprint::Test = borrow_component!(arg0).get_state();
+                    let result = blueprint::Test::x(&state);
+                    if let Err(error) = &result {
+                        ::scrypto::core::Runtime::abort(error);
+                    }
+                    rtn = ::scrypto::buffer::scrypto_encode_for_radix_engine(&result);
+                }
+            },
+        );
     }
 
     #[test]
@@ -320,6 +320,9 @@ fn get_native_type(ty: &des::Type) -> Result<(Type, Vec<Item>)> {
                 "PackageAddress" => "::scrypto::component::PackageAddress",
                 "ComponentAddress" => "::scrypto::component::ComponentAddress",
                 "LazyMap" => "::scrypto::component::LazyMap",
+                "LazyVec" => "::scrypto::component::LazyVec",
+                "LazySet" => "::scrypto::component::LazySet",
+                "OwnedComponent" => "::scrypto::component::OwnedComponent",
                 "Hash" => "::scrypto::crypto::Hash",
                 "EcdsaPublicKey" => "::scrypto::crypto::EcdsaPublicKey",
                 "EcdsaSignature" => "::scrypto::crypto::EcdsaSignature",
@@ -16,6 +16,14 @@ use proc_macro::TokenStream;
 /// This macro will derive the dispatcher method responsible for handling invocation
 /// according to Scrypto ABI.
 ///
+/// Methods can be protected with `#[auth(rule!(...))]`, or `#[restrict_to(field)]` which
+/// requires the resource or non-fungible stored in the given state field. Rules can also be
+/// declared on the struct, as `#[auth(method, rule!(...))]`. The macro then adds the
+/// `AccessRules` on instantiation, with methods without a rule accessible by anyone.
+///
+/// Functions and methods returning a `Result` can be annotated with `#[abort_on_err]`, which
+/// aborts the transaction with the error as an application error when they return an `Err`.
+///
 /// # Example
 /// ```ignore
 /// use scrypto::prelude::*;
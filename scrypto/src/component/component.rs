@@ -5,7 +5,7 @@ use crate::component::*;
 use crate::core::*;
 use crate::engine::{api::*, call_engine};
 use crate::misc::*;
-use crate::resource::AccessRules;
+use crate::resource::{AccessRule, AccessRules};
 use crate::rust::borrow::ToOwned;
 use crate::rust::fmt;
 use crate::rust::str::FromStr;
@@ -42,6 +42,17 @@ impl LocalComponent {
         let output: CreateComponentOutput = call_engine(CREATE_COMPONENT, input);
         output.component_address
     }
+
+    /// Turns this component into one owned by the caller, which must be stored in its state.
+    pub fn own(self) -> OwnedComponent {
+        let input = CreateOwnedComponentInput {
+            blueprint_name: self.blueprint_name,
+            state: self.state,
+            access_rules_list: self.access_rules_list,
+        };
+        let output: CreateOwnedComponentOutput = call_engine(CREATE_OWNED_COMPONENT, input);
+        OwnedComponent(output.component_address)
+    }
 }
 
 /// Represents the state of a component.
@@ -101,6 +112,77 @@ impl Component {
         let output: GetComponentInfoOutput = call_engine(GET_COMPONENT_INFO, input);
         output.blueprint_name
     }
+
+    /// Updates the access rule of a method, in the access rules at the given index.
+    ///
+    /// Only allowed from within this component and if the mutability rule of the method is satisfied.
+    pub fn set_method_auth(
+        &self,
+        access_rules_index: usize,
+        method_name: &str,
+        access_rule: AccessRule,
+    ) {
+        let input = SetComponentMethodAuthInput {
+            component_address: self.0,
+            access_rules_index: access_rules_index as u32,
+            method_name: method_name.to_owned(),
+            access_rule,
+        };
+        let _: SetComponentMethodAuthOutput = call_engine(SET_COMPONENT_METHOD_AUTH, input);
+    }
+
+    /// Locks the access rule of a method, in the access rules at the given index.
+    ///
+    /// Only allowed from within this component and if the mutability rule of the method is satisfied.
+    pub fn lock_method_auth(&self, access_rules_index: usize, method_name: &str) {
+        let input = LockComponentMethodAuthInput {
+            component_address: self.0,
+            access_rules_index: access_rules_index as u32,
+            method_name: method_name.to_owned(),
+        };
+        let _: LockComponentMethodAuthOutput = call_engine(LOCK_COMPONENT_METHOD_AUTH, input);
+    }
+
+    /// Deletes this component, along with its vaults, lazy maps and owned components.
+    ///
+    /// Only allowed from within this component, and if all its vaults are empty.
+    pub fn delete(&self) {
+        let input = DeleteComponentInput {
+            component_address: self.0,
+        };
+        let _: DeleteComponentOutput = call_engine(DELETE_COMPONENT, input);
+    }
+
+    /// Migrates this component to the latest version of its package, returning the new version.
+    ///
+    /// The state is converted by the `migrate` function of the new blueprint, which takes the
+    /// old state bytes and returns the new ones. Only allowed from within this component, by a
+    /// method which may write state, and that method must not update the state afterwards.
+    pub fn migrate(&self) -> u32 {
+        let input = MigrateComponentInput {
+            component_address: self.0,
+        };
+        let output: MigrateComponentOutput = call_engine(MIGRATE_COMPONENT, input);
+        output.package_version
+    }
+}
+
+/// A component which is owned by another component and can only be invoked by its owner.
+#[derive(PartialEq, Eq, Hash)]
+pub struct OwnedComponent(pub ComponentAddress);
+
+impl OwnedComponent {
+    /// Invokes a method on this component.
+    pub fn call<T: Decode>(&self, method: &str, args: Vec<Vec<u8>>) -> T {
+        let output = Runtime::call_method(self.0, method, args);
+
+        scrypto_decode(&output).unwrap()
+    }
+
+    /// Returns the address of this component.
+    pub fn component_address(&self) -> ComponentAddress {
+        self.0
+    }
 }
 
 //========
@@ -148,6 +230,22 @@ impl ComponentAddress {
 
 scrypto_type!(ComponentAddress, ScryptoType::ComponentAddress, Vec::new());
 
+impl TryFrom<&[u8]> for OwnedComponent {
+    type Error = ParseComponentAddressError;
+
+    fn try_from(slice: &[u8]) -> Result<Self, Self::Error> {
+        ComponentAddress::try_from(slice).map(Self)
+    }
+}
+
+impl OwnedComponent {
+    pub fn to_vec(&self) -> Vec<u8> {
+        self.0.to_vec()
+    }
+}
+
+scrypto_type!(OwnedComponent, ScryptoType::OwnedComponent, Vec::new());
+
 //======
 // text
 //======
@@ -178,3 +276,9 @@ impl fmt::Debug for ComponentAddress {
         write!(f, "{}", self)
     }
 }
+
+impl fmt::Debug for OwnedComponent {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        write!(f, "{}", self.0)
+    }
+}
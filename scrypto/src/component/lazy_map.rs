@@ -34,6 +34,15 @@ impl<K: Encode + Decode, V: Encode + Decode> LazyMap<K, V> {
         }
     }
 
+    /// Wraps an existing lazy map.
+    pub(crate) fn from_id(id: LazyMapId) -> Self {
+        Self {
+            id,
+            key: PhantomData,
+            value: PhantomData,
+        }
+    }
+
     /// Returns the value that is associated with the given key.
     pub fn get(&self, key: &K) -> Option<V> {
         let input = GetLazyMapEntryInput {
@@ -54,6 +63,78 @@ impl<K: Encode + Decode, V: Encode + Decode> LazyMap<K, V> {
         };
         let _: PutLazyMapEntryOutput = call_engine(PUT_LAZY_MAP_ENTRY, input);
     }
+
+    /// Removes the entry of the given key, returning its value if it exists.
+    pub fn remove(&self, key: &K) -> Option<V> {
+        let input = RemoveLazyMapEntryInput {
+            lazy_map_id: self.id,
+            key: scrypto_encode(key),
+        };
+        let output: RemoveLazyMapEntryOutput = call_engine(REMOVE_LAZY_MAP_ENTRY, input);
+
+        output.value.map(|v| scrypto_decode(&v).unwrap())
+    }
+
+    /// Returns true if this map contains an entry for the given key.
+    pub fn contains_key(&self, key: &K) -> bool {
+        let input = GetLazyMapEntryInput {
+            lazy_map_id: self.id,
+            key: scrypto_encode(key),
+        };
+        let output: GetLazyMapEntryOutput = call_engine(GET_LAZY_MAP_ENTRY, input);
+
+        output.value.is_some()
+    }
+
+    /// Returns an iterator over all entries, in the order of the encoded keys.
+    ///
+    /// All entries are loaded at once.
+    pub fn iter(&self) -> vec::IntoIter<(K, V)> {
+        self.entries()
+            .into_iter()
+            .map(|(k, v)| (scrypto_decode(&k).unwrap(), scrypto_decode(&v).unwrap()))
+            .collect::<Vec<(K, V)>>()
+            .into_iter()
+    }
+
+    /// Returns an iterator over all keys, in the order of the encoded keys.
+    ///
+    /// Only the keys are loaded.
+    pub fn keys(&self) -> vec::IntoIter<K> {
+        self.encoded_keys()
+            .into_iter()
+            .map(|k| scrypto_decode(&k).unwrap())
+            .collect::<Vec<K>>()
+            .into_iter()
+    }
+
+    /// Returns the number of entries in this map.
+    pub fn len(&self) -> usize {
+        self.encoded_keys().len()
+    }
+
+    /// Returns true if this map has no entries.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    fn entries(&self) -> Vec<(Vec<u8>, Vec<u8>)> {
+        let input = GetLazyMapEntriesInput {
+            lazy_map_id: self.id,
+        };
+        let output: GetLazyMapEntriesOutput = call_engine(GET_LAZY_MAP_ENTRIES, input);
+
+        output.entries
+    }
+
+    fn encoded_keys(&self) -> Vec<Vec<u8>> {
+        let input = GetLazyMapKeysInput {
+            lazy_map_id: self.id,
+        };
+        let output: GetLazyMapKeysOutput = call_engine(GET_LAZY_MAP_KEYS, input);
+
+        output.keys
+    }
 }
 
 //========
@@ -81,85 +162,94 @@ impl fmt::Display for ParseLazyMapError {
 // binary
 //========
 
-impl<K: Encode + Decode, V: Encode + Decode> TryFrom<&[u8]> for LazyMap<K, V> {
-    type Error = ParseLazyMapError;
-
-    fn try_from(slice: &[u8]) -> Result<Self, Self::Error> {
-        match slice.len() {
-            36 => Ok(Self {
-                id: (
-                    Hash(copy_u8_array(&slice[0..32])),
-                    u32::from_le_bytes(copy_u8_array(&slice[32..])),
-                ),
-                key: PhantomData,
-                value: PhantomData,
-            }),
-            _ => Err(ParseLazyMapError::InvalidLength(slice.len())),
+/// A macro to implement the binary and text formats of a lazy map backed type, which has a
+/// `from_id` constructor and is encoded as its lazy map id.
+///
+/// Types encoded otherwise implement `TryFrom<&[u8]>` and `to_vec` themselves, and only use the
+/// `@formats` arm.
+macro_rules! lazy_map_type {
+    ($t:ident<$($g:ident),+>, $ct:expr) => {
+        impl<$($g: Encode + Decode),+> TryFrom<&[u8]> for $t<$($g),+> {
+            type Error = ParseLazyMapError;
+
+            fn try_from(slice: &[u8]) -> Result<Self, Self::Error> {
+                match slice.len() {
+                    36 => Ok(Self::from_id((
+                        Hash(copy_u8_array(&slice[0..32])),
+                        u32::from_le_bytes(copy_u8_array(&slice[32..])),
+                    ))),
+                    _ => Err(ParseLazyMapError::InvalidLength(slice.len())),
+                }
+            }
         }
-    }
-}
 
-impl<K: Encode + Decode, V: Encode + Decode> LazyMap<K, V> {
-    pub fn to_vec(&self) -> Vec<u8> {
-        let mut v = self.id.0.to_vec();
-        v.extend(self.id.1.to_le_bytes());
-        v
-    }
-}
+        impl<$($g: Encode + Decode),+> $t<$($g),+> {
+            pub fn to_vec(&self) -> Vec<u8> {
+                let mut v = self.id.0.to_vec();
+                v.extend(self.id.1.to_le_bytes());
+                v
+            }
+        }
 
-impl<K: Encode + Decode, V: Encode + Decode> TypeId for LazyMap<K, V> {
-    #[inline]
-    fn type_id() -> u8 {
-        ScryptoType::LazyMap.id()
-    }
-}
+        lazy_map_type!(@formats $t<$($g),+>, $ct);
+    };
+    (@formats $t:ident<$($g:ident),+>, $ct:expr) => {
+        impl<$($g: Encode + Decode),+> TypeId for $t<$($g),+> {
+            #[inline]
+            fn type_id() -> u8 {
+                $ct.id()
+            }
+        }
 
-impl<K: Encode + Decode, V: Encode + Decode> Encode for LazyMap<K, V> {
-    fn encode_value(&self, encoder: &mut Encoder) {
-        let bytes = self.to_vec();
-        encoder.write_len(bytes.len());
-        encoder.write_slice(&bytes);
-    }
-}
+        impl<$($g: Encode + Decode),+> Encode for $t<$($g),+> {
+            fn encode_value(&self, encoder: &mut Encoder) {
+                let bytes = self.to_vec();
+                encoder.write_len(bytes.len());
+                encoder.write_slice(&bytes);
+            }
+        }
 
-impl<K: Encode + Decode, V: Encode + Decode> Decode for LazyMap<K, V> {
-    fn decode_value(decoder: &mut Decoder) -> Result<Self, DecodeError> {
-        let len = decoder.read_len()?;
-        let slice = decoder.read_bytes(len)?;
-        Self::try_from(slice).map_err(|_| DecodeError::InvalidCustomData(ScryptoType::LazyMap.id()))
-    }
-}
+        impl<$($g: Encode + Decode),+> Decode for $t<$($g),+> {
+            fn decode_value(decoder: &mut Decoder) -> Result<Self, DecodeError> {
+                let len = decoder.read_len()?;
+                let slice = decoder.read_bytes(len)?;
+                Self::try_from(slice).map_err(|_| DecodeError::InvalidCustomData($ct.id()))
+            }
+        }
 
-impl<K: Encode + Decode + Describe, V: Encode + Decode + Describe> Describe for LazyMap<K, V> {
-    fn describe() -> Type {
-        Type::Custom {
-            name: ScryptoType::LazyMap.name(),
-            generics: vec![K::describe(), V::describe()],
+        impl<$($g: Encode + Decode + Describe),+> Describe for $t<$($g),+> {
+            fn describe() -> sbor::describe::Type {
+                sbor::describe::Type::Custom {
+                    name: $ct.name(),
+                    generics: vec![$($g::describe()),+],
+                }
+            }
         }
-    }
-}
 
-//======
-// text
-//======
+        impl<$($g: Encode + Decode),+> FromStr for $t<$($g),+> {
+            type Err = ParseLazyMapError;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                let bytes =
+                    hex::decode(s).map_err(|_| ParseLazyMapError::InvalidHex(s.to_owned()))?;
+                Self::try_from(bytes.as_slice())
+            }
+        }
 
-impl<K: Encode + Decode, V: Encode + Decode> FromStr for LazyMap<K, V> {
-    type Err = ParseLazyMapError;
+        impl<$($g: Encode + Decode),+> fmt::Display for $t<$($g),+> {
+            fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+                write!(f, "{}", hex::encode(self.to_vec()))
+            }
+        }
 
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let bytes = hex::decode(s).map_err(|_| ParseLazyMapError::InvalidHex(s.to_owned()))?;
-        Self::try_from(bytes.as_slice())
-    }
+        impl<$($g: Encode + Decode),+> fmt::Debug for $t<$($g),+> {
+            fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+                write!(f, "{}", self)
+            }
+        }
+    };
 }
 
-impl<K: Encode + Decode, V: Encode + Decode> fmt::Display for LazyMap<K, V> {
-    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
-        write!(f, "{}", hex::encode(self.to_vec()))
-    }
-}
+pub(crate) use lazy_map_type;
 
-impl<K: Encode + Decode, V: Encode + Decode> fmt::Debug for LazyMap<K, V> {
-    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
-        write!(f, "{}", self)
-    }
-}
+lazy_map_type!(LazyMap<K, V>, ScryptoType::LazyMap);
@@ -0,0 +1,113 @@
+use sbor::*;
+
+use super::lazy_map::{lazy_map_type, ParseLazyMapError};
+use crate::buffer::*;
+use crate::crypto::*;
+use crate::engine::{api::*, call_engine, types::LazyMapId};
+use crate::misc::*;
+use crate::rust::borrow::ToOwned;
+use crate::rust::fmt;
+use crate::rust::marker::PhantomData;
+use crate::rust::str::FromStr;
+use crate::rust::vec;
+use crate::rust::vec::Vec;
+use crate::types::*;
+
+/// A scalable set which loads elements on demand.
+///
+/// Elements are stored as the keys of lazy map entries.
+#[derive(PartialEq, Eq, Hash)]
+pub struct LazySet<T: Encode + Decode> {
+    pub id: LazyMapId,
+    pub element: PhantomData<T>,
+}
+
+impl<T: Encode + Decode> LazySet<T> {
+    /// Creates a new lazy set.
+    pub fn new() -> Self {
+        let input = CreateLazyMapInput {};
+        let output: CreateLazyMapOutput = call_engine(CREATE_LAZY_MAP, input);
+
+        Self {
+            id: output.lazy_map_id,
+            element: PhantomData,
+        }
+    }
+
+    /// Wraps an existing lazy map.
+    pub(crate) fn from_id(id: LazyMapId) -> Self {
+        Self {
+            id,
+            element: PhantomData,
+        }
+    }
+
+    /// Returns true if this set contains the given element.
+    pub fn contains(&self, element: &T) -> bool {
+        let input = GetLazyMapEntryInput {
+            lazy_map_id: self.id,
+            key: scrypto_encode(element),
+        };
+        let output: GetLazyMapEntryOutput = call_engine(GET_LAZY_MAP_ENTRY, input);
+
+        output.value.is_some()
+    }
+
+    /// Adds an element to this set, returning false if it was already present.
+    pub fn insert(&self, element: T) -> bool {
+        if self.contains(&element) {
+            return false;
+        }
+
+        let input = PutLazyMapEntryInput {
+            lazy_map_id: self.id,
+            key: scrypto_encode(&element),
+            value: scrypto_encode(&()),
+        };
+        let _: PutLazyMapEntryOutput = call_engine(PUT_LAZY_MAP_ENTRY, input);
+        true
+    }
+
+    /// Removes an element from this set, returning true if it was present.
+    pub fn remove(&self, element: &T) -> bool {
+        let input = RemoveLazyMapEntryInput {
+            lazy_map_id: self.id,
+            key: scrypto_encode(element),
+        };
+        let output: RemoveLazyMapEntryOutput = call_engine(REMOVE_LAZY_MAP_ENTRY, input);
+
+        output.value.is_some()
+    }
+
+    /// Returns an iterator over all elements, in the order of the encoded elements.
+    ///
+    /// All elements are loaded at once.
+    pub fn iter(&self) -> vec::IntoIter<T> {
+        self.elements()
+            .into_iter()
+            .map(|k| scrypto_decode(&k).unwrap())
+            .collect::<Vec<T>>()
+            .into_iter()
+    }
+
+    /// Returns the number of elements in this set.
+    pub fn len(&self) -> usize {
+        self.elements().len()
+    }
+
+    /// Returns true if this set has no elements.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    fn elements(&self) -> Vec<Vec<u8>> {
+        let input = GetLazyMapKeysInput {
+            lazy_map_id: self.id,
+        };
+        let output: GetLazyMapKeysOutput = call_engine(GET_LAZY_MAP_KEYS, input);
+
+        output.keys
+    }
+}
+
+lazy_map_type!(LazySet<T>, ScryptoType::LazySet);
@@ -0,0 +1,183 @@
+use sbor::*;
+
+use super::lazy_map::{lazy_map_type, ParseLazyMapError};
+use crate::buffer::*;
+use crate::crypto::*;
+use crate::engine::{api::*, call_engine, types::LazyMapId};
+use crate::misc::*;
+use crate::rust::borrow::ToOwned;
+use crate::rust::fmt;
+use crate::rust::marker::PhantomData;
+use crate::rust::str::FromStr;
+use crate::rust::vec;
+use crate::rust::vec::Vec;
+use crate::types::*;
+
+/// A scalable vector which loads elements on demand.
+///
+/// Elements are stored as lazy map entries, keyed by index, while the length is stored along
+/// with the lazy map id in the vector itself.
+#[derive(PartialEq, Eq, Hash)]
+pub struct LazyVec<T: Encode + Decode> {
+    pub id: LazyMapId,
+    pub len: u32,
+    pub element: PhantomData<T>,
+}
+
+impl<T: Encode + Decode> LazyVec<T> {
+    /// Creates a new lazy vector.
+    pub fn new() -> Self {
+        let input = CreateLazyMapInput {};
+        let output: CreateLazyMapOutput = call_engine(CREATE_LAZY_MAP, input);
+
+        Self {
+            id: output.lazy_map_id,
+            len: 0,
+            element: PhantomData,
+        }
+    }
+
+    /// Returns the number of elements in this vector.
+    pub fn len(&self) -> usize {
+        self.len as usize
+    }
+
+    /// Returns true if this vector has no elements.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the element at the given index, if it exists.
+    pub fn get(&self, index: usize) -> Option<T> {
+        if index >= self.len() {
+            return None;
+        }
+        self.get_entry(Self::index_key(index))
+            .map(|v| scrypto_decode(&v).unwrap())
+    }
+
+    /// Replaces the element at the given index.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn set(&self, index: usize, element: T) {
+        if index >= self.len() {
+            panic!(
+                "Index out of bounds: the len is {} but the index is {}",
+                self.len, index
+            );
+        }
+        self.put_entry(Self::index_key(index), scrypto_encode(&element));
+    }
+
+    /// Appends an element to the back of this vector.
+    pub fn push(&mut self, element: T) {
+        self.put_entry(Self::index_key(self.len()), scrypto_encode(&element));
+        self.len += 1;
+    }
+
+    /// Removes the last element from this vector and returns it, or `None` if it is empty.
+    ///
+    /// A popped vault is moved to the caller, who must store it elsewhere or drop it.
+    pub fn pop(&mut self) -> Option<T> {
+        if self.len == 0 {
+            return None;
+        }
+
+        self.len -= 1;
+        let input = RemoveLazyMapEntryInput {
+            lazy_map_id: self.id,
+            key: Self::index_key(self.len()),
+        };
+        let output: RemoveLazyMapEntryOutput = call_engine(REMOVE_LAZY_MAP_ENTRY, input);
+
+        output.value.map(|v| scrypto_decode(&v).unwrap())
+    }
+
+    /// Returns an iterator over all elements, which are loaded one at a time.
+    pub fn iter(&self) -> LazyVecIter<'_, T> {
+        LazyVecIter {
+            vec: self,
+            index: 0,
+        }
+    }
+
+    fn index_key(index: usize) -> Vec<u8> {
+        scrypto_encode(&(index as u32))
+    }
+
+    fn get_entry(&self, key: Vec<u8>) -> Option<Vec<u8>> {
+        let input = GetLazyMapEntryInput {
+            lazy_map_id: self.id,
+            key,
+        };
+        let output: GetLazyMapEntryOutput = call_engine(GET_LAZY_MAP_ENTRY, input);
+
+        output.value
+    }
+
+    fn put_entry(&self, key: Vec<u8>, value: Vec<u8>) {
+        let input = PutLazyMapEntryInput {
+            lazy_map_id: self.id,
+            key,
+            value,
+        };
+        let _: PutLazyMapEntryOutput = call_engine(PUT_LAZY_MAP_ENTRY, input);
+    }
+}
+
+/// An iterator over the elements of a lazy vector.
+pub struct LazyVecIter<'a, T: Encode + Decode> {
+    vec: &'a LazyVec<T>,
+    index: usize,
+}
+
+impl<'a, T: Encode + Decode> Iterator for LazyVecIter<'a, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index >= self.vec.len() {
+            return None;
+        }
+        let element = self
+            .vec
+            .get_entry(LazyVec::<T>::index_key(self.index))
+            .map(|v| scrypto_decode(&v).unwrap());
+        self.index += 1;
+        element
+    }
+}
+
+//========
+// binary
+//========
+
+impl<T: Encode + Decode> TryFrom<&[u8]> for LazyVec<T> {
+    type Error = ParseLazyMapError;
+
+    fn try_from(slice: &[u8]) -> Result<Self, Self::Error> {
+        match slice.len() {
+            40 => Ok(Self {
+                id: (
+                    Hash(copy_u8_array(&slice[0..32])),
+                    u32::from_le_bytes(copy_u8_array(&slice[32..36])),
+                ),
+                len: u32::from_le_bytes(copy_u8_array(&slice[36..])),
+                element: PhantomData,
+            }),
+            _ => Err(ParseLazyMapError::InvalidLength(slice.len())),
+        }
+    }
+}
+
+impl<T: Encode + Decode> LazyVec<T> {
+    pub fn to_vec(&self) -> Vec<u8> {
+        let mut v = self.id.0.to_vec();
+        v.extend(self.id.1.to_le_bytes());
+        v.extend(self.len.to_le_bytes());
+        v
+    }
+}
+
+lazy_map_type!(@formats LazyVec<T>, ScryptoType::LazyVec);
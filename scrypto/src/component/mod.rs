@@ -1,11 +1,16 @@
 mod component;
 mod lazy_map;
+mod lazy_set;
+mod lazy_vec;
 mod package;
 mod system;
 
 pub use component::{
-    Component, ComponentAddress, ComponentState, LocalComponent, ParseComponentAddressError,
+    Component, ComponentAddress, ComponentState, LocalComponent, OwnedComponent,
+    ParseComponentAddressError,
 };
 pub use lazy_map::{LazyMap, ParseLazyMapError};
+pub use lazy_set::LazySet;
+pub use lazy_vec::LazyVec;
 pub use package::{Package, PackageAddress, ParsePackageAddressError};
 pub use system::{component_system, init_component_system, ComponentSystem};
@@ -25,3 +25,18 @@ pub const RADIX_TOKEN: ResourceAddress = ResourceAddress([
 pub const ECDSA_TOKEN: ResourceAddress = ResourceAddress([
     0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 5,
 ]);
+
+/// The virtual resource address of package badges, presented by callers on behalf of their package.
+pub const PACKAGE_TOKEN: ResourceAddress = ResourceAddress([
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 6,
+]);
+
+/// The virtual resource address of blueprint badges, presented by callers on behalf of their blueprint.
+pub const BLUEPRINT_TOKEN: ResourceAddress = ResourceAddress([
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 7,
+]);
+
+/// The virtual resource address of component badges, presented by callers on behalf of their component.
+pub const COMPONENT_TOKEN: ResourceAddress = ResourceAddress([
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 8,
+]);
@@ -3,7 +3,7 @@ use sbor::*;
 use crate::component::*;
 use crate::rust::string::String;
 
-#[derive(Debug, Clone, TypeId, Encode, Decode)]
+#[derive(Debug, Clone, PartialEq, Eq, TypeId, Encode, Decode)]
 pub enum ScryptoActor {
     Blueprint(PackageAddress, String),
     Component(ComponentAddress),
@@ -0,0 +1,77 @@
+use sbor::*;
+
+use crate::buffer::scrypto_decode;
+use crate::component::*;
+use crate::core::ScryptoActor;
+use crate::engine::types::{BucketId, ProofId};
+use crate::rust::string::String;
+use crate::rust::vec::Vec;
+
+/// Represents the error of a failed try-call.
+#[derive(Debug, Clone, PartialEq, Eq, TypeId, Encode, Decode)]
+pub enum CallError {
+    /// The callee aborted with an application error, SBOR-encoded.
+    ApplicationError { actor: ScryptoActor, error: Vec<u8> },
+
+    /// The callee panicked.
+    Panic {
+        actor: ScryptoActor,
+        message: String,
+        location: String,
+    },
+
+    /// The callee code trapped.
+    WasmTrap { actor: ScryptoActor },
+
+    /// The caller was not authorized to call the function.
+    NotAuthorized { function: String },
+
+    /// The package does not exist.
+    PackageNotFound(PackageAddress),
+
+    /// The blueprint does not exist.
+    BlueprintNotFound(PackageAddress, String),
+
+    /// The component does not exist.
+    ComponentNotFound(ComponentAddress),
+
+    /// The method does not exist on the component.
+    MethodNotFound(ComponentAddress, String),
+
+    /// The component is in use by a call in progress.
+    ComponentReentrancy(ComponentAddress),
+
+    /// The component is owned by another component.
+    OwnedComponentNotAccessible(ComponentAddress),
+
+    /// A method declared `&self` attempted to write component state.
+    ImmutableMethodWrite(ComponentAddress, String),
+
+    /// A bucket passed to the call does not exist.
+    BucketNotFound(BucketId),
+
+    /// A proof passed to the call does not exist.
+    ProofNotFound(ProofId),
+
+    /// A bucket passed to the call is locked by a proof.
+    CantMoveLockedBucket,
+
+    /// A proof passed to the call is restricted.
+    CantMoveRestrictedProof(ProofId),
+
+    /// The callee left resources behind without storing or returning them.
+    ResourceCheckFailure,
+
+    /// Any other engine error, with its description.
+    Other(String),
+}
+
+impl CallError {
+    /// Decodes the application error of the callee, if it aborted with one of this type.
+    pub fn application_error<E: Decode>(&self) -> Option<E> {
+        match self {
+            CallError::ApplicationError { error, .. } => scrypto_decode(error).ok(),
+            _ => None,
+        }
+    }
+}
@@ -1,3 +1,4 @@
+use crate::component::PackageAddress;
 use crate::core::ScryptoActor;
 use crate::engine::types::{BucketId, ProofId, VaultId};
 use crate::resource::ResourceAddress;
@@ -7,6 +8,7 @@ use sbor::*;
 #[derive(Debug, Clone, TypeId, Encode, Decode)]
 pub enum SNodeRef {
     PackageStatic,
+    PackageRef(PackageAddress),
     AuthZoneRef,
     WorktopRef,
     Scrypto(ScryptoActor),
@@ -1,10 +1,12 @@
 mod actor;
+mod call_error;
 mod invocation;
 mod level;
 mod logger;
 mod runtime;
 
 pub use actor::{ScryptoActor, ScryptoActorInfo};
+pub use call_error::CallError;
 pub use invocation::SNodeRef;
 pub use level::Level;
 pub use logger::Logger;
@@ -1,8 +1,12 @@
+use sbor::{Describe, Encode};
+
+use crate::buffer::scrypto_encode;
 use crate::component::*;
 use crate::core::*;
 use crate::crypto::*;
 use crate::engine::{api::*, call_engine};
 use crate::rust::borrow::ToOwned;
+use crate::rust::string::String;
 use crate::rust::vec::Vec;
 
 /// The transaction runtime.
@@ -18,6 +22,14 @@ impl Runtime {
         output.actor
     }
 
+    /// Returns the calling entity, a component or blueprint, or `None` if called by the
+    /// transaction.
+    pub fn caller() -> Option<ScryptoActor> {
+        let input = GetCallerInput {};
+        let output: GetCallerOutput = call_engine(GET_CALLER, input);
+        output.caller
+    }
+
     /// Returns the package ID.
     pub fn package_address() -> PackageAddress {
         let input = GetActorInput {};
@@ -69,6 +81,48 @@ impl Runtime {
         output.rtn
     }
 
+    /// Invokes a function on a blueprint, rolling back its changes if it fails.
+    ///
+    /// On failure, the resources passed to the function are given back, and the error
+    /// is returned.
+    pub fn try_call_function<S: AsRef<str>>(
+        package_address: PackageAddress,
+        blueprint_name: S,
+        function: S,
+        args: Vec<Vec<u8>>,
+    ) -> Result<Vec<u8>, CallError> {
+        let input = TryInvokeSNodeInput {
+            snode_ref: SNodeRef::Scrypto(ScryptoActor::Blueprint(
+                package_address,
+                blueprint_name.as_ref().to_owned(),
+            )),
+            function: function.as_ref().to_owned(),
+            args,
+        };
+        let output: TryInvokeSNodeOutput = call_engine(TRY_INVOKE_SNODE, input);
+
+        output.rtn
+    }
+
+    /// Invokes a method on a component, rolling back its changes if it fails.
+    ///
+    /// On failure, the resources passed to the method are given back, and the error
+    /// is returned.
+    pub fn try_call_method<S: AsRef<str>>(
+        component_address: ComponentAddress,
+        method: S,
+        args: Vec<Vec<u8>>,
+    ) -> Result<Vec<u8>, CallError> {
+        let input = TryInvokeSNodeInput {
+            snode_ref: SNodeRef::Scrypto(ScryptoActor::Component(component_address)),
+            function: method.as_ref().to_owned(),
+            args,
+        };
+        let output: TryInvokeSNodeOutput = call_engine(TRY_INVOKE_SNODE, input);
+
+        output.rtn
+    }
+
     /// Returns the transaction hash.
     pub fn transaction_hash() -> Hash {
         let input = GetTransactionHashInput {};
@@ -76,10 +130,26 @@ impl Runtime {
         output.transaction_hash
     }
 
+    /// Returns the public keys of the transaction signers.
+    pub fn transaction_signers() -> Vec<EcdsaPublicKey> {
+        let input = GetTransactionSignersInput {};
+        let output: GetTransactionSignersOutput = call_engine(GET_TRANSACTION_SIGNERS, input);
+        output.transaction_signers
+    }
+
     /// Returns the current epoch number.
     pub fn current_epoch() -> u64 {
         let input = GetCurrentEpochInput {};
         let output: GetCurrentEpochOutput = call_engine(GET_CURRENT_EPOCH, input);
         output.current_epoch
     }
+
+    /// Aborts the transaction with an application error.
+    pub fn abort<E: Encode + Describe>(error: &E) -> ! {
+        let input = AbortInput {
+            error: scrypto_encode(error),
+        };
+        let _: AbortOutput = call_engine(ABORT, input);
+        panic!("Transaction should have been aborted")
+    }
 }
@@ -1,4 +1,4 @@
-use crate::core::SNodeRef;
+use crate::core::{CallError, SNodeRef, ScryptoActor};
 use sbor::*;
 use scrypto::prelude::{AccessRule, AccessRules};
 
@@ -20,6 +20,16 @@ pub const GET_COMPONENT_INFO: u32 = 0x11;
 pub const GET_COMPONENT_STATE: u32 = 0x12;
 /// Update component state
 pub const PUT_COMPONENT_STATE: u32 = 0x13;
+/// Update the access rule of a component method
+pub const SET_COMPONENT_METHOD_AUTH: u32 = 0x14;
+/// Lock the access rule of a component method
+pub const LOCK_COMPONENT_METHOD_AUTH: u32 = 0x15;
+/// Create a component owned by the calling component
+pub const CREATE_OWNED_COMPONENT: u32 = 0x16;
+/// Delete a component and all its child substates
+pub const DELETE_COMPONENT: u32 = 0x17;
+/// Migrate a component to the latest version of its package
+pub const MIGRATE_COMPONENT: u32 = 0x18;
 
 /// Create a lazy map
 pub const CREATE_LAZY_MAP: u32 = 0x20;
@@ -27,11 +37,21 @@ pub const CREATE_LAZY_MAP: u32 = 0x20;
 pub const GET_LAZY_MAP_ENTRY: u32 = 0x21;
 /// Insert a key-value pair into a lazy map
 pub const PUT_LAZY_MAP_ENTRY: u32 = 0x22;
+/// Remove an entry from a lazy map
+pub const REMOVE_LAZY_MAP_ENTRY: u32 = 0x23;
+/// Retrieve all entries of a lazy map
+pub const GET_LAZY_MAP_ENTRIES: u32 = 0x24;
+/// Retrieve all keys of a lazy map
+pub const GET_LAZY_MAP_KEYS: u32 = 0x25;
 
 /// Create an empty vault
 pub const CREATE_EMPTY_VAULT: u32 = 0x40;
+/// Drop an empty vault
+pub const DROP_EMPTY_VAULT: u32 = 0x41;
 
 pub const INVOKE_SNODE: u32 = 0x70;
+/// Invoke an snode, rolling back its changes if it fails
+pub const TRY_INVOKE_SNODE: u32 = 0x71;
 
 /// Log a message
 pub const EMIT_LOG: u32 = 0xf0;
@@ -49,6 +69,15 @@ pub const GET_ACTOR: u32 = 0xf5;
 /// Check that an access rule is satisfied
 pub const CHECK_ACCESS_RULE: u32 = 0xf6;
 
+/// Report a panic, which aborts the transaction
+pub const PANIC: u32 = 0xf7;
+/// Abort the transaction with an application error
+pub const ABORT: u32 = 0xf8;
+/// Retrieve the public keys of the transaction signers
+pub const GET_TRANSACTION_SIGNERS: u32 = 0xf9;
+/// Retrieve the calling entity
+pub const GET_CALLER: u32 = 0xfa;
+
 #[derive(Debug, TypeId, Encode, Decode)]
 pub struct InvokeSNodeInput {
     pub snode_ref: SNodeRef,
@@ -61,6 +90,18 @@ pub struct InvokeSNodeOutput {
     pub rtn: Vec<u8>,
 }
 
+#[derive(Debug, TypeId, Encode, Decode)]
+pub struct TryInvokeSNodeInput {
+    pub snode_ref: SNodeRef,
+    pub function: String,
+    pub args: Vec<Vec<u8>>,
+}
+
+#[derive(Debug, TypeId, Encode, Decode)]
+pub struct TryInvokeSNodeOutput {
+    pub rtn: Result<Vec<u8>, CallError>,
+}
+
 //==========
 // component
 //==========
@@ -104,6 +145,57 @@ pub struct PutComponentStateInput {
 #[derive(Debug, TypeId, Encode, Decode)]
 pub struct PutComponentStateOutput {}
 
+#[derive(Debug, TypeId, Encode, Decode)]
+pub struct SetComponentMethodAuthInput {
+    pub component_address: ComponentAddress,
+    pub access_rules_index: u32,
+    pub method_name: String,
+    pub access_rule: AccessRule,
+}
+
+#[derive(Debug, TypeId, Encode, Decode)]
+pub struct SetComponentMethodAuthOutput {}
+
+#[derive(Debug, TypeId, Encode, Decode)]
+pub struct LockComponentMethodAuthInput {
+    pub component_address: ComponentAddress,
+    pub access_rules_index: u32,
+    pub method_name: String,
+}
+
+#[derive(Debug, TypeId, Encode, Decode)]
+pub struct LockComponentMethodAuthOutput {}
+
+#[derive(Debug, TypeId, Encode, Decode)]
+pub struct CreateOwnedComponentInput {
+    pub blueprint_name: String,
+    pub state: Vec<u8>,
+    pub access_rules_list: Vec<AccessRules>,
+}
+
+#[derive(Debug, TypeId, Encode, Decode)]
+pub struct CreateOwnedComponentOutput {
+    pub component_address: ComponentAddress,
+}
+
+#[derive(Debug, TypeId, Encode, Decode)]
+pub struct DeleteComponentInput {
+    pub component_address: ComponentAddress,
+}
+
+#[derive(Debug, TypeId, Encode, Decode)]
+pub struct DeleteComponentOutput {}
+
+#[derive(Debug, TypeId, Encode, Decode)]
+pub struct MigrateComponentInput {
+    pub component_address: ComponentAddress,
+}
+
+#[derive(Debug, TypeId, Encode, Decode)]
+pub struct MigrateComponentOutput {
+    pub package_version: u32,
+}
+
 //==========
 // LazyMap
 //==========
@@ -137,6 +229,39 @@ pub struct PutLazyMapEntryInput {
 #[derive(Debug, TypeId, Encode, Decode)]
 pub struct PutLazyMapEntryOutput {}
 
+#[derive(Debug, TypeId, Encode, Decode)]
+pub struct RemoveLazyMapEntryInput {
+    pub lazy_map_id: LazyMapId,
+    pub key: Vec<u8>,
+}
+
+#[derive(Debug, TypeId, Encode, Decode)]
+pub struct RemoveLazyMapEntryOutput {
+    pub value: Option<Vec<u8>>,
+}
+
+#[derive(Debug, TypeId, Encode, Decode)]
+pub struct GetLazyMapEntriesInput {
+    pub lazy_map_id: LazyMapId,
+}
+
+#[derive(Debug, TypeId, Encode, Decode)]
+pub struct GetLazyMapEntriesOutput {
+    /// The entries, in the order of the encoded keys
+    pub entries: Vec<(Vec<u8>, Vec<u8>)>,
+}
+
+#[derive(Debug, TypeId, Encode, Decode)]
+pub struct GetLazyMapKeysInput {
+    pub lazy_map_id: LazyMapId,
+}
+
+#[derive(Debug, TypeId, Encode, Decode)]
+pub struct GetLazyMapKeysOutput {
+    /// The encoded keys, in order
+    pub keys: Vec<Vec<u8>>,
+}
+
 //==========
 // vault
 //==========
@@ -151,6 +276,14 @@ pub struct CreateEmptyVaultOutput {
     pub vault_id: VaultId,
 }
 
+#[derive(Debug, TypeId, Encode, Decode)]
+pub struct DropEmptyVaultInput {
+    pub vault_id: VaultId,
+}
+
+#[derive(Debug, TypeId, Encode, Decode)]
+pub struct DropEmptyVaultOutput {}
+
 //=======
 // others
 //=======
@@ -192,6 +325,11 @@ pub struct GetTransactionHashOutput {
 #[derive(Debug, TypeId, Encode, Decode)]
 pub struct GetTransactionSignersInput {}
 
+#[derive(Debug, TypeId, Encode, Decode)]
+pub struct GetTransactionSignersOutput {
+    pub transaction_signers: Vec<EcdsaPublicKey>,
+}
+
 #[derive(Debug, TypeId, Encode, Decode)]
 pub struct GenerateUuidInput {}
 
@@ -208,13 +346,38 @@ pub struct GetActorOutput {
     pub actor: ScryptoActorInfo,
 }
 
+#[derive(Debug, TypeId, Encode, Decode)]
+pub struct GetCallerInput {}
+
+#[derive(Debug, TypeId, Encode, Decode)]
+pub struct GetCallerOutput {
+    pub caller: Option<ScryptoActor>,
+}
+
 #[derive(Debug, TypeId, Encode, Decode)]
 pub struct CheckAccessRuleInput {
     pub access_rule: AccessRule,
-    pub proof_ids: Vec<ProofId>
+    pub proof_ids: Vec<ProofId>,
 }
 
 #[derive(Debug, TypeId, Encode, Decode)]
 pub struct CheckAccessRuleOutput {
-    pub is_authorized: bool
+    pub is_authorized: bool,
 }
+
+#[derive(Debug, TypeId, Encode, Decode)]
+pub struct PanicInput {
+    pub message: String,
+    pub location: String,
+}
+
+#[derive(Debug, TypeId, Encode, Decode)]
+pub struct PanicOutput {}
+
+#[derive(Debug, TypeId, Encode, Decode)]
+pub struct AbortInput {
+    pub error: Vec<u8>,
+}
+
+#[derive(Debug, TypeId, Encode, Decode)]
+pub struct AbortOutput {}
@@ -1,4 +1,5 @@
 use cargo_toml::{Manifest, Product};
+use std::collections::HashMap;
 use std::fs;
 use std::path::{Path, PathBuf};
 use std::process::Command;
@@ -48,3 +49,23 @@ pub fn compile_package<P: AsRef<Path>>(package_dir: P) -> Vec<u8> {
     // return
     fs::read(path).unwrap()
 }
+
+/// Reads the metadata of a Scrypto package from its `Cargo.toml`.
+///
+/// The `name`, `version`, `description` and `authors` fields are returned, if present.
+pub fn package_metadata<P: AsRef<Path>>(package_dir: P) -> HashMap<String, String> {
+    let mut cargo = package_dir.as_ref().to_owned();
+    cargo.push("Cargo.toml");
+    let mut metadata = HashMap::new();
+    if let Some(pkg) = Manifest::from_path(&cargo).ok().and_then(|m| m.package) {
+        metadata.insert("name".to_owned(), pkg.name);
+        metadata.insert("version".to_owned(), pkg.version);
+        if let Some(description) = pkg.description {
+            metadata.insert("description".to_owned(), description);
+        }
+        if !pkg.authors.is_empty() {
+            metadata.insert("authors".to_owned(), pkg.authors.join(", "));
+        }
+    }
+    metadata
+}
@@ -4,6 +4,6 @@ mod panic;
 mod slice;
 
 #[cfg(not(feature = "alloc"))]
-pub use cargo::compile_package;
+pub use cargo::{compile_package, package_metadata};
 pub use panic::set_up_panic_hook;
 pub use slice::{combine, copy_u8_array};
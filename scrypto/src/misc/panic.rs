@@ -25,5 +25,13 @@ pub fn set_up_panic_hook() {
             payload,
             location
         ));
+
+        // report the panic, which aborts the transaction
+        let input = crate::engine::api::PanicInput {
+            message: payload,
+            location,
+        };
+        let _: crate::engine::api::PanicOutput =
+            crate::engine::call_engine(crate::engine::api::PANIC, input);
     }));
 }
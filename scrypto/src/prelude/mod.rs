@@ -7,9 +7,9 @@ pub use crate::math::*;
 pub use crate::misc::*;
 pub use crate::resource::*;
 pub use crate::{
-    args, rule, access_and_or, access_rule_node, blueprint, borrow_component, borrow_package,
+    access_and_or, access_rule_node, args, blueprint, borrow_component, borrow_package,
     borrow_resource_manager, compile_package, debug, dec, error, import, include_package, info,
-    resource_list, trace, warn, Decode, Describe, Encode, NonFungibleData, TypeId,
+    resource_list, rule, trace, warn, Decode, Describe, Encode, NonFungibleData, TypeId,
 };
 
 pub use crate::rust::borrow::ToOwned;
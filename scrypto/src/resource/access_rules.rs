@@ -10,6 +10,7 @@ use sbor::*;
 #[derive(Debug, Clone, PartialEq, Describe, TypeId, Encode, Decode)]
 pub struct AccessRules {
     method_auth: HashMap<String, AccessRule>,
+    method_mutability: HashMap<String, Mutability>,
     default_auth: AccessRule,
 }
 
@@ -17,6 +18,7 @@ impl AccessRules {
     pub fn new() -> Self {
         Self {
             method_auth: HashMap::new(),
+            method_mutability: HashMap::new(),
             default_auth: AccessRule::DenyAll,
         }
     }
@@ -31,17 +33,40 @@ impl AccessRules {
         &self.default_auth
     }
 
+    /// Returns who may update the rule of a method; rules are locked unless specified otherwise.
+    pub fn get_mutability(&self, method_name: &str) -> &Mutability {
+        self.method_mutability
+            .get(method_name)
+            .unwrap_or(&Mutability::LOCKED)
+    }
+
     pub fn method(mut self, method_name: &str, method_auth: AccessRule) -> Self {
         self.method_auth
             .insert(method_name.to_string(), method_auth);
         self
     }
 
+    pub fn mutability(mut self, method_name: &str, mutability: Mutability) -> Self {
+        self.method_mutability
+            .insert(method_name.to_string(), mutability);
+        self
+    }
+
     pub fn default(mut self, method_auth: AccessRule) -> Self {
         self.default_auth = method_auth;
         self
     }
 
+    pub fn set_method_auth(&mut self, method_name: &str, method_auth: AccessRule) {
+        self.method_auth
+            .insert(method_name.to_string(), method_auth);
+    }
+
+    pub fn lock_method_auth(&mut self, method_name: &str) {
+        self.method_mutability
+            .insert(method_name.to_string(), Mutability::LOCKED);
+    }
+
     pub fn iter(&self) -> Iter<'_, String, AccessRule> {
         let l = self.method_auth.iter();
         l
@@ -164,7 +164,6 @@ impl Bucket {
         self.non_fungible_ids().into_iter().next().unwrap()
     }
 
-
     /// Returns a singleton non-fungible.
     ///
     /// # Panics
@@ -0,0 +1,31 @@
+use crate::buffer::scrypto_encode;
+use crate::component::{ComponentAddress, PackageAddress};
+use crate::constants::{BLUEPRINT_TOKEN, COMPONENT_TOKEN, PACKAGE_TOKEN};
+use crate::resource::*;
+
+/// Returns the virtual badge which the engine presents on behalf of callers within a package.
+pub fn package_badge(package_address: PackageAddress) -> NonFungibleAddress {
+    NonFungibleAddress::new(
+        PACKAGE_TOKEN,
+        NonFungibleId::from_bytes(package_address.to_vec()),
+    )
+}
+
+/// Returns the virtual badge which the engine presents on behalf of callers within a blueprint.
+pub fn blueprint_badge(
+    package_address: PackageAddress,
+    blueprint_name: &str,
+) -> NonFungibleAddress {
+    NonFungibleAddress::new(
+        BLUEPRINT_TOKEN,
+        NonFungibleId::from_bytes(scrypto_encode(&(package_address, blueprint_name))),
+    )
+}
+
+/// Returns the virtual badge which the engine presents on behalf of callers within a component.
+pub fn component_badge(component_address: ComponentAddress) -> NonFungibleAddress {
+    NonFungibleAddress::new(
+        COMPONENT_TOKEN,
+        NonFungibleId::from_bytes(component_address.to_vec()),
+    )
+}
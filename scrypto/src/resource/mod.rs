@@ -1,6 +1,7 @@
 mod access_rules;
 mod auth_zone;
 mod bucket;
+mod caller_badge;
 mod mint_params;
 mod non_fungible;
 mod non_fungible_address;
@@ -18,6 +19,7 @@ mod vault;
 pub use access_rules::AccessRules;
 pub use auth_zone::ComponentAuthZone;
 pub use bucket::{Bucket, ParseBucketError};
+pub use caller_badge::{blueprint_badge, component_badge, package_badge};
 pub use mint_params::MintParams;
 pub use non_fungible::NonFungible;
 pub use non_fungible_address::{NonFungibleAddress, ParseNonFungibleAddressError};
@@ -25,8 +27,8 @@ pub use non_fungible_data::NonFungibleData;
 pub use non_fungible_id::{NonFungibleId, ParseNonFungibleIdError};
 pub use proof::{ParseProofError, Proof};
 pub use proof_rule::{
-    require, require_all_of, require_amount, require_any_of, require_n_of, AccessRuleNode,
-    AccessRule, ProofRule, SoftCount, SoftDecimal, SoftResource, SoftResourceOrNonFungible,
+    require, require_all_of, require_amount, require_any_of, require_n_of, AccessRule,
+    AccessRuleNode, ProofRule, SoftCount, SoftDecimal, SoftResource, SoftResourceOrNonFungible,
     SoftResourceOrNonFungibleList,
 };
 pub use resource_builder::{ResourceBuilder, DIVISIBILITY_MAXIMUM, DIVISIBILITY_NONE};
@@ -3,9 +3,9 @@ use crate::engine::call_engine;
 use crate::resource::AccessRuleNode::{AllOf, AnyOf};
 use crate::resource::*;
 use crate::rust::borrow::ToOwned;
+use crate::rust::string::ToString;
 use crate::rust::vec;
 use crate::rust::vec::Vec;
-use crate::rust::string::ToString;
 use sbor::*;
 use scrypto::math::Decimal;
 
@@ -357,7 +357,7 @@ impl AccessRule {
     pub fn check(&self, proofs: &[Proof]) -> bool {
         let input = CheckAccessRuleInput {
             access_rule: self.clone(),
-            proof_ids: proofs.iter().map(|proof| proof.0).collect()
+            proof_ids: proofs.iter().map(|proof| proof.0).collect(),
         };
         let output: CheckAccessRuleOutput = call_engine(CHECK_ACCESS_RULE, input);
 
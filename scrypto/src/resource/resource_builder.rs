@@ -1,6 +1,6 @@
-use crate::rule;
 use crate::math::*;
 use crate::resource::*;
+use crate::rule;
 use crate::rust::borrow::ToOwned;
 use crate::rust::collections::HashMap;
 use crate::rust::string::String;
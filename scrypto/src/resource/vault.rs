@@ -40,6 +40,12 @@ impl Vault {
         vault
     }
 
+    /// Drops this vault, which must be empty.
+    pub fn drop_empty(self) {
+        let input = DropEmptyVaultInput { vault_id: self.0 };
+        let _: DropEmptyVaultOutput = call_engine(DROP_EMPTY_VAULT, input);
+    }
+
     /// Puts a bucket of resources into this vault.
     pub fn put(&mut self, bucket: Bucket) {
         let input = InvokeSNodeInput {
@@ -49,6 +49,9 @@ pub enum ScryptoType {
     PackageAddress,
     ComponentAddress,
     LazyMap,
+    LazyVec,
+    LazySet,
+    OwnedComponent,
 
     // crypto
     Hash,
@@ -68,10 +71,13 @@ pub enum ScryptoType {
 }
 
 // Need to update `scrypto-derive/src/import.rs` after changing the table below
-const MAPPING: [(ScryptoType, u8, &str); 13] = [
+const MAPPING: [(ScryptoType, u8, &str); 16] = [
     (ScryptoType::PackageAddress, 0x80, "PackageAddress"),
     (ScryptoType::ComponentAddress, 0x81, "ComponentAddress"),
     (ScryptoType::LazyMap, 0x82, "LazyMap"),
+    (ScryptoType::LazyVec, 0x83, "LazyVec"),
+    (ScryptoType::LazySet, 0x84, "LazySet"),
+    (ScryptoType::OwnedComponent, 0x85, "OwnedComponent"),
     (ScryptoType::Hash, 0x90, "Hash"),
     (ScryptoType::EcdsaPublicKey, 0x91, "EcdsaPublicKey"),
     (ScryptoType::EcdsaSignature, 0x93, "EcdsaSignature"),
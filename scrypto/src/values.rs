@@ -1,6 +1,7 @@
+use sbor::describe::Fields;
+use sbor::path::{MutableSborPath, SborPath};
 use sbor::type_id::*;
 use sbor::{any::*, *};
-use sbor::path::{MutableSborPath, SborPath};
 
 use crate::buffer::*;
 use crate::component::*;
@@ -39,6 +40,7 @@ pub struct ScryptoValue {
     pub proof_ids: HashMap<ProofId, SborPath>,
     pub vault_ids: HashSet<VaultId>,
     pub lazy_map_ids: HashSet<LazyMapId>,
+    pub owned_component_addresses: HashSet<ComponentAddress>,
 }
 
 impl ScryptoValue {
@@ -54,24 +56,38 @@ impl ScryptoValue {
         Ok(ScryptoValue {
             raw: slice.to_vec(),
             dom: value,
-            bucket_ids: checker.buckets.drain().map(|(e, path)| (e.0, path)).collect(),
-            proof_ids: checker.proofs.drain().map(|(e, path)| (e.0, path)).collect(),
+            bucket_ids: checker
+                .buckets
+                .drain()
+                .map(|(e, path)| (e.0, path))
+                .collect(),
+            proof_ids: checker
+                .proofs
+                .drain()
+                .map(|(e, path)| (e.0, path))
+                .collect(),
             vault_ids: checker.vaults.iter().map(|e| e.0).collect(),
             lazy_map_ids: checker.lazy_maps.iter().map(|e| e.id).collect(),
+            owned_component_addresses: checker.owned_components.iter().map(|e| e.0).collect(),
         })
     }
 
     pub fn replace_ids(
         &mut self,
         proof_replacements: &mut HashMap<ProofId, ProofId>,
-        bucket_replacements: &mut HashMap<BucketId, BucketId>
-    ) -> Result<(), ScryptoValueReplaceError>{
+        bucket_replacements: &mut HashMap<BucketId, BucketId>,
+    ) -> Result<(), ScryptoValueReplaceError> {
         let mut new_proof_ids = HashMap::new();
         for (proof_id, path) in self.proof_ids.drain() {
-            let next_id = proof_replacements.remove(&proof_id)
+            let next_id = proof_replacements
+                .remove(&proof_id)
                 .ok_or(ScryptoValueReplaceError::ProofIdNotFound(proof_id))?;
             let value = path.get_from_value_mut(&mut self.dom).unwrap();
-            if let Value::Custom { type_id: _, ref mut bytes} = value {
+            if let Value::Custom {
+                type_id: _,
+                ref mut bytes,
+            } = value
+            {
                 *bytes = scrypto::resource::Proof(next_id).to_vec();
             } else {
                 panic!("Proof Id should be custom type");
@@ -83,10 +99,15 @@ impl ScryptoValue {
 
         let mut new_bucket_ids = HashMap::new();
         for (bucket_id, path) in self.bucket_ids.drain() {
-            let next_id = bucket_replacements.remove(&bucket_id)
+            let next_id = bucket_replacements
+                .remove(&bucket_id)
                 .ok_or(ScryptoValueReplaceError::BucketIdNotFound(bucket_id))?;
             let value = path.get_from_value_mut(&mut self.dom).unwrap();
-            if let Value::Custom { type_id: _, ref mut bytes} = value {
+            if let Value::Custom {
+                type_id: _,
+                ref mut bytes,
+            } = value
+            {
                 *bytes = scrypto::resource::Bucket(next_id).to_vec();
             } else {
                 panic!("Bucket should be custom type");
@@ -119,6 +140,192 @@ impl ScryptoValue {
     ) -> String {
         ScryptoValueFormatter::format_value(&self.dom, bucket_ids, proof_ids)
     }
+
+    /// Checks whether this value conforms to the given type schema.
+    ///
+    /// Custom types which are not Scrypto types are not checked, but custom values must still
+    /// be of a known Scrypto type.
+    pub fn matches_schema(&self, schema: &Type) -> bool {
+        value_matches_type(&self.dom, schema)
+    }
+}
+
+fn schema_type_id(ty: &Type) -> Option<u8> {
+    match ty {
+        Type::Unit => Some(TYPE_UNIT),
+        Type::Bool => Some(TYPE_BOOL),
+        Type::I8 => Some(TYPE_I8),
+        Type::I16 => Some(TYPE_I16),
+        Type::I32 => Some(TYPE_I32),
+        Type::I64 => Some(TYPE_I64),
+        Type::I128 => Some(TYPE_I128),
+        Type::U8 => Some(TYPE_U8),
+        Type::U16 => Some(TYPE_U16),
+        Type::U32 => Some(TYPE_U32),
+        Type::U64 => Some(TYPE_U64),
+        Type::U128 => Some(TYPE_U128),
+        Type::String => Some(TYPE_STRING),
+        Type::Option { .. } => Some(TYPE_OPTION),
+        Type::Array { .. } => Some(TYPE_ARRAY),
+        Type::Tuple { .. } => Some(TYPE_TUPLE),
+        Type::Struct { .. } => Some(TYPE_STRUCT),
+        Type::Enum { .. } => Some(TYPE_ENUM),
+        Type::Result { .. } => Some(TYPE_RESULT),
+        Type::Vec { .. } => Some(TYPE_VEC),
+        Type::TreeSet { .. } => Some(TYPE_TREE_SET),
+        Type::TreeMap { .. } => Some(TYPE_TREE_MAP),
+        Type::HashSet { .. } => Some(TYPE_HASH_SET),
+        Type::HashMap { .. } => Some(TYPE_HASH_MAP),
+        Type::Custom { name, .. } => ScryptoType::from_name(name).map(|t| t.id()),
+    }
+}
+
+fn type_id_matches(type_id: u8, ty: &Type) -> bool {
+    schema_type_id(ty).map(|id| id == type_id).unwrap_or(true)
+}
+
+fn fields_match(values: &[Value], fields: &Fields) -> bool {
+    match fields {
+        Fields::Named { named } => {
+            values.len() == named.len()
+                && values
+                    .iter()
+                    .zip(named)
+                    .all(|(v, (_, t))| value_matches_type(v, t))
+        }
+        Fields::Unnamed { unnamed } => {
+            values.len() == unnamed.len()
+                && values
+                    .iter()
+                    .zip(unnamed)
+                    .all(|(v, t)| value_matches_type(v, t))
+        }
+        Fields::Unit => values.is_empty(),
+    }
+}
+
+fn elements_match(element_type_id: u8, elements: &[Value], element: &Type) -> bool {
+    type_id_matches(element_type_id, element)
+        && elements.iter().all(|e| value_matches_type(e, element))
+}
+
+fn entries_match(
+    key_type_id: u8,
+    value_type_id: u8,
+    elements: &[Value],
+    key: &Type,
+    value: &Type,
+) -> bool {
+    type_id_matches(key_type_id, key)
+        && type_id_matches(value_type_id, value)
+        && elements
+            .chunks(2)
+            .all(|pair| value_matches_type(&pair[0], key) && value_matches_type(&pair[1], value))
+}
+
+fn value_matches_type(value: &Value, ty: &Type) -> bool {
+    match (value, ty) {
+        (Value::Unit, Type::Unit)
+        | (Value::Bool { .. }, Type::Bool)
+        | (Value::I8 { .. }, Type::I8)
+        | (Value::I16 { .. }, Type::I16)
+        | (Value::I32 { .. }, Type::I32)
+        | (Value::I64 { .. }, Type::I64)
+        | (Value::I128 { .. }, Type::I128)
+        | (Value::U8 { .. }, Type::U8)
+        | (Value::U16 { .. }, Type::U16)
+        | (Value::U32 { .. }, Type::U32)
+        | (Value::U64 { .. }, Type::U64)
+        | (Value::U128 { .. }, Type::U128)
+        | (Value::String { .. }, Type::String) => true,
+        (Value::Option { value: v }, Type::Option { value: t }) => match v.borrow() {
+            Some(v) => value_matches_type(v, t),
+            None => true,
+        },
+        (
+            Value::Array {
+                element_type_id,
+                elements,
+            },
+            Type::Array { element, length },
+        ) => {
+            elements.len() == *length as usize
+                && elements_match(*element_type_id, elements, element)
+        }
+        (Value::Tuple { elements }, Type::Tuple { elements: types }) => {
+            elements.len() == types.len()
+                && elements
+                    .iter()
+                    .zip(types)
+                    .all(|(v, t)| value_matches_type(v, t))
+        }
+        (Value::Struct { fields: values }, Type::Struct { fields, .. }) => {
+            fields_match(values, fields)
+        }
+        (
+            Value::Enum {
+                name,
+                fields: values,
+            },
+            Type::Enum { variants, .. },
+        ) => variants
+            .iter()
+            .find(|variant| &variant.name == name)
+            .map(|variant| fields_match(values, &variant.fields))
+            .unwrap_or(false),
+        (Value::Result { value: v }, Type::Result { okay, error }) => match v.borrow() {
+            Ok(v) => value_matches_type(v, okay),
+            Err(v) => value_matches_type(v, error),
+        },
+        (
+            Value::Vec {
+                element_type_id,
+                elements,
+            },
+            Type::Vec { element },
+        )
+        | (
+            Value::TreeSet {
+                element_type_id,
+                elements,
+            },
+            Type::TreeSet { element },
+        )
+        | (
+            Value::HashSet {
+                element_type_id,
+                elements,
+            },
+            Type::HashSet { element },
+        ) => elements_match(*element_type_id, elements, element),
+        (
+            Value::TreeMap {
+                key_type_id,
+                value_type_id,
+                elements,
+            },
+            Type::TreeMap { key, value },
+        )
+        | (
+            Value::HashMap {
+                key_type_id,
+                value_type_id,
+                elements,
+            },
+            Type::HashMap { key, value },
+        ) => entries_match(*key_type_id, *value_type_id, elements, key, value),
+        // Custom values must be of a known Scrypto type, even if the schema type isn't one
+        (Value::Custom { type_id, .. }, Type::Custom { name, .. }) => {
+            match (ScryptoType::from_id(*type_id), ScryptoType::from_name(name)) {
+                (Some(actual), Some(expected)) => actual.id() == expected.id(),
+                (Some(_), None) => true,
+                (None, _) => false,
+            }
+        }
+        // Custom types which are not Scrypto types may be encoded as anything
+        (_, Type::Custom { name, .. }) => ScryptoType::from_name(name).is_none(),
+        _ => false,
+    }
 }
 
 impl fmt::Debug for ScryptoValue {
@@ -139,6 +346,7 @@ pub struct ScryptoCustomValueChecker {
     pub proofs: HashMap<Proof, SborPath>,
     pub vaults: HashSet<Vault>,
     pub lazy_maps: HashSet<LazyMap<(), ()>>,
+    pub owned_components: HashSet<OwnedComponent>,
 }
 
 /// Represents an error when validating a Scrypto-specific value.
@@ -156,6 +364,9 @@ pub enum ScryptoCustomValueCheckError {
     InvalidBucket(ParseBucketError),
     InvalidProof(ParseProofError),
     InvalidLazyMap(ParseLazyMapError),
+    InvalidLazyVec(ParseLazyMapError),
+    InvalidLazySet(ParseLazyMapError),
+    InvalidOwnedComponent(ParseComponentAddressError),
     InvalidVault(ParseVaultError),
     InvalidNonFungibleId(ParseNonFungibleIdError),
     InvalidNonFungibleAddress(ParseNonFungibleAddressError),
@@ -169,6 +380,7 @@ impl ScryptoCustomValueChecker {
             proofs: HashMap::new(),
             vaults: HashSet::new(),
             lazy_maps: HashSet::new(),
+            owned_components: HashSet::new(),
         }
     }
 }
@@ -176,7 +388,12 @@ impl ScryptoCustomValueChecker {
 impl CustomValueVisitor for ScryptoCustomValueChecker {
     type Err = ScryptoCustomValueCheckError;
 
-    fn visit(&mut self, path: &mut MutableSborPath, type_id: u8, data: &[u8]) -> Result<(), Self::Err> {
+    fn visit(
+        &mut self,
+        path: &mut MutableSborPath,
+        type_id: u8,
+        data: &[u8],
+    ) -> Result<(), Self::Err> {
         match ScryptoType::from_id(type_id).ok_or(Self::Err::InvalidTypeId(type_id))? {
             ScryptoType::PackageAddress => {
                 PackageAddress::try_from(data)
@@ -193,6 +410,28 @@ impl CustomValueVisitor for ScryptoCustomValueChecker {
                     return Err(ScryptoCustomValueCheckError::DuplicateIds);
                 }
             }
+            // Lazy vectors and sets are backed by lazy maps in the engine
+            ScryptoType::LazyVec => {
+                let vec = LazyVec::<()>::try_from(data)
+                    .map_err(ScryptoCustomValueCheckError::InvalidLazyVec)?;
+                if !self.lazy_maps.insert(LazyMap::from_id(vec.id)) {
+                    return Err(ScryptoCustomValueCheckError::DuplicateIds);
+                }
+            }
+            ScryptoType::LazySet => {
+                let set = LazySet::<()>::try_from(data)
+                    .map_err(ScryptoCustomValueCheckError::InvalidLazySet)?;
+                if !self.lazy_maps.insert(LazyMap::from_id(set.id)) {
+                    return Err(ScryptoCustomValueCheckError::DuplicateIds);
+                }
+            }
+            ScryptoType::OwnedComponent => {
+                let component = OwnedComponent::try_from(data)
+                    .map_err(ScryptoCustomValueCheckError::InvalidOwnedComponent)?;
+                if !self.owned_components.insert(component) {
+                    return Err(ScryptoCustomValueCheckError::DuplicateIds);
+                }
+            }
             ScryptoType::Hash => {
                 Hash::try_from(data).map_err(ScryptoCustomValueCheckError::InvalidHash)?;
             }
@@ -208,15 +447,17 @@ impl CustomValueVisitor for ScryptoCustomValueChecker {
                 Decimal::try_from(data).map_err(ScryptoCustomValueCheckError::InvalidDecimal)?;
             }
             ScryptoType::Bucket => {
-                let bucket = Bucket::try_from(data).map_err(ScryptoCustomValueCheckError::InvalidBucket)?;
+                let bucket =
+                    Bucket::try_from(data).map_err(ScryptoCustomValueCheckError::InvalidBucket)?;
                 if self.buckets.insert(bucket, path.clone().into()).is_some() {
-                    return Err(ScryptoCustomValueCheckError::DuplicateIds)
+                    return Err(ScryptoCustomValueCheckError::DuplicateIds);
                 }
             }
             ScryptoType::Proof => {
-                let proof = Proof::try_from(data).map_err(ScryptoCustomValueCheckError::InvalidProof)?;
+                let proof =
+                    Proof::try_from(data).map_err(ScryptoCustomValueCheckError::InvalidProof)?;
                 if self.proofs.insert(proof, path.clone().into()).is_some() {
-                    return Err(ScryptoCustomValueCheckError::DuplicateIds)
+                    return Err(ScryptoCustomValueCheckError::DuplicateIds);
                 }
             }
             ScryptoType::Vault => {
@@ -445,6 +686,16 @@ impl ScryptoValueFormatter {
                 "LazyMap(\"{}\")",
                 LazyMap::<(), ()>::try_from(data).unwrap()
             ),
+            ScryptoType::LazyVec => {
+                format!("LazyVec(\"{}\")", LazyVec::<()>::try_from(data).unwrap())
+            }
+            ScryptoType::LazySet => {
+                format!("LazySet(\"{}\")", LazySet::<()>::try_from(data).unwrap())
+            }
+            ScryptoType::OwnedComponent => format!(
+                "OwnedComponent(\"{}\")",
+                OwnedComponent::try_from(data).unwrap().0
+            ),
             ScryptoType::Hash => format!("Hash(\"{}\")", Hash::try_from(data).unwrap()),
             ScryptoType::EcdsaPublicKey => {
                 format!(
@@ -511,4 +762,33 @@ mod tests {
             )
         );
     }
+
+    #[test]
+    fn should_check_value_against_schema() {
+        let value = ScryptoValue::from_value(&(1u32, vec![Decimal::one()], Some("a".to_owned())));
+        let schema = <(u32, Vec<Decimal>, Option<String>)>::describe();
+        assert!(value.matches_schema(&schema));
+
+        let schema = <(u32, Vec<u32>, Option<String>)>::describe();
+        assert!(!value.matches_schema(&schema));
+    }
+
+    #[test]
+    fn should_reject_unknown_custom_type_against_schema() {
+        let schema = Type::Custom {
+            name: "Unknown".to_owned(),
+            generics: vec![],
+        };
+        let known = Value::Custom {
+            type_id: ScryptoType::Decimal.id(),
+            bytes: Decimal::one().to_vec(),
+        };
+        assert!(value_matches_type(&known, &schema));
+
+        let unknown = Value::Custom {
+            type_id: 0xff,
+            bytes: vec![],
+        };
+        assert!(!value_matches_type(&unknown, &schema));
+    }
 }
@@ -0,0 +1,199 @@
+use std::io;
+
+use radix_engine::ledger::*;
+use sbor::*;
+use scrypto::buffer::*;
+
+/// The version of the ledger archive format produced by `export_state`.
+pub const LEDGER_ARCHIVE_VERSION: u32 = 1;
+
+/// Magic bytes at the start of every ledger archive.
+const LEDGER_ARCHIVE_MAGIC: [u8; 8] = *b"RADIXARC";
+
+/// Represents an error when exporting or importing a ledger archive.
+#[derive(Debug)]
+pub enum ArchiveError {
+    IOError(io::Error),
+    DecodeError(DecodeError),
+    InvalidMagic,
+    UnsupportedVersion(u32),
+    UnexpectedEndOfArchive,
+    LedgerNotEmpty,
+}
+
+#[derive(Debug, TypeId, Encode, Decode)]
+struct ArchiveHeader {
+    version: u32,
+    epoch: u64,
+    nonce: u64,
+}
+
+#[derive(Debug, TypeId, Encode, Decode)]
+enum ArchiveRecord {
+    Substate {
+        address: Vec<u8>,
+        substate: Substate,
+    },
+    ChildSubstate {
+        address: Vec<u8>,
+        key: Vec<u8>,
+        substate: Substate,
+    },
+    End,
+}
+
+/// Summary of an exported or imported ledger archive.
+#[derive(Debug, Clone, Copy)]
+pub struct ArchiveSummary {
+    pub epoch: u64,
+    pub nonce: u64,
+    pub substates: usize,
+    pub child_substates: usize,
+}
+
+fn write_frame<W: io::Write, T: Encode>(output: &mut W, value: &T) -> Result<(), ArchiveError> {
+    let bytes = scrypto_encode(value);
+    output
+        .write_all(&(bytes.len() as u32).to_le_bytes())
+        .map_err(ArchiveError::IOError)?;
+    output.write_all(&bytes).map_err(ArchiveError::IOError)
+}
+
+fn read_frame<R: io::Read, T: Decode>(input: &mut R) -> Result<T, ArchiveError> {
+    let mut len = [0u8; 4];
+    input.read_exact(&mut len).map_err(|e| match e.kind() {
+        io::ErrorKind::UnexpectedEof => ArchiveError::UnexpectedEndOfArchive,
+        _ => ArchiveError::IOError(e),
+    })?;
+    let mut bytes = vec![0u8; u32::from_le_bytes(len) as usize];
+    input.read_exact(&mut bytes).map_err(|e| match e.kind() {
+        io::ErrorKind::UnexpectedEof => ArchiveError::UnexpectedEndOfArchive,
+        _ => ArchiveError::IOError(e),
+    })?;
+    scrypto_decode(&bytes).map_err(ArchiveError::DecodeError)
+}
+
+/// Streams every substate and child substate of a ledger, along with the epoch and nonce,
+/// into a versioned archive.
+pub fn export_state<T: SubstateStore, W: io::Write>(
+    substate_store: &T,
+    output: &mut W,
+) -> Result<ArchiveSummary, ArchiveError> {
+    let mut summary = ArchiveSummary {
+        epoch: substate_store.get_epoch(),
+        nonce: substate_store.get_nonce(),
+        substates: 0,
+        child_substates: 0,
+    };
+
+    output
+        .write_all(&LEDGER_ARCHIVE_MAGIC)
+        .map_err(ArchiveError::IOError)?;
+    write_frame(
+        output,
+        &ArchiveHeader {
+            version: LEDGER_ARCHIVE_VERSION,
+            epoch: summary.epoch,
+            nonce: summary.nonce,
+        },
+    )?;
+
+    // The iteration callbacks can't fail, so keep the first error and skip the rest.
+    let mut error = None;
+    substate_store
+        .iter_substates(|address, substate| {
+            if error.is_none() {
+                let record = ArchiveRecord::Substate {
+                    address: address.to_vec(),
+                    substate,
+                };
+                match write_frame(output, &record) {
+                    Ok(()) => summary.substates += 1,
+                    Err(e) => error = Some(e),
+                }
+            }
+        })
+        .map_err(ArchiveError::DecodeError)?;
+    substate_store
+        .iter_child_substates(|address, key, substate| {
+            if error.is_none() {
+                let record = ArchiveRecord::ChildSubstate {
+                    address: address.to_vec(),
+                    key: key.to_vec(),
+                    substate,
+                };
+                match write_frame(output, &record) {
+                    Ok(()) => summary.child_substates += 1,
+                    Err(e) => error = Some(e),
+                }
+            }
+        })
+        .map_err(ArchiveError::DecodeError)?;
+    if let Some(e) = error {
+        return Err(e);
+    }
+
+    write_frame(output, &ArchiveRecord::End)?;
+    output.flush().map_err(ArchiveError::IOError)?;
+    Ok(summary)
+}
+
+/// Restores a ledger from an archive produced by `export_state`.
+///
+/// The ledger must be empty, i.e. hold no substates nor child substates.
+pub fn import_state<T: SubstateStore, R: io::Read>(
+    substate_store: &mut T,
+    input: &mut R,
+) -> Result<ArchiveSummary, ArchiveError> {
+    let mut empty = true;
+    substate_store
+        .iter_substates(|_, _| empty = false)
+        .map_err(ArchiveError::DecodeError)?;
+    substate_store
+        .iter_child_substates(|_, _, _| empty = false)
+        .map_err(ArchiveError::DecodeError)?;
+    if !empty {
+        return Err(ArchiveError::LedgerNotEmpty);
+    }
+
+    let mut magic = [0u8; 8];
+    input
+        .read_exact(&mut magic)
+        .map_err(|_| ArchiveError::InvalidMagic)?;
+    if magic != LEDGER_ARCHIVE_MAGIC {
+        return Err(ArchiveError::InvalidMagic);
+    }
+
+    let header: ArchiveHeader = read_frame(input)?;
+    if header.version != LEDGER_ARCHIVE_VERSION {
+        return Err(ArchiveError::UnsupportedVersion(header.version));
+    }
+
+    let mut summary = ArchiveSummary {
+        epoch: header.epoch,
+        nonce: header.nonce,
+        substates: 0,
+        child_substates: 0,
+    };
+    loop {
+        match read_frame(input)? {
+            ArchiveRecord::Substate { address, substate } => {
+                substate_store.put_raw_substate(&address, substate);
+                summary.substates += 1;
+            }
+            ArchiveRecord::ChildSubstate {
+                address,
+                key,
+                substate,
+            } => {
+                substate_store.put_raw_child_substate(&address, &key, substate);
+                summary.child_substates += 1;
+            }
+            ArchiveRecord::End => break,
+        }
+    }
+    substate_store.set_epoch(header.epoch);
+    substate_store.set_nonce(header.nonce);
+
+    Ok(summary)
+}
@@ -0,0 +1,389 @@
+#![allow(unused_must_use)]
+use colored::*;
+use radix_engine::ledger::*;
+use radix_engine::model::*;
+use sbor::{Decode, DecodeError, Decoder};
+use scrypto::buffer::*;
+use scrypto::engine::types::*;
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+
+use crate::ledger::*;
+use crate::utils::*;
+
+/// Represents an error when comparing ledgers.
+#[derive(Debug, Clone)]
+pub enum DiffError {
+    /// A substate or its key is not of the type its address implies.
+    DecodeError(DecodeError),
+}
+
+/// The number of differences between two ledgers, by kind.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DiffSummary {
+    pub packages: usize,
+    pub package_versions: usize,
+    pub components: usize,
+    pub resource_managers: usize,
+    pub vaults: usize,
+    pub lazy_map_entries: usize,
+    pub non_fungibles: usize,
+}
+
+impl DiffSummary {
+    pub fn total(&self) -> usize {
+        self.packages
+            + self.package_versions
+            + self.components
+            + self.resource_managers
+            + self.vaults
+            + self.lazy_map_entries
+            + self.non_fungibles
+    }
+}
+
+enum Change<T> {
+    Added(T),
+    Removed(T),
+    Changed(T, T),
+}
+
+impl<T> Change<T> {
+    fn symbol(&self) -> ColoredString {
+        match self {
+            Change::Added(_) => "+".green().bold(),
+            Change::Removed(_) => "-".red().bold(),
+            Change::Changed(_, _) => "~".yellow().bold(),
+        }
+    }
+}
+
+/// Compares two maps, returning the entries whose values differ.
+fn changes<K: Ord + Clone>(
+    from: &BTreeMap<K, Vec<u8>>,
+    to: &BTreeMap<K, Vec<u8>>,
+) -> Vec<(K, Change<Vec<u8>>)> {
+    let keys: BTreeSet<&K> = from.keys().chain(to.keys()).collect();
+    keys.into_iter()
+        .filter_map(|k| match (from.get(k), to.get(k)) {
+            (None, Some(new)) => Some((k.clone(), Change::Added(new.clone()))),
+            (Some(old), None) => Some((k.clone(), Change::Removed(old.clone()))),
+            (Some(old), Some(new)) if old != new => {
+                Some((k.clone(), Change::Changed(old.clone(), new.clone())))
+            }
+            _ => None,
+        })
+        .collect()
+}
+
+fn decode<T: Decode>(value: &[u8]) -> Result<T, DiffError> {
+    scrypto_decode(value).map_err(DiffError::DecodeError)
+}
+
+/// Decodes a value at the start of a key, returning it along with the rest of the key.
+fn decode_prefix<T: Decode>(key: &[u8]) -> Result<(T, &[u8]), DiffError> {
+    let mut decoder = Decoder::with_type(key);
+    let value = T::decode(&mut decoder).map_err(DiffError::DecodeError)?;
+    Ok((value, &key[key.len() - decoder.remaining()..]))
+}
+
+/// Collects the values of all substates and child substates of a ledger, ignoring physical ids.
+fn collect_substates<T: SubstateStore>(
+    substate_store: &T,
+) -> Result<
+    (
+        BTreeMap<Vec<u8>, Vec<u8>>,
+        BTreeMap<(Vec<u8>, Vec<u8>), Vec<u8>>,
+    ),
+    DiffError,
+> {
+    let mut substates = BTreeMap::new();
+    substate_store
+        .iter_substates(|address, substate| {
+            substates.insert(address.to_vec(), substate.value);
+        })
+        .map_err(DiffError::DecodeError)?;
+    let mut child_substates = BTreeMap::new();
+    substate_store
+        .iter_child_substates(|address, key, substate| {
+            child_substates.insert((address.to_vec(), key.to_vec()), substate.value);
+        })
+        .map_err(DiffError::DecodeError)?;
+    Ok((substates, child_substates))
+}
+
+/// Prints the differences between two ledgers into console.
+pub fn dump_diff<A: SubstateStore, B: SubstateStore, O: std::io::Write>(
+    from: &A,
+    to: &B,
+    output: &mut O,
+) -> Result<DiffSummary, DiffError> {
+    let (from_substates, from_child_substates) = collect_substates(from)?;
+    let (to_substates, to_child_substates) = collect_substates(to)?;
+    let mut summary = DiffSummary::default();
+
+    let mut packages = Vec::new();
+    let mut components = Vec::new();
+    let mut resource_managers = Vec::new();
+    for (address, change) in changes(&from_substates, &to_substates) {
+        if let Ok(package_address) = scrypto_decode::<PackageAddress>(&address) {
+            packages.push((package_address, change));
+        } else if let Ok(component_address) = scrypto_decode::<ComponentAddress>(&address) {
+            components.push((component_address, change));
+        } else if let Ok(resource_address) = scrypto_decode::<ResourceAddress>(&address) {
+            resource_managers.push((resource_address, change));
+        }
+    }
+
+    // Package versions are keyed by number; vaults and non-fungibles are keyed by their id;
+    // lazy map entries are keyed by the map id followed by the entry key.
+    let mut package_versions = Vec::new();
+    let mut vaults = Vec::new();
+    let mut lazy_map_entries = Vec::new();
+    let mut non_fungibles = Vec::new();
+    for ((address, key), change) in changes(&from_child_substates, &to_child_substates) {
+        if let Ok(package_address) = scrypto_decode::<PackageAddress>(&address) {
+            package_versions.push((package_address, decode::<u32>(&key)?, change));
+        } else if let Ok(component_address) = scrypto_decode::<ComponentAddress>(&address) {
+            let (id, entry_key) = decode_prefix::<(Hash, u32)>(&key)?;
+            if entry_key.is_empty() {
+                vaults.push((component_address, id, change));
+            } else {
+                lazy_map_entries.push((component_address, id, entry_key.to_vec(), change));
+            }
+        } else if let Ok(resource_address) = scrypto_decode::<ResourceAddress>(&address) {
+            non_fungibles.push((resource_address, decode::<NonFungibleId>(&key)?, change));
+        }
+    }
+
+    writeln!(output, "{}:", "Packages".green().bold());
+    for (last, (package_address, change)) in packages.iter().identify_last() {
+        let latest_version = |v: &Vec<u8>| decode::<Package>(v).map(|p| p.latest_version());
+        let detail = match change {
+            Change::Added(v) | Change::Removed(v) => format!("version {}", latest_version(v)?),
+            Change::Changed(old, new) => format!(
+                "version {} -> {}",
+                latest_version(old)?,
+                latest_version(new)?
+            ),
+        };
+        writeln!(
+            output,
+            "{} {} {} ({})",
+            list_item_prefix(last),
+            change.symbol(),
+            package_address,
+            detail
+        );
+    }
+    summary.packages = packages.len();
+
+    writeln!(output, "{}:", "Package Versions".green().bold());
+    for (last, (package_address, version, change)) in package_versions.iter().identify_last() {
+        let code_size = |v: &Vec<u8>| decode::<PackageVersion>(v).map(|p| p.code().len());
+        let detail = match change {
+            Change::Added(v) | Change::Removed(v) => format!("{} bytes", code_size(v)?),
+            Change::Changed(old, new) => {
+                format!("{} bytes -> {} bytes", code_size(old)?, code_size(new)?)
+            }
+        };
+        writeln!(
+            output,
+            "{} {} {} v{} ({})",
+            list_item_prefix(last),
+            change.symbol(),
+            package_address,
+            version,
+            detail
+        );
+    }
+    summary.package_versions = package_versions.len();
+
+    writeln!(output, "{}:", "Components".green().bold());
+    for (last, (component_address, change)) in components.iter().identify_last() {
+        writeln!(
+            output,
+            "{} {} {}",
+            list_item_prefix(last),
+            change.symbol(),
+            component_address
+        );
+        let indent = if last { " " } else { "│" };
+        match change {
+            Change::Added(v) | Change::Removed(v) => {
+                let component = decode::<Component>(v)?;
+                writeln!(
+                    output,
+                    "{}  ├─ Blueprint: {}",
+                    indent,
+                    format_blueprint(&component)
+                );
+                writeln!(
+                    output,
+                    "{}  └─ State: {}",
+                    indent,
+                    format_value(component.state())
+                );
+            }
+            Change::Changed(old, new) => {
+                let old = decode::<Component>(old)?;
+                let new = decode::<Component>(new)?;
+                if format_blueprint(&old) != format_blueprint(&new) {
+                    writeln!(
+                        output,
+                        "{}  ├─ Blueprint: {} -> {}",
+                        indent,
+                        format_blueprint(&old),
+                        format_blueprint(&new)
+                    );
+                }
+                if old.authorization() != new.authorization() {
+                    writeln!(output, "{}  ├─ Authorization changed", indent);
+                }
+                writeln!(
+                    output,
+                    "{}  └─ State: {} -> {}",
+                    indent,
+                    format_value(old.state()),
+                    format_value(new.state())
+                );
+            }
+        }
+    }
+    summary.components = components.len();
+
+    writeln!(output, "{}:", "Resource Managers".green().bold());
+    for (last, (resource_address, change)) in resource_managers.iter().identify_last() {
+        let describe = |v: &Vec<u8>| {
+            decode::<ResourceManager>(v).map(|resource_manager| {
+                format!(
+                    "{:?}{}, total supply: {}",
+                    resource_manager.resource_type(),
+                    format_resource_names(&resource_manager),
+                    resource_manager.total_supply()
+                )
+            })
+        };
+        let detail = match change {
+            Change::Added(v) | Change::Removed(v) => describe(v)?,
+            Change::Changed(old, new) => format!("{} -> {}", describe(old)?, describe(new)?),
+        };
+        writeln!(
+            output,
+            "{} {} {} ({})",
+            list_item_prefix(last),
+            change.symbol(),
+            resource_address,
+            detail
+        );
+    }
+    summary.resource_managers = resource_managers.len();
+
+    writeln!(output, "{}:", "Vaults".green().bold());
+    for (last, (component_address, vault_id, change)) in vaults.iter().identify_last() {
+        let (resource_address, old_amount, new_amount) = match change {
+            Change::Added(v) => {
+                let vault = decode::<Vault>(v)?;
+                (
+                    vault.resource_address(),
+                    Decimal::zero(),
+                    vault.total_amount(),
+                )
+            }
+            Change::Removed(v) => {
+                let vault = decode::<Vault>(v)?;
+                (
+                    vault.resource_address(),
+                    vault.total_amount(),
+                    Decimal::zero(),
+                )
+            }
+            Change::Changed(old, new) => {
+                let old = decode::<Vault>(old)?;
+                let new = decode::<Vault>(new)?;
+                (
+                    new.resource_address(),
+                    old.total_amount(),
+                    new.total_amount(),
+                )
+            }
+        };
+        let delta = new_amount - old_amount;
+        writeln!(
+            output,
+            "{} {} {:?}{:?} {{ resource address: {}, amount: {} -> {}, delta: {}{} }}",
+            list_item_prefix(last),
+            change.symbol(),
+            component_address,
+            vault_id,
+            resource_address,
+            old_amount,
+            new_amount,
+            if delta.is_negative() { "" } else { "+" },
+            delta
+        );
+    }
+    summary.vaults = vaults.len();
+
+    writeln!(output, "{}:", "Lazy Map Entries".green().bold());
+    for (last, (component_address, lazy_map_id, key, change)) in
+        lazy_map_entries.iter().identify_last()
+    {
+        let detail = match change {
+            Change::Added(v) | Change::Removed(v) => format_value(v),
+            Change::Changed(old, new) => format!("{} -> {}", format_value(old), format_value(new)),
+        };
+        writeln!(
+            output,
+            "{} {} {:?}{:?} {} => {}",
+            list_item_prefix(last),
+            change.symbol(),
+            component_address,
+            lazy_map_id,
+            format_value(key),
+            detail
+        );
+    }
+    summary.lazy_map_entries = lazy_map_entries.len();
+
+    writeln!(output, "{}:", "Non Fungibles".green().bold());
+    for (last, (resource_address, id, change)) in non_fungibles.iter().identify_last() {
+        let describe = |v: &Vec<u8>| {
+            decode::<Option<NonFungible>>(v).map(|non_fungible| match non_fungible {
+                Some(non_fungible) => format!("{{ {} }}", format_non_fungible_data(&non_fungible)),
+                None => "burnt".to_owned(),
+            })
+        };
+        let detail = match change {
+            Change::Added(v) | Change::Removed(v) => describe(v)?,
+            Change::Changed(old, new) => format!("{} -> {}", describe(old)?, describe(new)?),
+        };
+        writeln!(
+            output,
+            "{} {} {} {} {}",
+            list_item_prefix(last),
+            change.symbol(),
+            resource_address,
+            id,
+            detail
+        );
+    }
+    summary.non_fungibles = non_fungibles.len();
+
+    Ok(summary)
+}
+
+/// Copies a ledger into memory.
+pub fn load_into_memory<T: SubstateStore>(
+    substate_store: &T,
+) -> Result<InMemorySubstateStore, DecodeError> {
+    let mut ledger = InMemorySubstateStore::new();
+    substate_store
+        .iter_substates(|address, substate| ledger.put_raw_substate(address, substate))?;
+    substate_store.iter_child_substates(|address, key, substate| {
+        ledger.put_raw_child_substate(address, key, substate)
+    })?;
+    ledger.set_epoch(substate_store.get_epoch());
+    ledger.set_nonce(substate_store.get_nonce());
+    Ok(ledger)
+}
@@ -3,7 +3,7 @@ use colored::*;
 use radix_engine::ledger::*;
 use radix_engine::model::*;
 use scrypto::engine::types::*;
-use scrypto::rust::collections::HashSet;
+use scrypto::rust::collections::{HashMap, HashSet};
 use scrypto::values::*;
 use std::collections::VecDeque;
 
@@ -17,6 +17,44 @@ pub enum DisplayError {
     ResourceManagerNotFound,
 }
 
+/// Formats an SBOR-encoded value, falling back to hex if it's not a valid Scrypto value.
+pub fn format_value(value: &[u8]) -> String {
+    ScryptoValue::from_slice(value)
+        .map(|v| ScryptoValueFormatter::format_value(&v.dom, &HashMap::new(), &HashMap::new()))
+        .unwrap_or_else(|_| hex::encode(value))
+}
+
+/// Formats the blueprint of a component.
+pub fn format_blueprint(component: &Component) -> String {
+    format!(
+        "{{ package_address: {}, blueprint_name: \"{}\" }}",
+        component.package_address(),
+        component.blueprint_name()
+    )
+}
+
+/// Formats the name and symbol of a resource, if any, as trailing fields.
+pub fn format_resource_names(resource_manager: &ResourceManager) -> String {
+    ["name", "symbol"]
+        .iter()
+        .filter_map(|key| {
+            resource_manager
+                .metadata()
+                .get(*key)
+                .map(|value| format!(", {}: \"{}\"", key, value))
+        })
+        .collect()
+}
+
+/// Formats the data of a non-fungible.
+pub fn format_non_fungible_data(non_fungible: &NonFungible) -> String {
+    format!(
+        "immutable_data: {}, mutable_data: {}",
+        format_value(&non_fungible.immutable_data()),
+        format_value(&non_fungible.mutable_data())
+    )
+}
+
 /// Dump a package into console.
 pub fn dump_package<T: SubstateStore, O: std::io::Write>(
     package_address: PackageAddress,
@@ -34,12 +72,43 @@ pub fn dump_package<T: SubstateStore, O: std::io::Write>(
                 "Package".green().bold(),
                 package_address.to_string()
             );
+            let latest: PackageVersion = substate_store
+                .get_decoded_child_substate(&package_address, &b.latest_version())
+                .map(|(package_version, _)| package_version)
+                .ok_or(DisplayError::PackageNotFound)?;
+            writeln!(
+                output,
+                "{}: {}",
+                "Version".green().bold(),
+                b.latest_version()
+            );
             writeln!(
                 output,
                 "{}: {} bytes",
                 "Code size".green().bold(),
-                b.code().len()
+                latest.code().len()
+            );
+            writeln!(
+                output,
+                "{}: {}",
+                "Code hash".green().bold(),
+                latest.code_hash()
             );
+            writeln!(
+                output,
+                "{}: {}",
+                "Metadata".green().bold(),
+                latest.metadata().len()
+            );
+            for (last, e) in latest.metadata().iter().identify_last() {
+                writeln!(
+                    output,
+                    "{} {}: {}",
+                    list_item_prefix(last),
+                    e.0.green().bold(),
+                    e.1
+                );
+            }
             Ok(())
         }
         None => Err(DisplayError::PackageNotFound),
@@ -66,10 +135,9 @@ pub fn dump_component<T: SubstateStore + QueryableSubstateStore, O: std::io::Wri
 
             writeln!(
                 output,
-                "{}: {{ package_address: {}, blueprint_name: \"{}\" }}",
+                "{}: {}",
                 "Blueprint".green().bold(),
-                c.package_address(),
-                c.blueprint_name()
+                format_blueprint(&c)
             );
 
             writeln!(output, "{}", "Authorization".green().bold());
@@ -154,20 +222,11 @@ fn dump_resources<T: SubstateStore, O: std::io::Write>(
             .unwrap();
         writeln!(
             output,
-            "{} {{ amount: {}, resource address: {}{}{} }}",
+            "{} {{ amount: {}, resource address: {}{} }}",
             list_item_prefix(last),
             amount,
             resource_address,
-            resource_manager
-                .metadata()
-                .get("name")
-                .map(|name| format!(", name: \"{}\"", name))
-                .unwrap_or(String::new()),
-            resource_manager
-                .metadata()
-                .get("symbol")
-                .map(|symbol| format!(", symbol: \"{}\"", symbol))
-                .unwrap_or(String::new()),
+            format_resource_names(&resource_manager),
         );
         if matches!(resource_manager.resource_type(), ResourceType::NonFungible) {
             let ids = vault.total_ids().unwrap();
@@ -178,18 +237,13 @@ fn dump_resources<T: SubstateStore, O: std::io::Write>(
                     .0;
 
                 if let Some(non_fungible) = non_fungible {
-                    let immutable_data =
-                        ScryptoValue::from_slice(&non_fungible.immutable_data()).unwrap();
-                    let mutable_data =
-                        ScryptoValue::from_slice(&non_fungible.mutable_data()).unwrap();
                     writeln!(
                         output,
-                        "{}  {} NonFungible {{ id: {}, immutable_data: {}, mutable_data: {} }}",
+                        "{}  {} NonFungible {{ id: {}, {} }}",
                         if last { " " } else { "│" },
                         list_item_prefix(inner_last),
                         id,
-                        immutable_data,
-                        mutable_data
+                        format_non_fungible_data(&non_fungible)
                     );
                 }
             }
@@ -0,0 +1,51 @@
+use radix_engine::model::*;
+use sbor::*;
+use scrypto::engine::types::*;
+
+/// A transaction executed by the simulator, along with its receipt.
+#[derive(Debug, Clone, TypeId, Encode, Decode)]
+pub struct TransactionRecord {
+    /// The hash of the transaction intent, i.e. the unsigned transaction
+    pub intent_hash: Hash,
+    pub transaction: Transaction,
+    pub signatures: Vec<(EcdsaPublicKey, EcdsaSignature)>,
+    pub receipt: ReceiptRecord,
+}
+
+/// A receipt, stored in its rendered form so that it can be displayed again.
+#[derive(Debug, Clone, TypeId, Encode, Decode)]
+pub struct ReceiptRecord {
+    pub success: bool,
+    pub new_package_addresses: Vec<PackageAddress>,
+    pub new_component_addresses: Vec<ComponentAddress>,
+    pub new_resource_addresses: Vec<ResourceAddress>,
+    /// The receipt as printed when the transaction was executed
+    pub rendered: String,
+}
+
+impl TransactionRecord {
+    pub fn new(signed: &SignedTransaction, receipt: &Receipt) -> Self {
+        Self {
+            intent_hash: signed.transaction.raw_hash(),
+            transaction: signed.transaction.clone(),
+            signatures: signed.signatures.clone(),
+            receipt: ReceiptRecord {
+                success: receipt.result.is_ok(),
+                new_package_addresses: receipt.new_package_addresses.clone(),
+                new_component_addresses: receipt.new_component_addresses.clone(),
+                new_resource_addresses: receipt.new_resource_addresses.clone(),
+                rendered: format!("{:?}", receipt),
+            },
+        }
+    }
+
+    pub fn is_success(&self) -> bool {
+        self.receipt.success
+    }
+
+    pub fn new_entities(&self) -> usize {
+        self.receipt.new_package_addresses.len()
+            + self.receipt.new_component_addresses.len()
+            + self.receipt.new_resource_addresses.len()
+    }
+}
@@ -0,0 +1,293 @@
+use std::collections::HashMap;
+use std::fs;
+use std::io;
+
+use radix_engine::errors::*;
+use radix_engine::ledger::*;
+use radix_engine::model::*;
+use radix_engine::transaction::*;
+use sbor::*;
+use scrypto::buffer::*;
+use scrypto::crypto::*;
+use scrypto::engine::types::*;
+
+use crate::ledger::*;
+
+/// The name of the journal file, under the data directory.
+pub const JOURNAL_FILE_NAME: &str = "journal.bin";
+
+/// Represents an error when writing or replaying a journal.
+#[derive(Debug)]
+pub enum JournalError {
+    IOError(io::Error),
+    DecodeError(DecodeError),
+    GenesisError(GenesisError),
+    TransactionValidationError(TransactionValidationError),
+    TransactionExecutionError(RuntimeError),
+    PackageValidationError(WasmValidationError),
+    ArchiveError(ArchiveError),
+    ArchiveHashMismatch {
+        path: String,
+        expected: Hash,
+        actual: Hash,
+    },
+    StateHashMismatch {
+        index: usize,
+        expected: Hash,
+        actual: Hash,
+    },
+}
+
+/// A ledger mutation.
+#[derive(Debug, Clone, TypeId, Encode, Decode)]
+pub enum JournalEntry {
+    /// Bootstraps the ledger; without this or an import first, the journal starts from the default genesis.
+    Genesis { genesis: GenesisConfig },
+    /// Executes a transaction, which has been committed.
+    Transaction {
+        transaction: Transaction,
+        signatures: Vec<(EcdsaPublicKey, EcdsaSignature)>,
+    },
+    /// Overwrites a package, bypassing transaction execution.
+    OverwritePackage {
+        package_address: PackageAddress,
+        code: Vec<u8>,
+        metadata: HashMap<String, String>,
+    },
+    /// Sets the current epoch.
+    SetEpoch { epoch: u64 },
+    /// Restores the ledger from an archive created by `export_state`, which is read again
+    /// from its path on replay.
+    ImportState { path: String, archive_hash: Hash },
+}
+
+/// A journal entry, along with the state hash right after it's applied.
+#[derive(Debug, Clone, TypeId, Encode, Decode)]
+pub struct JournalRecord {
+    pub entry: JournalEntry,
+    pub state_hash: Hash,
+}
+
+/// Computes a hash of all substates, the epoch and the nonce of a ledger.
+///
+/// Substates are hashed in the order of their ids, each along with its id, so that two ledgers
+/// have the same state hash only if they hold the same substates.
+pub fn state_hash<T: SubstateStore>(substate_store: &T) -> Result<Hash, DecodeError> {
+    let mut entries = Vec::new();
+    substate_store.iter_substates(|address, substate| {
+        let mut id = vec![0u8];
+        id.extend(address);
+        entries.push((id, substate_hash(&substate)));
+    })?;
+    substate_store.iter_child_substates(|address, key, substate| {
+        let mut id = vec![1u8];
+        id.extend((address.len() as u32).to_le_bytes());
+        id.extend(address);
+        id.extend(key);
+        entries.push((id, substate_hash(&substate)));
+    })?;
+    entries.sort();
+
+    let mut data = Vec::new();
+    data.extend(substate_store.get_epoch().to_le_bytes());
+    data.extend(substate_store.get_nonce().to_le_bytes());
+    for (id, substate_hash) in entries {
+        data.extend((id.len() as u32).to_le_bytes());
+        data.extend(id);
+        data.extend(substate_hash.0);
+    }
+    Ok(hash(data))
+}
+
+/// Hashes a substate, with the entries of the hash sets and hash maps in its value sorted, as
+/// they're encoded in the iteration order of the process which wrote them.
+fn substate_hash(substate: &Substate) -> Hash {
+    let value = match decode_any(&substate.value) {
+        Ok(mut value) => {
+            sort_hash_collections(&mut value);
+            encode_any_to_vec(None, &value)
+        }
+        Err(_) => substate.value.clone(),
+    };
+    let mut data = Vec::new();
+    data.extend((value.len() as u32).to_le_bytes());
+    data.extend(value);
+    data.extend(scrypto_encode(&substate.phys_id));
+    hash(data)
+}
+
+fn sort_hash_collections(value: &mut Value) {
+    match value {
+        Value::Struct { fields } | Value::Enum { fields, .. } => {
+            fields.iter_mut().for_each(sort_hash_collections);
+        }
+        Value::Option { value } => {
+            if let Some(value) = value.as_mut() {
+                sort_hash_collections(value);
+            }
+        }
+        Value::Result { value } => match value.as_mut() {
+            Ok(value) | Err(value) => sort_hash_collections(value),
+        },
+        Value::Array { elements, .. }
+        | Value::Tuple { elements }
+        | Value::Vec { elements, .. }
+        | Value::TreeSet { elements, .. }
+        | Value::TreeMap { elements, .. } => {
+            elements.iter_mut().for_each(sort_hash_collections);
+        }
+        Value::HashSet {
+            element_type_id,
+            elements,
+        } => {
+            elements.iter_mut().for_each(sort_hash_collections);
+            elements.sort_by_cached_key(|e| encode_any_to_vec(Some(*element_type_id), e));
+        }
+        Value::HashMap {
+            key_type_id,
+            elements,
+            ..
+        } => {
+            elements.iter_mut().for_each(sort_hash_collections);
+            let mut entries = Vec::new();
+            let mut iter = std::mem::take(elements).into_iter();
+            while let (Some(key), Some(value)) = (iter.next(), iter.next()) {
+                entries.push((encode_any_to_vec(Some(*key_type_id), &key), key, value));
+            }
+            entries.sort_by(|a, b| a.0.cmp(&b.0));
+            for (_, key, value) in entries {
+                elements.push(key);
+                elements.push(value);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn encode_any_to_vec(ty_ctx: Option<u8>, value: &Value) -> Vec<u8> {
+    let mut bytes = Vec::new();
+    encode_any(ty_ctx, value, &mut Encoder::with_type(&mut bytes));
+    bytes
+}
+
+/// Appends a record to a journal.
+pub fn write_journal_record<W: io::Write>(
+    output: &mut W,
+    record: &JournalRecord,
+) -> Result<(), JournalError> {
+    let bytes = scrypto_encode(record);
+    output
+        .write_all(&(bytes.len() as u32).to_le_bytes())
+        .map_err(JournalError::IOError)?;
+    output.write_all(&bytes).map_err(JournalError::IOError)?;
+    output.flush().map_err(JournalError::IOError)
+}
+
+/// Reads all records of a journal.
+pub fn read_journal<R: io::Read>(input: &mut R) -> Result<Vec<JournalRecord>, JournalError> {
+    let mut records = Vec::new();
+    loop {
+        let mut len = [0u8; 4];
+        match input.read_exact(&mut len) {
+            Ok(()) => {}
+            Err(e) if e.kind() == io::ErrorKind::UnexpectedEof => break,
+            Err(e) => return Err(JournalError::IOError(e)),
+        }
+        let mut bytes = vec![0u8; u32::from_le_bytes(len) as usize];
+        input
+            .read_exact(&mut bytes)
+            .map_err(JournalError::IOError)?;
+        records.push(scrypto_decode(&bytes).map_err(JournalError::DecodeError)?);
+    }
+    Ok(records)
+}
+
+/// Applies a journal entry to a ledger.
+pub fn apply_journal_entry<L: SubstateStore>(
+    executor: &mut TransactionExecutor<L>,
+    entry: &JournalEntry,
+) -> Result<(), JournalError> {
+    match entry {
+        JournalEntry::Genesis { genesis } => {
+            executor
+                .substate_store_mut()
+                .bootstrap_with_genesis(genesis)
+                .map_err(JournalError::GenesisError)?;
+        }
+        JournalEntry::Transaction {
+            transaction,
+            signatures,
+        } => {
+            let signed = SignedTransaction {
+                transaction: transaction.clone(),
+                signatures: signatures.clone(),
+            };
+            let receipt = executor
+                .validate_and_execute(&signed)
+                .map_err(JournalError::TransactionValidationError)?;
+            receipt
+                .result
+                .map_err(JournalError::TransactionExecutionError)?;
+        }
+        JournalEntry::OverwritePackage {
+            package_address,
+            code,
+            metadata,
+        } => {
+            executor
+                .overwrite_package(*package_address, code.clone(), metadata.clone())
+                .map_err(JournalError::PackageValidationError)?;
+        }
+        JournalEntry::SetEpoch { epoch } => {
+            executor.substate_store_mut().set_epoch(*epoch);
+        }
+        JournalEntry::ImportState { path, archive_hash } => {
+            let archive = fs::read(path).map_err(JournalError::IOError)?;
+            let actual = hash(&archive);
+            if actual != *archive_hash {
+                return Err(JournalError::ArchiveHashMismatch {
+                    path: path.clone(),
+                    expected: *archive_hash,
+                    actual,
+                });
+            }
+            import_state(executor.substate_store_mut(), &mut archive.as_slice())
+                .map_err(JournalError::ArchiveError)?;
+        }
+    }
+    Ok(())
+}
+
+/// Re-executes a journal on an empty ledger, checking the state hash after each entry.
+///
+/// Returns the final state hash.
+pub fn replay_journal<L: SubstateStore>(
+    substate_store: &mut L,
+    records: &[JournalRecord],
+    trace: bool,
+) -> Result<Hash, JournalError> {
+    if !matches!(
+        records.first(),
+        Some(JournalRecord {
+            entry: JournalEntry::Genesis { .. } | JournalEntry::ImportState { .. },
+            ..
+        })
+    ) {
+        substate_store.bootstrap();
+    }
+
+    let mut executor = TransactionExecutor::new(substate_store, trace);
+    for (index, record) in records.iter().enumerate() {
+        apply_journal_entry(&mut executor, &record.entry)?;
+
+        let actual = state_hash(executor.substate_store()).map_err(JournalError::DecodeError)?;
+        if actual != record.state_hash {
+            return Err(JournalError::StateHashMismatch {
+                index,
+                expected: record.state_hash,
+                actual,
+            });
+        }
+    }
+    state_hash(executor.substate_store()).map_err(JournalError::DecodeError)
+}
@@ -1,5 +1,13 @@
+mod archive;
+mod diff;
 mod dumper;
+mod history;
+mod journal;
 mod radix_engine_db;
 
+pub use archive::*;
+pub use diff::*;
 pub use dumper::*;
+pub use history::*;
+pub use journal::*;
 pub use radix_engine_db::RadixEngineDB;
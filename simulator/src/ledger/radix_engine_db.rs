@@ -1,19 +1,43 @@
-use std::collections::HashMap;
 use std::path::PathBuf;
 
 use radix_engine::ledger::*;
-use rocksdb::{DBWithThreadMode, Direction, IteratorMode, SingleThreaded, DB};
-use sbor::{Decode, Encode};
+use rocksdb::{DBWithThreadMode, Direction, IteratorMode, Options, SingleThreaded, DB};
+use sbor::{Decode, DecodeError, Encode};
 use scrypto::buffer::*;
 use scrypto::engine::types::*;
 
+use crate::ledger::TransactionRecord;
+
+/// Column family of substates, keyed by encoded address.
+const SUBSTATES_CF: &str = "substates";
+/// Column family of child substates, keyed by the length of the encoded address (big-endian),
+/// the encoded address and the child key.
+const CHILD_SUBSTATES_CF: &str = "child_substates";
+/// Column family of transaction records, keyed by transaction hash.
+const TRANSACTIONS_CF: &str = "transactions";
+/// Column family of transaction hashes, keyed by execution sequence number (big-endian).
+const TRANSACTION_INDEX_CF: &str = "transaction_index";
+
 pub struct RadixEngineDB {
     db: DBWithThreadMode<SingleThreaded>,
 }
 
 impl RadixEngineDB {
     pub fn new(root: PathBuf) -> Self {
-        let db = DB::open_default(root.as_path()).unwrap();
+        let mut options = Options::default();
+        options.create_if_missing(true);
+        options.create_missing_column_families(true);
+        let db = DB::open_cf(
+            &options,
+            root.as_path(),
+            [
+                SUBSTATES_CF,
+                CHILD_SUBSTATES_CF,
+                TRANSACTIONS_CF,
+                TRANSACTION_INDEX_CF,
+            ],
+        )
+        .unwrap();
         Self { db }
     }
 
@@ -42,9 +66,10 @@ impl RadixEngineDB {
     }
 
     fn list_items<T: Decode>(&self, start: &[u8], inclusive_end: &[u8]) -> Vec<T> {
+        let substates = self.db.cf_handle(SUBSTATES_CF).unwrap();
         let mut iter = self
             .db
-            .iterator(IteratorMode::From(start, Direction::Forward));
+            .iterator_cf(substates, IteratorMode::From(start, Direction::Forward));
         let mut items = Vec::new();
         while let Some(kv) = iter.next() {
             if kv.0.as_ref() > inclusive_end {
@@ -57,6 +82,78 @@ impl RadixEngineDB {
         items
     }
 
+    /// Stores an executed transaction and its receipt.
+    pub fn put_transaction_record(&mut self, record: &TransactionRecord) {
+        let transactions = self.db.cf_handle(TRANSACTIONS_CF).unwrap();
+        let index = self.db.cf_handle(TRANSACTION_INDEX_CF).unwrap();
+
+        let hash = record.intent_hash.to_vec();
+        if self.db.get_cf(transactions, &hash).unwrap().is_none() {
+            let next_seq = self
+                .db
+                .iterator_cf(index, IteratorMode::End)
+                .next()
+                .map(|(key, _)| u64::from_be_bytes(key.as_ref().try_into().unwrap()) + 1)
+                .unwrap_or(0);
+            self.db
+                .put_cf(index, next_seq.to_be_bytes(), &hash)
+                .unwrap();
+        }
+        self.db
+            .put_cf(transactions, &hash, scrypto_encode(record))
+            .unwrap();
+    }
+
+    /// Returns the transaction record of the given hash.
+    pub fn get_transaction_record(&self, hash: &Hash) -> Option<TransactionRecord> {
+        let transactions = self.db.cf_handle(TRANSACTIONS_CF).unwrap();
+        self.db
+            .get_cf(transactions, hash.to_vec())
+            .unwrap()
+            .map(|v| scrypto_decode(&v).unwrap())
+    }
+
+    /// Returns the most recent transaction records, newest first.
+    pub fn list_transaction_records(&self, limit: usize) -> Vec<TransactionRecord> {
+        let index = self.db.cf_handle(TRANSACTION_INDEX_CF).unwrap();
+        self.db
+            .iterator_cf(index, IteratorMode::End)
+            .take(limit)
+            .filter_map(|(_, hash)| {
+                Hash::try_from(hash.as_ref())
+                    .ok()
+                    .and_then(|h| self.get_transaction_record(&h))
+            })
+            .collect()
+    }
+
+    /// Returns the database key of a child substate.
+    fn child_substate_id(address: &[u8], key: &[u8]) -> Vec<u8> {
+        let mut id = (address.len() as u32).to_be_bytes().to_vec();
+        id.extend(address);
+        id.extend(key);
+        id
+    }
+
+    /// Splits the database key of a child substate into the encoded address and the child key.
+    fn split_child_substate_id(id: &[u8]) -> Result<(&[u8], &[u8]), DecodeError> {
+        if id.len() < 4 {
+            return Err(DecodeError::Underflow {
+                required: 4,
+                remaining: id.len(),
+            });
+        }
+        let (len, rest) = id.split_at(4);
+        let address_len = u32::from_be_bytes(len.try_into().unwrap()) as usize;
+        if rest.len() < address_len {
+            return Err(DecodeError::Underflow {
+                required: address_len,
+                remaining: rest.len(),
+            });
+        }
+        Ok(rest.split_at(address_len))
+    }
+
     fn read(&self, key: &[u8]) -> Option<Vec<u8>> {
         // TODO: Use get_pinned
         self.db.get(key).unwrap()
@@ -65,55 +162,107 @@ impl RadixEngineDB {
     fn write(&self, key: &[u8], value: &[u8]) {
         self.db.put(key, value).unwrap();
     }
+
+    fn read_substate(&self, cf: &str, id: &[u8]) -> Option<Substate> {
+        let cf = self.db.cf_handle(cf).unwrap();
+        self.db
+            .get_cf(cf, id)
+            .unwrap()
+            .map(|b| scrypto_decode(&b).unwrap())
+    }
+
+    fn write_substate(&self, cf: &str, id: &[u8], substate: &Substate) {
+        let cf = self.db.cf_handle(cf).unwrap();
+        self.db.put_cf(cf, id, scrypto_encode(substate)).unwrap();
+    }
+
+    fn delete_substate_id(&self, cf: &str, id: &[u8]) {
+        let cf = self.db.cf_handle(cf).unwrap();
+        self.db.delete_cf(cf, id).unwrap();
+    }
 }
 
-impl QueryableSubstateStore for RadixEngineDB {
-    fn get_lazy_map_entries(
+impl SubstateStore for RadixEngineDB {
+    fn get_substate<T: Encode>(&self, address: &T) -> Option<Substate> {
+        self.read_substate(SUBSTATES_CF, &scrypto_encode(address))
+    }
+
+    fn put_substate<T: Encode>(&mut self, address: &T, substate: Substate) {
+        self.write_substate(SUBSTATES_CF, &scrypto_encode(address), &substate);
+    }
+
+    fn delete_substate<T: Encode>(&mut self, address: &T) {
+        self.delete_substate_id(SUBSTATES_CF, &scrypto_encode(address));
+    }
+
+    fn get_child_substate<T: Encode>(&self, address: &T, key: &[u8]) -> Option<Substate> {
+        let id = Self::child_substate_id(&scrypto_encode(address), key);
+        self.read_substate(CHILD_SUBSTATES_CF, &id)
+    }
+
+    fn put_child_substate<T: Encode>(&mut self, address: &T, key: &[u8], substate: Substate) {
+        let id = Self::child_substate_id(&scrypto_encode(address), key);
+        self.write_substate(CHILD_SUBSTATES_CF, &id, &substate);
+    }
+
+    fn delete_child_substate<T: Encode>(&mut self, address: &T, key: &[u8]) {
+        let id = Self::child_substate_id(&scrypto_encode(address), key);
+        self.delete_substate_id(CHILD_SUBSTATES_CF, &id);
+    }
+
+    fn get_child_substates_by_prefix<T: Encode>(
         &self,
-        component_address: ComponentAddress,
-        lazy_map_id: &LazyMapId,
-    ) -> HashMap<Vec<u8>, Vec<u8>> {
-        let mut id = scrypto_encode(&component_address);
-        id.extend(scrypto_encode(lazy_map_id));
-        let key_size = id.len();
+        address: &T,
+        prefix: &[u8],
+    ) -> Vec<(Vec<u8>, Substate)> {
+        let address_id = Self::child_substate_id(&scrypto_encode(address), &[]);
+        let mut id = address_id.clone();
+        id.extend(prefix);
 
+        let child_substates = self.db.cf_handle(CHILD_SUBSTATES_CF).unwrap();
         let mut iter = self
             .db
-            .iterator(IteratorMode::From(&id, Direction::Forward));
-        let mut items = HashMap::new();
+            .iterator_cf(child_substates, IteratorMode::From(&id, Direction::Forward));
+        let mut items = Vec::new();
         while let Some((key, value)) = iter.next() {
             if !key.starts_with(&id) {
                 break;
             }
-
-            let local_key = key.split_at(key_size).1.to_vec();
-            let substate: Substate = scrypto_decode(&value.to_vec()).unwrap();
-            items.insert(local_key, substate.value);
+            items.push((
+                key[address_id.len()..].to_vec(),
+                scrypto_decode(&value).unwrap(),
+            ));
         }
         items
     }
-}
 
-impl SubstateStore for RadixEngineDB {
-    fn get_substate<T: Encode>(&self, address: &T) -> Option<Substate> {
-        self.read(&scrypto_encode(address))
-            .map(|b| scrypto_decode(&b).unwrap())
+    fn iter_substates<F: FnMut(&[u8], Substate)>(&self, mut f: F) -> Result<(), DecodeError> {
+        let substates = self.db.cf_handle(SUBSTATES_CF).unwrap();
+        for (key, value) in self.db.iterator_cf(substates, IteratorMode::Start) {
+            f(key.as_ref(), scrypto_decode(&value)?);
+        }
+        Ok(())
     }
 
-    fn put_substate<T: Encode>(&mut self, address: &T, substate: Substate) {
-        self.write(&scrypto_encode(address), &scrypto_encode(&substate));
+    fn iter_child_substates<F: FnMut(&[u8], &[u8], Substate)>(
+        &self,
+        mut f: F,
+    ) -> Result<(), DecodeError> {
+        let child_substates = self.db.cf_handle(CHILD_SUBSTATES_CF).unwrap();
+        for (key, value) in self.db.iterator_cf(child_substates, IteratorMode::Start) {
+            let (address, child_key) = Self::split_child_substate_id(&key)?;
+            f(address, child_key, scrypto_decode(&value)?);
+        }
+        Ok(())
     }
 
-    fn get_child_substate<T: Encode>(&self, address: &T, key: &[u8]) -> Option<Substate> {
-        let mut id = scrypto_encode(address);
-        id.extend(key.to_vec());
-        self.read(&id).map(|b| scrypto_decode(&b).unwrap())
+    fn put_raw_substate(&mut self, address: &[u8], substate: Substate) {
+        self.write_substate(SUBSTATES_CF, address, &substate);
     }
 
-    fn put_child_substate<T: Encode>(&mut self, address: &T, key: &[u8], substate: Substate) {
-        let mut id = scrypto_encode(address);
-        id.extend(key.to_vec());
-        self.write(&id, &scrypto_encode(&substate));
+    fn put_raw_child_substate(&mut self, address: &[u8], key: &[u8], substate: Substate) {
+        let id = Self::child_substate_id(address, key);
+        self.write_substate(CHILD_SUBSTATES_CF, &id, &substate);
     }
 
     fn get_epoch(&self) -> u64 {
@@ -141,4 +290,10 @@ impl SubstateStore for RadixEngineDB {
         let value = scrypto_encode(&(self.get_nonce() + 1));
         self.write(&id, &value)
     }
+
+    fn set_nonce(&mut self, nonce: u64) {
+        let id = scrypto_encode(&"nonce");
+        let value = scrypto_encode(&nonce);
+        self.write(&id, &value)
+    }
 }
@@ -0,0 +1,45 @@
+use clap::Parser;
+use radix_engine::transaction::*;
+use scrypto::engine::types::*;
+
+use crate::resim::*;
+
+/// Call a read-only method, without a transaction
+#[derive(Parser, Debug)]
+pub struct CallView {
+    /// The component that the method belongs to
+    component_address: ComponentAddress,
+
+    /// The method name
+    method_name: String,
+
+    /// The call arguments
+    arguments: Vec<String>,
+
+    /// Turn on tracing
+    #[clap(short, long)]
+    trace: bool,
+}
+
+impl CallView {
+    pub fn run<O: std::io::Write>(&self, out: &mut O) -> Result<(), Error> {
+        let mut ledger = RadixEngineDB::with_bootstrap(get_data_dir()?);
+        let mut executor = TransactionExecutor::new(&mut ledger, self.trace);
+
+        let args = TransactionBuilder::new()
+            .parse_method_args(
+                &self.method_name,
+                self.arguments.clone(),
+                None,
+                &executor
+                    .export_abi_by_component(self.component_address)
+                    .map_err(Error::AbiExportError)?,
+            )
+            .map_err(Error::TransactionConstructionError)?;
+        let output = executor
+            .query(self.component_address, &self.method_name, args)
+            .map_err(Error::TransactionExecutionError)?;
+        writeln!(out, "{}", output).map_err(Error::IOError)?;
+        Ok(())
+    }
+}
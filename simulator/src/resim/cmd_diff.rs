@@ -0,0 +1,44 @@
+use clap::Parser;
+use radix_engine::ledger::*;
+use std::fs::File;
+use std::io::BufReader;
+use std::path::{Path, PathBuf};
+
+use crate::ledger::*;
+use crate::resim::*;
+
+/// Compare the ledger state of two data directories or exported snapshots
+#[derive(Parser, Debug)]
+pub struct Diff {
+    /// The data directory or snapshot file (see `export-state`) to compare from
+    #[clap(long)]
+    from: PathBuf,
+
+    /// The data directory or snapshot file (see `export-state`) to compare to
+    #[clap(long)]
+    to: PathBuf,
+}
+
+impl Diff {
+    pub fn run<O: std::io::Write>(&self, out: &mut O) -> Result<(), Error> {
+        let from = Self::load(&self.from)?;
+        let to = Self::load(&self.to)?;
+
+        let summary = dump_diff(&from, &to, out).map_err(Error::LedgerDiffError)?;
+        writeln!(out, "Found {} differences.", summary.total()).map_err(Error::IOError)?;
+        Ok(())
+    }
+
+    /// Loads a ledger into memory, so that the same data directory can be compared with itself.
+    fn load(path: &Path) -> Result<InMemorySubstateStore, Error> {
+        if path.is_dir() {
+            let ledger = RadixEngineDB::new(path.to_path_buf());
+            load_into_memory(&ledger).map_err(Error::DataError)
+        } else {
+            let file = File::open(path).map_err(Error::IOError)?;
+            let mut ledger = InMemorySubstateStore::new();
+            import_state(&mut ledger, &mut BufReader::new(file)).map_err(Error::ArchiveError)?;
+            Ok(ledger)
+        }
+    }
+}
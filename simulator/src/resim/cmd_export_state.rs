@@ -0,0 +1,31 @@
+use clap::Parser;
+use std::fs::File;
+use std::io::BufWriter;
+use std::path::PathBuf;
+
+use crate::ledger::*;
+use crate::resim::*;
+
+/// Export the ledger state into a portable archive
+#[derive(Parser, Debug)]
+pub struct ExportState {
+    /// The path to the archive file
+    path: PathBuf,
+}
+
+impl ExportState {
+    pub fn run<O: std::io::Write>(&self, out: &mut O) -> Result<(), Error> {
+        let ledger = RadixEngineDB::with_bootstrap(get_data_dir()?);
+        let file = File::create(&self.path).map_err(Error::IOError)?;
+        let summary =
+            export_state(&ledger, &mut BufWriter::new(file)).map_err(Error::ArchiveError)?;
+
+        writeln!(
+            out,
+            "Exported {} substates and {} child substates (epoch: {}, nonce: {})",
+            summary.substates, summary.child_substates, summary.epoch, summary.nonce
+        )
+        .map_err(Error::IOError)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,74 @@
+use clap::Parser;
+use colored::*;
+
+use crate::ledger::*;
+use crate::resim::*;
+use crate::utils::*;
+
+/// List the most recent transactions executed by this simulator
+#[derive(Parser, Debug)]
+pub struct History {
+    /// The maximum number of transactions to list
+    #[clap(short, long, default_value = "10")]
+    limit: usize,
+}
+
+impl History {
+    pub fn run<O: std::io::Write>(&self, out: &mut O) -> Result<(), Error> {
+        let ledger = RadixEngineDB::with_bootstrap(get_data_dir()?);
+
+        writeln!(out, "{}:", "Transactions".green().bold()).map_err(Error::IOError)?;
+        for (last, record) in ledger
+            .list_transaction_records(self.limit)
+            .iter()
+            .identify_last()
+        {
+            let status = if record.is_success() {
+                "SUCCESS".blue()
+            } else {
+                "FAILURE".red()
+            };
+            writeln!(
+                out,
+                "{} {} {} (new entities: {})",
+                list_item_prefix(last),
+                record.intent_hash,
+                status,
+                record.new_entities()
+            )
+            .map_err(Error::IOError)?;
+
+            let entities = record
+                .receipt
+                .new_package_addresses
+                .iter()
+                .map(|a| format!("Package: {}", a))
+                .chain(
+                    record
+                        .receipt
+                        .new_component_addresses
+                        .iter()
+                        .map(|a| format!("Component: {}", a)),
+                )
+                .chain(
+                    record
+                        .receipt
+                        .new_resource_addresses
+                        .iter()
+                        .map(|a| format!("Resource: {}", a)),
+                )
+                .collect::<Vec<String>>();
+            for (last_entity, entity) in entities.iter().identify_last() {
+                writeln!(
+                    out,
+                    "{}  {} {}",
+                    if last { " " } else { "│" },
+                    list_item_prefix(last_entity),
+                    entity
+                )
+                .map_err(Error::IOError)?;
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,41 @@
+use clap::Parser;
+use scrypto::crypto::hash;
+use std::fs;
+use std::path::PathBuf;
+
+use crate::ledger::*;
+use crate::resim::*;
+
+/// Import the ledger state from an archive created by `export-state`
+///
+/// The simulator must be reset first.
+#[derive(Parser, Debug)]
+pub struct ImportState {
+    /// The path to the archive file
+    path: PathBuf,
+}
+
+impl ImportState {
+    pub fn run<O: std::io::Write>(&self, out: &mut O) -> Result<(), Error> {
+        let mut ledger = RadixEngineDB::new(get_data_dir()?);
+        let archive = fs::read(&self.path).map_err(Error::IOError)?;
+        let summary =
+            import_state(&mut ledger, &mut archive.as_slice()).map_err(Error::ArchiveError)?;
+        let path = fs::canonicalize(&self.path).map_err(Error::IOError)?;
+        record_journal_entry(
+            &ledger,
+            JournalEntry::ImportState {
+                path: path.to_string_lossy().to_string(),
+                archive_hash: hash(&archive),
+            },
+        )?;
+
+        writeln!(
+            out,
+            "Imported {} substates and {} child substates (epoch: {}, nonce: {})",
+            summary.substates, summary.child_substates, summary.epoch, summary.nonce
+        )
+        .map_err(Error::IOError)?;
+        Ok(())
+    }
+}
@@ -3,6 +3,7 @@ use colored::*;
 use rand::Rng;
 use scrypto::prelude::*;
 
+use crate::ledger::*;
 use crate::resim::*;
 
 /// Create an account
@@ -22,21 +23,20 @@ impl NewAccount {
         let mut ledger = RadixEngineDB::with_bootstrap(get_data_dir()?);
         let mut executor = TransactionExecutor::new(&mut ledger, self.trace);
 
+        let secret = rand::thread_rng().gen::<[u8; 32]>();
+        let private_key = EcdsaPrivateKey::from_bytes(&secret).unwrap();
+        let public_key = private_key.public_key();
+        let auth_address =
+            NonFungibleAddress::new(ECDSA_TOKEN, NonFungibleId::from_bytes(public_key.to_vec()));
+        let withdraw_auth = rule!(require(auth_address));
+        let mut transaction = TransactionBuilder::new()
+            .call_method(SYSTEM_COMPONENT, "free_xrd", vec![])
+            .take_from_worktop(RADIX_TOKEN, |builder, bucket_id| {
+                builder.new_account_with_resource(&withdraw_auth, bucket_id)
+            })
+            .build_with_no_nonce();
+
         if let Some(path) = &self.manifest {
-            let secret = rand::thread_rng().gen::<[u8; 32]>();
-            let private_key = EcdsaPrivateKey::from_bytes(&secret).unwrap();
-            let public_key = private_key.public_key();
-            let auth_address = NonFungibleAddress::new(
-                ECDSA_TOKEN,
-                NonFungibleId::from_bytes(public_key.to_vec()),
-            );
-            let withdraw_auth = rule!(require(auth_address));
-            let transaction = TransactionBuilder::new()
-                .call_method(SYSTEM_COMPONENT, "free_xrd", vec![])
-                .take_from_worktop(RADIX_TOKEN, |builder, bucket_id| {
-                    builder.new_account_with_resource(&withdraw_auth, bucket_id)
-                })
-                .build_with_no_nonce();
             process_transaction(&mut executor, transaction, &None, &Some(path.clone()), out)?;
             writeln!(out, "A manifest has been produced for the following key pair. To complete account creation, you will need to run the manifest!").map_err(Error::IOError)?;
             writeln!(out, "Public key: {}", public_key.to_string().green())
@@ -48,7 +48,10 @@ impl NewAccount {
             )
             .map_err(Error::IOError)?;
         } else {
-            let (public_key, private_key, account) = executor.new_account();
+            transaction.add_nonce(executor.get_nonce([]));
+            let receipt = execute_transaction(&mut executor, &transaction.sign([]))?;
+            receipt.result.map_err(Error::TransactionExecutionError)?;
+            let account = receipt.new_component_addresses[0];
             writeln!(out, "A new account has been created!").map_err(Error::IOError)?;
             writeln!(
                 out,
@@ -2,6 +2,8 @@ use clap::Parser;
 use colored::*;
 use radix_engine::transaction::*;
 use scrypto::engine::types::*;
+use scrypto::misc::package_metadata;
+use std::collections::HashMap;
 use std::ffi::OsStr;
 use std::fs;
 use std::path::PathBuf;
@@ -31,23 +33,29 @@ pub struct Publish {
 
 impl Publish {
     pub fn run<O: std::io::Write>(&self, out: &mut O) -> Result<(), Error> {
-        // Load wasm code
-        let code = fs::read(if self.path.extension() != Some(OsStr::new("wasm")) {
-            build_package(&self.path, false).map_err(Error::CargoError)?
+        // Load wasm code, and metadata from the manifest of a package
+        let (code, metadata) = if self.path.extension() != Some(OsStr::new("wasm")) {
+            let wasm_path = build_package(&self.path, false).map_err(Error::CargoError)?;
+            (
+                fs::read(wasm_path).map_err(Error::IOError)?,
+                package_metadata(&self.path),
+            )
         } else {
-            self.path.clone()
-        })
-        .map_err(Error::IOError)?;
+            (
+                fs::read(&self.path).map_err(Error::IOError)?,
+                HashMap::new(),
+            )
+        };
 
         if let Some(path) = &self.manifest {
             let mut ledger = RadixEngineDB::with_bootstrap(get_data_dir()?);
             let mut executor = TransactionExecutor::new(&mut ledger, self.trace);
             let transaction = TransactionBuilder::new()
-                .publish_package(code.as_ref())
+                .publish_package_with_metadata(code.as_ref(), metadata)
                 .build_with_no_nonce();
             process_transaction(&mut executor, transaction, &None, &Some(path.clone()), out)?;
         } else {
-            self.store_package(out, &code)?;
+            self.store_package(out, &code, metadata)?;
         }
         Ok(())
     }
@@ -61,22 +69,41 @@ impl Publish {
         println!("Publishing ..");
         let code = fs::read(wasm_file_path).map_err(Error::IOError)?;
         println!("Read code to variable");
-        self.store_package(out, &code)
+        self.store_package(out, &code, HashMap::new())
     }
 
-    pub fn store_package<O: std::io::Write>(&self, out: &mut O, code: &[u8]) -> Result<(), Error> {
+    pub fn store_package<O: std::io::Write>(
+        &self,
+        out: &mut O,
+        code: &[u8],
+        metadata: HashMap<String, String>,
+    ) -> Result<(), Error> {
         let mut ledger = RadixEngineDB::with_bootstrap(get_data_dir()?);
         let mut executor = TransactionExecutor::new(&mut ledger, self.trace);
         if let Some(package_address) = self.package_address.clone() {
             // Overwrite package
             executor
-                .overwrite_package(package_address, code.to_vec())
+                .overwrite_package(package_address, code.to_vec(), metadata.clone())
                 .map_err(|e| Error::PackageValidationError(e))?;
+            record_journal_entry(
+                executor.substate_store(),
+                JournalEntry::OverwritePackage {
+                    package_address,
+                    code: code.to_vec(),
+                    metadata,
+                },
+            )?;
             writeln!(out, "Package updated!").map_err(Error::IOError)?;
             Ok(())
         } else {
-            match executor.publish_package(code) {
-                Ok(package_address) => {
+            let transaction = TransactionBuilder::new()
+                .publish_package_with_metadata(code, metadata)
+                .build(executor.get_nonce([]))
+                .sign([]);
+            let receipt = execute_transaction(&mut executor, &transaction)?;
+            match receipt.result {
+                Ok(()) => {
+                    let package_address = receipt.new_package_addresses[0];
                     writeln!(
                         out,
                         "Success! New Package: {}",
@@ -0,0 +1,40 @@
+use clap::Parser;
+use colored::*;
+use radix_engine::ledger::*;
+use std::fs::File;
+use std::io::BufReader;
+use std::path::PathBuf;
+
+use crate::ledger::*;
+use crate::resim::*;
+
+/// Replay a journal on a fresh ledger, checking the state hash at each step
+#[derive(Parser, Debug)]
+pub struct Replay {
+    /// The journal file, e.g. `journal.bin` under the data directory
+    path: PathBuf,
+
+    /// Turn on tracing
+    #[clap(short, long)]
+    trace: bool,
+}
+
+impl Replay {
+    pub fn run<O: std::io::Write>(&self, out: &mut O) -> Result<(), Error> {
+        let file = File::open(&self.path).map_err(Error::IOError)?;
+        let records = read_journal(&mut BufReader::new(file)).map_err(Error::JournalError)?;
+
+        let mut ledger = InMemorySubstateStore::new();
+        let state_hash =
+            replay_journal(&mut ledger, &records, self.trace).map_err(Error::JournalError)?;
+
+        writeln!(
+            out,
+            "Replayed {} journal entries. Final state hash: {}",
+            records.len(),
+            state_hash.to_string().green()
+        )
+        .map_err(Error::IOError)?;
+        Ok(())
+    }
+}
@@ -1,17 +1,68 @@
 use clap::Parser;
+use colored::*;
+use radix_engine::ledger::*;
 use std::fs::remove_dir_all;
+use std::path::PathBuf;
 
+use crate::ledger::*;
 use crate::resim::*;
 
 /// Reset this simulator
 #[derive(Parser, Debug)]
-pub struct Reset {}
+pub struct Reset {
+    /// Bootstrap the ledger from a genesis file, in JSON
+    #[clap(long)]
+    genesis: Option<PathBuf>,
+}
 
 impl Reset {
     pub fn run<O: std::io::Write>(&self, out: &mut O) -> Result<(), Error> {
+        // Load the genesis before clearing anything, so that a bad file leaves the ledger intact
+        let genesis = match &self.genesis {
+            Some(path) => Some(load_genesis(path)?),
+            None => None,
+        };
+
         let dir = get_data_dir()?;
         remove_dir_all(dir).map_err(Error::IOError)?;
         writeln!(out, "Data directory cleared.").map_err(Error::IOError)?;
+
+        if let Some(genesis) = genesis {
+            let mut ledger = RadixEngineDB::new(get_data_dir()?);
+            let receipt = ledger
+                .bootstrap_with_genesis(&genesis)
+                .map_err(Error::GenesisError)?
+                .expect("Data directory should be empty");
+            record_journal_entry(
+                &ledger,
+                JournalEntry::Genesis {
+                    genesis: genesis.clone(),
+                },
+            )?;
+            writeln!(
+                out,
+                "Ledger bootstrapped from genesis (epoch: {}).",
+                genesis.epoch
+            )
+            .map_err(Error::IOError)?;
+            for package_address in receipt.package_addresses {
+                writeln!(out, "Package: {}", package_address.to_string().green())
+                    .map_err(Error::IOError)?;
+            }
+            for resource_address in receipt.resource_addresses {
+                writeln!(out, "Resource: {}", resource_address.to_string().green())
+                    .map_err(Error::IOError)?;
+            }
+            for (public_key, component_address) in receipt.account_addresses {
+                writeln!(
+                    out,
+                    "Account: {} (public key: {})",
+                    component_address.to_string().green(),
+                    public_key
+                )
+                .map_err(Error::IOError)?;
+            }
+        }
         Ok(())
     }
 }
@@ -1,6 +1,7 @@
 use clap::Parser;
 use radix_engine::ledger::SubstateStore;
 
+use crate::ledger::*;
 use crate::resim::*;
 
 /// Set the current epoch
@@ -14,6 +15,7 @@ impl SetCurrentEpoch {
     pub fn run<O: std::io::Write>(&self, out: &mut O) -> Result<(), Error> {
         let mut ledger = RadixEngineDB::with_bootstrap(get_data_dir()?);
         ledger.set_epoch(self.epoch);
+        record_journal_entry(&ledger, JournalEntry::SetEpoch { epoch: self.epoch })?;
 
         writeln!(out, "Current epoch set!").map_err(Error::IOError)?;
         Ok(())
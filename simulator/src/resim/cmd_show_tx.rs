@@ -0,0 +1,40 @@
+use clap::Parser;
+use colored::*;
+use scrypto::engine::types::*;
+use std::str::FromStr;
+
+use crate::ledger::*;
+use crate::resim::*;
+use crate::utils::*;
+
+/// Show a transaction executed by this simulator, with its receipt
+#[derive(Parser, Debug)]
+pub struct ShowTx {
+    /// The intent hash of the transaction
+    hash: String,
+}
+
+impl ShowTx {
+    pub fn run<O: std::io::Write>(&self, out: &mut O) -> Result<(), Error> {
+        let ledger = RadixEngineDB::with_bootstrap(get_data_dir()?);
+
+        let hash = Hash::from_str(&self.hash).map_err(|_| Error::InvalidId(self.hash.clone()))?;
+        let record = ledger
+            .get_transaction_record(&hash)
+            .ok_or(Error::TransactionNotFound(hash))?;
+
+        writeln!(
+            out,
+            "{} {}",
+            "Intent Hash:".bold().green(),
+            record.intent_hash
+        )
+        .map_err(Error::IOError)?;
+        writeln!(out, "{}", "Signers:".bold().green()).map_err(Error::IOError)?;
+        for (last, (public_key, _)) in record.signatures.iter().identify_last() {
+            writeln!(out, "{} {}", list_item_prefix(last), public_key).map_err(Error::IOError)?;
+        }
+        writeln!(out, "{}", record.receipt.rendered).map_err(Error::IOError)?;
+        Ok(())
+    }
+}
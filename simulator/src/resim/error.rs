@@ -1,8 +1,10 @@
 use std::io;
 
 use radix_engine::errors::*;
+use radix_engine::ledger::*;
 use radix_engine::transaction::*;
 use sbor::*;
+use scrypto::crypto::Hash;
 
 use crate::ledger::*;
 use crate::utils::*;
@@ -36,11 +38,23 @@ pub enum Error {
 
     LedgerDumpError(DisplayError),
 
+    LedgerDiffError(DiffError),
+
+    ArchiveError(ArchiveError),
+
+    JournalError(JournalError),
+
+    GenesisError(GenesisError),
+
+    InvalidGenesis(String),
+
     CompileError(transaction_manifest::CompileError),
 
     DecompileError(transaction_manifest::DecompileError),
 
     InvalidId(String),
 
+    TransactionNotFound(Hash),
+
     InvalidPrivateKey,
 }
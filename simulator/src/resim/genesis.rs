@@ -0,0 +1,97 @@
+use radix_engine::ledger::*;
+use radix_engine::model::PackageVersion;
+use scrypto::prelude::*;
+use serde::Deserialize;
+use std::fs;
+use std::path::Path;
+
+use crate::resim::*;
+
+/// A genesis file, in JSON.
+///
+/// Public keys are hex-encoded; amounts are decimal strings; package paths are relative
+/// to the genesis file.
+#[derive(Debug, Deserialize)]
+pub struct GenesisFile {
+    #[serde(default)]
+    pub epoch: u64,
+    #[serde(default)]
+    pub xrd_metadata: Option<HashMap<String, String>>,
+    #[serde(default)]
+    pub xrd_allocations: Vec<GenesisAllocation>,
+    #[serde(default)]
+    pub packages: Vec<String>,
+    #[serde(default)]
+    pub resources: Vec<GenesisFileResource>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct GenesisAllocation {
+    pub public_key: String,
+    pub amount: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct GenesisFileResource {
+    #[serde(default = "default_divisibility")]
+    pub divisibility: u8,
+    #[serde(default)]
+    pub metadata: HashMap<String, String>,
+    #[serde(default)]
+    pub allocations: Vec<GenesisAllocation>,
+}
+
+fn default_divisibility() -> u8 {
+    18
+}
+
+fn parse_allocations(
+    allocations: &[GenesisAllocation],
+) -> Result<Vec<(EcdsaPublicKey, Decimal)>, Error> {
+    allocations
+        .iter()
+        .map(|a| {
+            let public_key = EcdsaPublicKey::from_str(&a.public_key).map_err(|_| {
+                Error::InvalidGenesis(format!("Invalid public key: {}", a.public_key))
+            })?;
+            let amount = Decimal::from_str(&a.amount)
+                .map_err(|_| Error::InvalidGenesis(format!("Invalid amount: {}", a.amount)))?;
+            Ok((public_key, amount))
+        })
+        .collect()
+}
+
+/// Loads a genesis config from a JSON file.
+pub fn load_genesis<P: AsRef<Path>>(path: P) -> Result<GenesisConfig, Error> {
+    let path = path.as_ref();
+    let content = fs::read_to_string(path).map_err(Error::IOError)?;
+    let file: GenesisFile = serde_json::from_str(&content).map_err(Error::JSONError)?;
+
+    let base_dir = path.parent().unwrap_or_else(|| Path::new("."));
+    let mut packages = Vec::new();
+    for package_path in &file.packages {
+        let code = fs::read(base_dir.join(package_path)).map_err(Error::IOError)?;
+        packages.push(
+            PackageVersion::new(code, HashMap::new()).map_err(Error::PackageValidationError)?,
+        );
+    }
+
+    let mut resources = Vec::new();
+    for resource in &file.resources {
+        resources.push(GenesisResource {
+            divisibility: resource.divisibility,
+            metadata: resource.metadata.clone(),
+            allocations: parse_allocations(&resource.allocations)?,
+        });
+    }
+
+    Ok(GenesisConfig {
+        epoch: file.epoch,
+        xrd_metadata: file
+            .xrd_metadata
+            .unwrap_or_else(GenesisConfig::default_xrd_metadata),
+        xrd_allocations: parse_allocations(&file.xrd_allocations)?,
+        packages,
+        resources,
+    })
+}
@@ -1,7 +1,12 @@
 mod cmd_call_function;
 mod cmd_call_method;
+mod cmd_call_view;
+mod cmd_diff;
 mod cmd_export_abi;
+mod cmd_export_state;
 mod cmd_generate_key_pair;
+mod cmd_history;
+mod cmd_import_state;
 mod cmd_mint;
 mod cmd_new_account;
 mod cmd_new_badge_fixed;
@@ -9,6 +14,7 @@ mod cmd_new_badge_mutable;
 mod cmd_new_token_fixed;
 mod cmd_new_token_mutable;
 mod cmd_publish;
+mod cmd_replay;
 mod cmd_reset;
 mod cmd_run;
 mod cmd_set_current_epoch;
@@ -16,14 +22,21 @@ mod cmd_set_default_account;
 mod cmd_show;
 mod cmd_show_configs;
 mod cmd_show_ledger;
+mod cmd_show_tx;
 mod cmd_transfer;
 mod config;
 mod error;
+mod genesis;
 
 pub use cmd_call_function::*;
 pub use cmd_call_method::*;
+pub use cmd_call_view::*;
+pub use cmd_diff::*;
 pub use cmd_export_abi::*;
+pub use cmd_export_state::*;
 pub use cmd_generate_key_pair::*;
+pub use cmd_history::*;
+pub use cmd_import_state::*;
 pub use cmd_mint::*;
 pub use cmd_new_account::*;
 pub use cmd_new_badge_fixed::*;
@@ -31,6 +44,7 @@ pub use cmd_new_badge_mutable::*;
 pub use cmd_new_token_fixed::*;
 pub use cmd_new_token_mutable::*;
 pub use cmd_publish::*;
+pub use cmd_replay::*;
 pub use cmd_reset::*;
 pub use cmd_run::*;
 pub use cmd_set_current_epoch::*;
@@ -38,9 +52,11 @@ pub use cmd_set_default_account::*;
 pub use cmd_show::*;
 pub use cmd_show_configs::*;
 pub use cmd_show_ledger::*;
+pub use cmd_show_tx::*;
 pub use cmd_transfer::*;
 pub use config::*;
 pub use error::*;
+pub use genesis::*;
 
 pub const DEFAULT_SCRYPTO_DIR_UNDER_HOME: &'static str = ".scrypto";
 pub const ENV_DATA_DIR: &'static str = "DATA_DIR";
@@ -76,8 +92,13 @@ impl ResimCli {
 pub enum Command {
     CallFunction(CallFunction),
     CallMethod(CallMethod),
+    CallView(CallView),
+    Diff(Diff),
     ExportAbi(ExportAbi),
+    ExportState(ExportState),
     GenerateKeyPair(GenerateKeyPair),
+    History(History),
+    ImportState(ImportState),
     Mint(Mint),
     NewAccount(NewAccount),
     NewBadgeFixed(NewBadgeFixed),
@@ -85,12 +106,14 @@ pub enum Command {
     NewTokenFixed(NewTokenFixed),
     NewTokenMutable(NewTokenMutable),
     Publish(Publish),
+    Replay(Replay),
     Reset(Reset),
     Run(Run),
     SetCurrentEpoch(SetCurrentEpoch),
     SetDefaultAccount(SetDefaultAccount),
     ShowConfigs(ShowConfigs),
     ShowLedger(ShowLedger),
+    ShowTx(ShowTx),
     Show(Show),
     Transfer(Transfer),
 }
@@ -103,8 +126,13 @@ pub fn run() -> Result<(), Error> {
     match cli.command {
         Command::CallFunction(cmd) => cmd.run(&mut out),
         Command::CallMethod(cmd) => cmd.run(&mut out),
+        Command::CallView(cmd) => cmd.run(&mut out),
+        Command::Diff(cmd) => cmd.run(&mut out),
         Command::ExportAbi(cmd) => cmd.run(&mut out),
+        Command::ExportState(cmd) => cmd.run(&mut out),
         Command::GenerateKeyPair(cmd) => cmd.run(&mut out),
+        Command::History(cmd) => cmd.run(&mut out),
+        Command::ImportState(cmd) => cmd.run(&mut out),
         Command::Mint(cmd) => cmd.run(&mut out),
         Command::NewAccount(cmd) => cmd.run(&mut out),
         Command::NewBadgeFixed(cmd) => cmd.run(&mut out),
@@ -112,19 +140,21 @@ pub fn run() -> Result<(), Error> {
         Command::NewTokenFixed(cmd) => cmd.run(&mut out),
         Command::NewTokenMutable(cmd) => cmd.run(&mut out),
         Command::Publish(cmd) => cmd.run(&mut out),
+        Command::Replay(cmd) => cmd.run(&mut out),
         Command::Reset(cmd) => cmd.run(&mut out),
         Command::Run(cmd) => cmd.run(&mut out),
         Command::SetCurrentEpoch(cmd) => cmd.run(&mut out),
         Command::SetDefaultAccount(cmd) => cmd.run(&mut out),
         Command::ShowConfigs(cmd) => cmd.run(&mut out),
         Command::ShowLedger(cmd) => cmd.run(&mut out),
+        Command::ShowTx(cmd) => cmd.run(&mut out),
         Command::Show(cmd) => cmd.run(&mut out),
         Command::Transfer(cmd) => cmd.run(&mut out),
     }
 }
 
-pub fn process_transaction<L: SubstateStore, O: std::io::Write>(
-    executor: &mut TransactionExecutor<L>,
+pub fn process_transaction<O: std::io::Write>(
+    executor: &mut TransactionExecutor<RadixEngineDB>,
     mut transaction: Transaction,
     signing_keys: &Option<String>,
     manifest_path: &Option<PathBuf>,
@@ -148,15 +178,53 @@ pub fn process_transaction<L: SubstateStore, O: std::io::Write>(
             let nonce = executor.get_nonce(&pks);
             transaction.add_nonce(nonce);
             let signed = transaction.sign(sks.iter().collect::<Vec<&EcdsaPrivateKey>>());
-            let receipt = executor
-                .validate_and_execute(&signed)
-                .map_err(Error::TransactionValidationError)?;
+            let receipt = execute_transaction(executor, &signed)?;
             writeln!(out, "{:?}", receipt).map_err(Error::IOError)?;
             receipt.result.map_err(Error::TransactionExecutionError)
         }
     }
 }
 
+/// Executes a signed transaction, recording it in the transaction history and, if committed,
+/// in the journal.
+pub fn execute_transaction(
+    executor: &mut TransactionExecutor<RadixEngineDB>,
+    signed: &SignedTransaction,
+) -> Result<Receipt, Error> {
+    let receipt = executor
+        .validate_and_execute(signed)
+        .map_err(Error::TransactionValidationError)?;
+    executor
+        .substate_store_mut()
+        .put_transaction_record(&TransactionRecord::new(signed, &receipt));
+    if receipt.commit_receipt.is_some() {
+        record_journal_entry(
+            executor.substate_store(),
+            JournalEntry::Transaction {
+                transaction: signed.transaction.clone(),
+                signatures: signed.signatures.clone(),
+            },
+        )?;
+    }
+    Ok(receipt)
+}
+
+/// Appends a ledger mutation, which has just been applied, to the journal under the data directory.
+pub fn record_journal_entry(ledger: &RadixEngineDB, entry: JournalEntry) -> Result<(), Error> {
+    let mut path = get_data_dir()?;
+    path.push(JOURNAL_FILE_NAME);
+    let mut file = fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .map_err(Error::IOError)?;
+    let record = JournalRecord {
+        entry,
+        state_hash: state_hash(ledger).map_err(Error::DataError)?,
+    };
+    write_journal_record(&mut file, &record).map_err(Error::JournalError)
+}
+
 pub fn parse_signing_keys(signing_keys: &Option<String>) -> Result<Vec<EcdsaPrivateKey>, Error> {
     let private_keys = if let Some(keys) = signing_keys {
         keys.split(",")
@@ -28,6 +28,9 @@ package=`$resim publish ../examples/hello-world | awk '/Package:/ {print $NF}'`
 component=`$resim call-function $package Hello instantiate_hello | awk '/Component:/ {print $NF}'`
 $resim call-method $component free_token
 
+# Test - call a read-only method without a transaction
+$resim call-view $account balance 030000000000000000000000000000000000000000000000000004
+
 # Test - export abi
 $resim export-abi $package Hello
 
@@ -55,3 +58,29 @@ package=`$resim publish ./tests/nft | awk '/Package:/ {print $NF}'`
 $resim call-function $package Foo nfts
 $resim show $account
 
+# Test - export and import ledger state
+$resim export-state ./target/ledger.bin
+$resim reset
+$resim import-state ./target/ledger.bin
+$resim show $account
+$resim replay ${DATA_DIR:-$HOME/.scrypto}/journal.bin
+
+# Test - reset with a genesis file
+genesis_account=`$resim reset --genesis ./tests/genesis.json | awk '/Account:/ {print $2}'`
+$resim show $genesis_account
+
+# Test - transaction history
+$resim new-account
+package=`$resim publish ./tests/hello_world.wasm | awk '/Package:/ {print $NF}'`
+$resim history --limit 5
+$resim history --limit 1 | grep "Package: $package"
+tx_hash=`$resim history --limit 1 | awk '/SUCCESS/ {print $2}'`
+$resim show-tx $tx_hash
+
+# Test - replay the journal
+$resim replay ${DATA_DIR:-$HOME/.scrypto}/journal.bin
+
+# Test - diff ledger state
+$resim export-state ./target/before.bin
+$resim new-token-fixed 200
+$resim diff --from ./target/before.bin --to ${DATA_DIR:-$HOME/.scrypto}
@@ -101,6 +101,19 @@ pub enum Instruction {
 
     PublishPackage {
         code: Value,
+        metadata: Value,
+    },
+
+    PublishPackageWithOwner {
+        code: Value,
+        metadata: Value,
+        owner_rule: Value,
+    },
+
+    UpgradePackage {
+        package_address: Value,
+        code: Value,
+        metadata: Value,
     },
 }
 
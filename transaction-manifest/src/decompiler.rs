@@ -261,10 +261,35 @@ pub fn decompile(tx: &Transaction) -> Result<String, DecompileError> {
                     component_address, method
                 ));
             }
-            Instruction::PublishPackage { code } => {
+            Instruction::PublishPackage { code, metadata } => {
                 buf.push_str(&format!(
-                    "PUBLISH_PACKAGE Bytes(\"{}\");\n",
-                    hex::encode(&code)
+                    "PUBLISH_PACKAGE Bytes(\"{}\") {};\n",
+                    hex::encode(&code),
+                    ScryptoValue::from_value(&metadata)
+                ));
+            }
+            Instruction::PublishPackageWithOwner {
+                code,
+                metadata,
+                owner_rule,
+            } => {
+                buf.push_str(&format!(
+                    "PUBLISH_PACKAGE_WITH_OWNER Bytes(\"{}\") {} {};\n",
+                    hex::encode(&code),
+                    ScryptoValue::from_value(&metadata),
+                    ScryptoValue::from_value(&owner_rule)
+                ));
+            }
+            Instruction::UpgradePackage {
+                package_address,
+                code,
+                metadata,
+            } => {
+                buf.push_str(&format!(
+                    "UPGRADE_PACKAGE PackageAddress(\"{}\") Bytes(\"{}\") {};\n",
+                    package_address,
+                    hex::encode(&code),
+                    ScryptoValue::from_value(&metadata)
                 ));
             }
             Instruction::Nonce { .. } => {
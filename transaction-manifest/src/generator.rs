@@ -3,8 +3,10 @@ use radix_engine::engine::*;
 use radix_engine::model::*;
 use sbor::any::{encode_any, Value};
 use sbor::type_id::*;
-use sbor::Encoder;
+use sbor::{DecodeError, Encoder};
+use scrypto::buffer::scrypto_decode;
 use scrypto::engine::types::*;
+use scrypto::resource::AccessRule;
 use scrypto::rust::collections::BTreeSet;
 use scrypto::rust::collections::HashMap;
 use scrypto::rust::str::FromStr;
@@ -30,6 +32,8 @@ pub enum GeneratorError {
     InvalidVaultId(String),
     InvalidNonFungibleId(String),
     InvalidNonFungibleAddress(String),
+    InvalidAccessRule(DecodeError),
+    InvalidMetadata(DecodeError),
     OddNumberOfElements(usize),
     NameResolverError(NameResolverError),
     IdValidatorError(IdValidatorError),
@@ -325,8 +329,27 @@ pub fn generate_instruction(
                 method: generate_string(method)?,
             }
         }
-        ast::Instruction::PublishPackage { code } => Instruction::PublishPackage {
+        ast::Instruction::PublishPackage { code, metadata } => Instruction::PublishPackage {
             code: generate_bytes(code)?,
+            metadata: generate_metadata(metadata, resolver)?,
+        },
+        ast::Instruction::PublishPackageWithOwner {
+            code,
+            metadata,
+            owner_rule,
+        } => Instruction::PublishPackageWithOwner {
+            code: generate_bytes(code)?,
+            metadata: generate_metadata(metadata, resolver)?,
+            owner_rule: generate_access_rule(owner_rule, resolver)?,
+        },
+        ast::Instruction::UpgradePackage {
+            package_address,
+            code,
+            metadata,
+        } => Instruction::UpgradePackage {
+            package_address: generate_package_address(package_address)?,
+            code: generate_bytes(code)?,
+            metadata: generate_metadata(metadata, resolver)?,
         },
     })
 }
@@ -347,16 +370,23 @@ fn generate_args(
 ) -> Result<Vec<Vec<u8>>, GeneratorError> {
     let mut result = Vec::new();
     for v in values {
-        let value = generate_value(v, None, resolver)?;
-
-        let mut bytes = Vec::new();
-        let mut enc = Encoder::with_type(&mut bytes);
-        encode_any(None, &value, &mut enc);
-        result.push(bytes);
+        result.push(generate_encoded_value(v, resolver)?);
     }
     Ok(result)
 }
 
+fn generate_encoded_value(
+    value: &ast::Value,
+    resolver: &mut NameResolver,
+) -> Result<Vec<u8>, GeneratorError> {
+    let value = generate_value(value, None, resolver)?;
+
+    let mut bytes = Vec::new();
+    let mut enc = Encoder::with_type(&mut bytes);
+    encode_any(None, &value, &mut enc);
+    Ok(bytes)
+}
+
 fn generate_string(value: &ast::Value) -> Result<String, GeneratorError> {
     match value {
         ast::Value::String(s) => Ok(s.into()),
@@ -395,6 +425,22 @@ fn generate_bytes(value: &ast::Value) -> Result<Vec<u8>, GeneratorError> {
     }
 }
 
+fn generate_access_rule(
+    value: &ast::Value,
+    resolver: &mut NameResolver,
+) -> Result<AccessRule, GeneratorError> {
+    let bytes = generate_encoded_value(value, resolver)?;
+    scrypto_decode(&bytes).map_err(GeneratorError::InvalidAccessRule)
+}
+
+fn generate_metadata(
+    value: &ast::Value,
+    resolver: &mut NameResolver,
+) -> Result<HashMap<String, String>, GeneratorError> {
+    let bytes = generate_encoded_value(value, resolver)?;
+    scrypto_decode(&bytes).map_err(GeneratorError::InvalidMetadata)
+}
+
 fn generate_decimal(value: &ast::Value) -> Result<Decimal, GeneratorError> {
     match value {
         ast::Value::Decimal(inner) => match &**inner {
@@ -959,6 +1005,25 @@ mod tests {
 
     #[test]
     fn test_instructions() {
+        generate_instruction_ok!(
+            r#"PUBLISH_PACKAGE_WITH_OWNER  Bytes("01ff")  HashMap<String, String>("name", "hello")  Enum("AllowAll");"#,
+            Instruction::PublishPackageWithOwner {
+                code: vec![1u8, 255u8],
+                metadata: HashMap::from([("name".to_owned(), "hello".to_owned())]),
+                owner_rule: AccessRule::AllowAll,
+            }
+        );
+        generate_instruction_ok!(
+            r#"UPGRADE_PACKAGE  PackageAddress("01d1f50010e4102d88aacc347711491f852c515134a9ecf67ba17c")  Bytes("01ff")  HashMap<String, String>();"#,
+            Instruction::UpgradePackage {
+                package_address: PackageAddress::from_str(
+                    "01d1f50010e4102d88aacc347711491f852c515134a9ecf67ba17c"
+                )
+                .unwrap(),
+                code: vec![1u8, 255u8],
+                metadata: HashMap::new(),
+            }
+        );
         generate_instruction_ok!(
             r#"TAKE_FROM_WORKTOP_BY_AMOUNT  Decimal("1.0")  ResourceAddress("03cbdf875789d08cc80c97e2915b920824a69ea8d809e50b9fe09d")  Bucket("xrd_bucket");"#,
             Instruction::TakeFromWorktopByAmount {
@@ -1140,8 +1205,14 @@ mod tests {
                         .unwrap(),
                         method: "deposit_batch".into(),
                     },
-                    Instruction::PublishPackage { code: code.clone() },
-                    Instruction::PublishPackage { code: code.clone() }
+                    Instruction::PublishPackage {
+                        code: code.clone(),
+                        metadata: HashMap::new()
+                    },
+                    Instruction::PublishPackage {
+                        code: code.clone(),
+                        metadata: HashMap::from([("name".to_owned(), "hello".to_owned())])
+                    }
                 ]
             }
         );
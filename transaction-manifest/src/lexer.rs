@@ -98,6 +98,8 @@ pub enum TokenKind {
     CallMethod,
     CallMethodWithAllResources,
     PublishPackage,
+    PublishPackageWithOwner,
+    UpgradePackage,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -419,6 +421,8 @@ impl Lexer {
             "CALL_METHOD" => Ok(TokenKind::CallMethod),
             "CALL_METHOD_WITH_ALL_RESOURCES" => Ok(TokenKind::CallMethodWithAllResources),
             "PUBLISH_PACKAGE" => Ok(TokenKind::PublishPackage),
+            "PUBLISH_PACKAGE_WITH_OWNER" => Ok(TokenKind::PublishPackageWithOwner),
+            "UPGRADE_PACKAGE" => Ok(TokenKind::UpgradePackage),
 
             s @ _ => Err(LexerError::UnknownIdentifier(s.into())),
         }
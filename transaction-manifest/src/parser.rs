@@ -162,6 +162,17 @@ impl Parser {
             },
             TokenKind::PublishPackage => Instruction::PublishPackage {
                 code: self.parse_value()?,
+                metadata: self.parse_value()?,
+            },
+            TokenKind::PublishPackageWithOwner => Instruction::PublishPackageWithOwner {
+                code: self.parse_value()?,
+                metadata: self.parse_value()?,
+                owner_rule: self.parse_value()?,
+            },
+            TokenKind::UpgradePackage => Instruction::UpgradePackage {
+                package_address: self.parse_value()?,
+                code: self.parse_value()?,
+                metadata: self.parse_value()?,
             },
             _ => {
                 return Err(ParserError::UnexpectedToken(token));